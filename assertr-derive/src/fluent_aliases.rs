@@ -105,9 +105,15 @@ fn has_attr_in_cfg_attr(attr: &Attribute, name: &str) -> bool {
         return false;
     }
     // Use string-based matching since cfg_attr contents have complex parse requirements.
-    let tokens = attr.meta.to_token_stream().to_string();
-    // Look for ", <name>" or ", <name> (" pattern after the cfg condition.
-    tokens.contains(&format!(", {name}"))
+    // Whitespace is removed, as long attributes may be printed across multiple lines.
+    let tokens: String = attr
+        .meta
+        .to_token_stream()
+        .to_string()
+        .split_whitespace()
+        .collect();
+    // Look for ",<name>" or ",<name>(" pattern after the cfg condition.
+    tokens.contains(&format!(",{name}"))
 }
 
 /// Extract the alias name from `#[fluent_alias("name")]`
@@ -133,6 +133,76 @@ pub fn store(input: TokenStream) -> TokenStream {
     })
 }
 
+#[derive(Debug, FromField)]
+#[darling(attributes(approx_eq))]
+struct ApproxEqFieldReceiver {
+    ident: Option<Ident>,
+
+    #[darling(default)]
+    exact: bool,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(approx_eq), supports(struct_named))]
+struct ApproxEqInputReceiver {
+    ident: Ident,
+
+    data: ast::Data<(), ApproxEqFieldReceiver>,
+}
+
+/// Derive macro for `ApproxEq`.
+///
+/// Fields are compared using their own `ApproxEq` implementation, allowing floats to deviate by
+/// the given tolerance. Mark fields with `#[approx_eq(exact)]` to compare them using
+/// `AssertrPartialEq` instead.
+///
+/// # Panics
+///
+/// This proc macro will panic if applied to an enum, as only structs are supported.
+#[proc_macro_derive(ApproxEq, attributes(approx_eq))]
+pub fn approx_eq(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let input: ApproxEqInputReceiver = match FromDeriveInput::from_derive_input(&ast) {
+        Ok(args) => args,
+        Err(err) => return Error::write_errors(err).into(),
+    };
+
+    let struct_ident = &input.ident;
+
+    let fields = match &input.data {
+        ast::Data::Enum(_) => panic!("Only structs are supported"),
+        ast::Data::Struct(fields) => fields,
+    };
+
+    let field_checks = fields.iter().map(|field| {
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("only named fields are supported!");
+        let ident_string = ident.to_string();
+        if field.exact {
+            quote! {
+                eq &= ::assertr::approx::eq_at(#ident_string, &self.#ident, &other.#ident, ctx.as_deref_mut());
+            }
+        } else {
+            quote! {
+                eq &= ::assertr::approx::approx_eq_at(#ident_string, &self.#ident, &other.#ident, tolerance, ctx.as_deref_mut());
+            }
+        }
+    });
+
+    Into::into(quote! {
+        impl ::assertr::approx::ApproxEq for #struct_ident {
+            fn approx_eq(&self, other: &Self, tolerance: f64, mut ctx: Option<&mut ::assertr::EqContext>) -> bool {
+                let mut eq = true;
+                #(#field_checks)*
+                eq
+            }
+        }
+    })
+}
+
 /// Attribute macro that generates fluent aliases for assertion trait methods.
 ///
 /// Place on a trait definition to auto-generate `be_*` aliases for `is_*` methods
@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use assertr::prelude::*;
+use indoc::formatdoc;
+
+#[derive(Debug, PartialEq, ApproxEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, AssertrEq, ApproxEq)]
+pub struct Measurement {
+    #[approx_eq(exact)]
+    pub label: Option<String>,
+
+    pub samples: Vec<f32>,
+
+    pub origin: Point,
+}
+
+fn main() {
+    let measurement = Measurement {
+        label: Some("m1".to_string()),
+        samples: vec![1.0, 2.0],
+        origin: Point { x: 0.0, y: 0.5 },
+    };
+
+    measurement.must().be_approx_equal_to(
+        Measurement {
+            label: Some("m1".to_string()),
+            samples: vec![1.01, 1.99],
+            origin: Point { x: 0.01, y: 0.5 },
+        },
+        0.05,
+    );
+
+    assert_that_panic_by(|| {
+        measurement.must().with_location(false).be_approx_equal_to(
+            Measurement {
+                label: None,
+                samples: vec![1.0, 2.5],
+                origin: Point { x: 0.0, y: 0.0 },
+            },
+            0.05,
+        )
+    })
+    .has_type::<String>()
+    .is_equal_to(formatdoc! {r#"
+            -------- assertr --------
+            Expected: Measurement {{
+                label: None,
+                samples: [
+                    1.0,
+                    2.5,
+                ],
+                origin: Point {{
+                    x: 0.0,
+                    y: 0.0,
+                }},
+            }}
+
+              Actual: Measurement {{
+                label: Some(
+                    "m1",
+                ),
+                samples: [
+                    1.0,
+                    2.0,
+                ],
+                origin: Point {{
+                    x: 0.0,
+                    y: 0.5,
+                }},
+            }}
+
+            Allowed deviation: 0.05
+
+            Details: [
+                Differences: [
+                    label: expected None, but was Some(
+                        "m1",
+                    ),
+                    samples[1]: expected 2.5, but was 2.0, deviating by 0.5,
+                    origin.y: expected 0.0, but was 0.5, deviating by 0.5,
+                ],
+            ]
+            -------- assertr --------
+        "#});
+}
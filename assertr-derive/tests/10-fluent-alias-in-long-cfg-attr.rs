@@ -0,0 +1,27 @@
+#![allow(dead_code, unexpected_cfgs)]
+
+use assertr::prelude::*;
+
+/// The `cfg_attr` below is long enough for its tokens to be printed across multiple lines. It must
+/// still be recognized and stripped, as `fluent_alias` is no attribute of its own.
+#[assertr_derive::fluent_aliases]
+pub trait AnswerAssertions {
+    #[cfg_attr(
+        all(
+            not(feature = "some_feature_that_is_never_enabled"),
+            not(feature = "another_one")
+        ),
+        fluent_alias("have_the_answer_to_life_the_universe_and_everything")
+    )]
+    fn has_the_answer_to_life_the_universe_and_everything(self) -> Self;
+}
+
+impl<M: Mode> AnswerAssertions for AssertThat<'_, u32, M> {
+    fn has_the_answer_to_life_the_universe_and_everything(self) -> Self {
+        self.is_equal_to(42)
+    }
+}
+
+fn main() {
+    assert_that!(42u32).has_the_answer_to_life_the_universe_and_everything();
+}
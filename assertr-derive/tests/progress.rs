@@ -9,4 +9,6 @@ fn tests() {
     t.pass("tests/06-replace-deep-field-type.rs");
     t.pass("tests/07-derive-impl-for-reference.rs");
     t.pass("tests/08-default-impl.rs");
+    t.pass("tests/09-approx-eq.rs");
+    t.pass("tests/10-fluent-alias-in-long-cfg-attr.rs");
 }
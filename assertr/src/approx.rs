@@ -0,0 +1,281 @@
+use crate::{AssertrPartialEq, EqContext};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Equality check allowing floating point values to deviate from each other by an absolute
+/// `tolerance`.
+///
+/// Implemented for `f32` and `f64`, for other primitives (compared exactly) and for slices,
+/// arrays and `Vec`s of approximately comparable elements. Derive it for your own structs using
+/// `#[derive(ApproxEq)]`.
+///
+/// Implementations record every value deviating by more than `tolerance` in the given
+/// `EqContext`, allowing assertions to report all of them at once.
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    /// This method tests for `self` and `other` values to be approximately equal.
+    #[must_use]
+    fn approx_eq(&self, other: &Rhs, tolerance: f64, ctx: Option<&mut EqContext>) -> bool;
+
+    /// The absolute deviation of `self` from `other`, if `self` is a single numeric value.
+    ///
+    /// Composite values return `None` and report their deviating parts on their own.
+    fn deviation(&self, _other: &Rhs) -> Option<f64> {
+        None
+    }
+}
+
+/// Compares `actual` with `expected`, recording a deviation at `location` in `ctx` if they are
+/// not approximately equal.
+///
+/// Used by `ApproxEq` implementations of composite values, reporting their elements or fields.
+/// Deviations found within nested composite values are recorded by them, at their location
+/// joined onto `location`, e.g. `samples[1]` or `origin.y`.
+pub fn approx_eq_at<A, E>(
+    location: &str,
+    actual: &A,
+    expected: &E,
+    tolerance: f64,
+    ctx: Option<&mut EqContext>,
+) -> bool
+where
+    A: ApproxEq<E> + Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    compare_at(
+        location,
+        actual,
+        expected,
+        ctx,
+        |ctx| actual.approx_eq(expected, tolerance, ctx),
+        || actual.deviation(expected),
+    )
+}
+
+/// Compares `actual` with `expected` for exact equality, recording a difference at `location` in
+/// `ctx` if they are not equal.
+///
+/// Used by `ApproxEq` implementations of composite values for parts which must not deviate.
+pub fn eq_at<A, E>(location: &str, actual: &A, expected: &E, ctx: Option<&mut EqContext>) -> bool
+where
+    A: AssertrPartialEq<E> + Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    compare_at(
+        location,
+        actual,
+        expected,
+        ctx,
+        |ctx| AssertrPartialEq::eq(actual, expected, ctx),
+        || None,
+    )
+}
+
+fn compare_at<A, E>(
+    location: &str,
+    actual: &A,
+    expected: &E,
+    ctx: Option<&mut EqContext>,
+    compare: impl FnOnce(Option<&mut EqContext>) -> bool,
+    deviation: impl FnOnce() -> Option<f64>,
+) -> bool
+where
+    A: Debug + ?Sized,
+    E: Debug + ?Sized,
+{
+    let Some(ctx) = ctx else {
+        return compare(None);
+    };
+    let path = ctx.path_to(location);
+    let parent = core::mem::replace(&mut ctx.path, path);
+    let recorded = ctx.differences.differences.len();
+    let eq = compare(Some(&mut *ctx));
+    let path = core::mem::replace(&mut ctx.path, parent);
+    if !eq {
+        match deviation() {
+            Some(deviation) => ctx.add_deviation(&path, expected, actual, deviation),
+            // Composite values already recorded their deviating parts.
+            None if ctx.differences.differences.len() > recorded => {}
+            None => ctx.add_difference(format!(
+                "{path}: expected {expected:#?}, but was {actual:#?}"
+            )),
+        }
+    }
+    eq
+}
+
+macro_rules! impl_approx_eq_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                fn approx_eq(&self, other: &$t, tolerance: f64, _ctx: Option<&mut EqContext>) -> bool {
+                    // Equal infinities are approximately equal, although their difference is NaN.
+                    #[allow(clippy::float_cmp)]
+                    let equal = self == other;
+                    equal || self.deviation(other).is_some_and(|deviation| deviation <= tolerance)
+                }
+
+                fn deviation(&self, other: &$t) -> Option<f64> {
+                    Some((f64::from(*self) - f64::from(*other)).abs())
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_for_float!(f32, f64);
+
+macro_rules! impl_exact_approx_eq {
+    ($($t:ty),*) => {
+        $(
+            impl ApproxEq for $t {
+                fn approx_eq(&self, other: &$t, _tolerance: f64, _ctx: Option<&mut EqContext>) -> bool {
+                    PartialEq::eq(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_approx_eq!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, str, String
+);
+
+impl<T1, T2> ApproxEq<[T2]> for [T1]
+where
+    T1: ApproxEq<T2> + Debug,
+    T2: Debug,
+{
+    fn approx_eq(&self, other: &[T2], tolerance: f64, mut ctx: Option<&mut EqContext>) -> bool {
+        if self.len() != other.len() {
+            if let Some(ctx) = ctx {
+                let difference = format!(
+                    "Expected {} elements, but found {}",
+                    other.len(),
+                    self.len()
+                );
+                if ctx.path.is_empty() {
+                    ctx.add_difference(difference);
+                } else {
+                    ctx.add_difference(format!("{}: {difference}", ctx.path));
+                }
+            }
+            return false;
+        }
+        let mut eq = true;
+        for (i, (actual, expected)) in self.iter().zip(other).enumerate() {
+            eq &= approx_eq_at(
+                &format!("[{i}]"),
+                actual,
+                expected,
+                tolerance,
+                ctx.as_deref_mut(),
+            );
+        }
+        eq
+    }
+}
+
+impl<T1, T2, const N: usize> ApproxEq<[T2; N]> for [T1; N]
+where
+    T1: ApproxEq<T2> + Debug,
+    T2: Debug,
+{
+    fn approx_eq(&self, other: &[T2; N], tolerance: f64, ctx: Option<&mut EqContext>) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance, ctx)
+    }
+}
+
+impl<T1, T2> ApproxEq<Vec<T2>> for Vec<T1>
+where
+    T1: ApproxEq<T2> + Debug,
+    T2: Debug,
+{
+    fn approx_eq(&self, other: &Vec<T2>, tolerance: f64, ctx: Option<&mut EqContext>) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance, ctx)
+    }
+}
+
+impl<T1, T2> ApproxEq<&T2> for &T1
+where
+    T1: ApproxEq<T2> + ?Sized,
+    T2: ?Sized,
+{
+    fn approx_eq(&self, other: &&T2, tolerance: f64, ctx: Option<&mut EqContext>) -> bool {
+        (**self).approx_eq(*other, tolerance, ctx)
+    }
+
+    fn deviation(&self, other: &&T2) -> Option<f64> {
+        (**self).deviation(*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EqContext;
+    use crate::approx::{ApproxEq, approx_eq_at};
+    use crate::prelude::*;
+    use indoc::formatdoc;
+
+    #[test]
+    fn floats_are_equal_within_tolerance() {
+        assert_that!(1.0f64.approx_eq(&1.05, 0.1, None)).is_true();
+        assert_that!(1.0f32.approx_eq(&1.05, 0.1, None)).is_true();
+        assert_that!(1.0f64.approx_eq(&1.2, 0.1, None)).is_false();
+    }
+
+    #[test]
+    fn nan_is_never_equal_and_infinities_only_to_themselves() {
+        assert_that!(f64::NAN.approx_eq(&f64::NAN, f64::INFINITY, None)).is_false();
+        assert_that!(f64::INFINITY.approx_eq(&f64::INFINITY, 0.0, None)).is_true();
+        assert_that!(f64::INFINITY.approx_eq(&f64::NEG_INFINITY, f64::MAX, None)).is_false();
+    }
+
+    #[test]
+    fn slices_report_every_deviating_index() {
+        let mut ctx = EqContext::new();
+
+        let eq = [1.0, 2.5, 3.0, 4.0].approx_eq(&[1.0, 2.0, 3.0, 5.0], 0.1, Some(&mut ctx));
+
+        assert_that!(eq).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                [1]: expected 2.0, but was 2.5, deviating by 0.5,
+                [3]: expected 5.0, but was 4.0, deviating by 1.0,
+            ]"});
+    }
+
+    #[test]
+    fn nested_values_report_deviations_at_their_full_location() {
+        let mut ctx = EqContext::new();
+
+        let eq = approx_eq_at(
+            "samples",
+            &vec![vec![1.0, 2.0], vec![3.0]],
+            &vec![vec![1.0, 2.5], vec![3.0, 4.0]],
+            0.1,
+            Some(&mut ctx),
+        );
+
+        assert_that!(eq).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                samples[0][1]: expected 2.5, but was 2.0, deviating by 0.5,
+                samples[1]: Expected 2 elements, but found 1,
+            ]"});
+    }
+
+    #[test]
+    fn slices_of_different_length_are_not_equal() {
+        let mut ctx = EqContext::new();
+
+        let eq = vec![1.0].approx_eq(&vec![1.0, 2.0], 0.1, Some(&mut ctx));
+
+        assert_that!(eq).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                Expected 2 elements, but found 1,
+            ]"});
+    }
+}
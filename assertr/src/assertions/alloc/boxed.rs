@@ -1,188 +1,85 @@
-use crate::{AssertThat, mode::Panic, tracking::AssertionTracking};
-use alloc::borrow::Cow;
+use crate::{AssertThat, actual::Actual, mode::Mode, tracking::AssertionTracking};
 use alloc::boxed::Box;
-use alloc::format;
 use alloc::string::String;
 use core::any::{Any, type_name, type_name_of_val};
 use core::fmt::Write;
 use indoc::writedoc;
 
 /// Data-extracting assertions for boxed values.
-/// Only available in Panic mode, as the extracted type cannot be produced when the downcast fails.
+/// In `Capture` mode, the subject is poisoned when the downcast fails, skipping all subsequent
+/// assertions.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait BoxAssertions<'t> {
-    fn has_type<E: 'static>(self) -> AssertThat<'t, E, Panic>;
+pub trait BoxAssertions<'t, M: Mode> {
+    fn has_type<E: 'static>(self) -> AssertThat<'t, E, M>;
 
-    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, Panic>;
+    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, M>;
 }
 
-impl<'t> BoxAssertions<'t> for AssertThat<'t, Box<dyn Any>, Panic> {
+impl<'t, M: Mode> BoxAssertions<'t, M> for AssertThat<'t, Box<dyn Any>, M> {
     #[track_caller]
-    #[allow(clippy::too_many_lines)]
-    fn has_type<E: 'static>(self) -> AssertThat<'t, E, Panic> {
-        enum CastResult<'c, C> {
-            Owned(Box<C>),
-            Ref(&'c C),
-            Err {
-                err: String,
-                actual_type_name: Cow<'static, str>,
-                actual_type_name_will_be_any: bool,
-            },
-        }
-
+    fn has_type<E: 'static>(self) -> AssertThat<'t, E, M> {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
-        let cast = match self.actual {
-            crate::actual::Actual::Borrowed(borrowed_boxed_any) => {
-                let is_str = borrowed_boxed_any.downcast_ref::<&str>().is_some();
-                let is_string = borrowed_boxed_any.downcast_ref::<String>().is_some();
-
-                let mut actual_type_name_will_be_any = false;
-                let actual_type_name = if is_str {
-                    Cow::Borrowed("&str")
-                } else if is_string {
-                    Cow::Borrowed("String")
-                } else {
-                    // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
-                    actual_type_name_will_be_any = true;
-                    Cow::Borrowed(type_name_of_val(borrowed_boxed_any))
-                };
-
-                borrowed_boxed_any.downcast_ref::<E>().map_or_else(
-                    || CastResult::Err {
-                        err: String::from("asd"),
-                        actual_type_name,
-                        actual_type_name_will_be_any,
-                    },
-                    |it| CastResult::Ref(it),
-                )
-            }
-            crate::actual::Actual::Owned(owned_box_any) => {
-                let is_str = owned_box_any.downcast_ref::<&str>().is_some();
-                let is_string = owned_box_any.downcast_ref::<String>().is_some();
-
-                let mut actual_type_name_will_be_any = false;
-                let actual_type_name = if is_str {
-                    Cow::Borrowed("&str")
-                } else if is_string {
-                    Cow::Borrowed("String")
-                } else {
-                    // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
-                    actual_type_name_will_be_any = true;
-                    Cow::Borrowed(type_name_of_val(&*owned_box_any))
-                };
-
-                owned_box_any.downcast::<E>().map_or_else(
-                    |err| CastResult::Err {
-                        err: format!("{err:#?}"),
-                        actual_type_name,
-                        actual_type_name_will_be_any,
-                    },
-                    |it| CastResult::Owned(it),
-                )
-            }
-        };
-
-        match cast {
-            CastResult::Owned(casted) => {
-                AssertThat {
-                    parent: self.parent,
-                    actual: (*casted).into(),
-                    subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
-                    detail_messages: self.detail_messages,
-                    print_location: self.print_location,
-                    number_of_assertions: self.number_of_assertions,
-                    failures: self.failures,
-                    mode: self.mode,
-                }
-            }
-            CastResult::Ref(casted) => {
-                AssertThat {
-                    parent: self.parent,
-                    actual: casted.into(),
-                    subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
-                    detail_messages: self.detail_messages,
-                    print_location: self.print_location,
-                    number_of_assertions: self.number_of_assertions,
-                    failures: self.failures,
-                    mode: self.mode,
-                }
-            }
-            CastResult::Err {
-                err,
-                actual_type_name,
-                actual_type_name_will_be_any,
-            } => {
-                let err = AssertThat {
-                    parent: self.parent,
-                    actual: err.into(),
-                    subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
-                    detail_messages: self.detail_messages,
-                    print_location: self.print_location,
-                    number_of_assertions: self.number_of_assertions,
-                    failures: self.failures,
-                    mode: self.mode,
-                };
-
-                let expected_type_name = type_name::<E>();
-
-                if actual_type_name_will_be_any {
-                    err.add_detail_message("A Box<dyn Any> means that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.");
-                }
-
-                err.fail(|w: &mut String| {
-                    writedoc! {w, r"
-                        Expected value type: {expected_type_name}
-
-                          Actual value type: {actual_type_name}
-                    "}
-                });
-                unreachable!("Panic mode always panics on fail")
-            }
+        if !self.actual().is::<E>() {
+            fail_type_mismatch::<E, M>(&self);
+            return self.poison();
         }
+
+        // Downcasting cannot fail here, as we returned early when the type does not match!
+        self.map(|it| match it {
+            Actual::Owned(o) => Actual::Owned(*o.downcast::<E>().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.downcast_ref::<E>().unwrap()),
+        })
     }
 
     #[track_caller]
-    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, Panic> {
+    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, M> {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.derive_poisoned();
+        }
 
-        let any = &self.actual();
-        if let Some(casted) = any.downcast_ref::<E>() {
+        if let Some(casted) = self.actual().downcast_ref::<E>() {
             self.derive(|_actual| casted)
         } else {
-            let expected_type_name = type_name::<E>();
-
-            let is_str = any.downcast_ref::<&str>().is_some();
-            let is_string = any.downcast_ref::<String>().is_some();
-
-            let actual_type_name = if is_str {
-                Cow::Borrowed("&str")
-            } else if is_string {
-                Cow::Borrowed("String")
-            } else {
-                // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
-                self.add_detail_message("A Box<dyn Any> means that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.");
-                Cow::Borrowed(type_name_of_val(&**self.actual()))
-            };
-
-            self.fail(|w: &mut String| {
-                writedoc! {w, r"
-                    Expected value type: {expected_type_name}
-
-                      Actual value type: {actual_type_name}
-                "}
-            });
-            unreachable!("Panic mode always panics on fail")
+            fail_type_mismatch::<E, M>(self);
+            self.derive_poisoned()
         }
     }
 }
 
+#[track_caller]
+fn fail_type_mismatch<E: 'static, M: Mode>(assertion: &AssertThat<'_, Box<dyn Any>, M>) {
+    let any = &**assertion.actual();
+    let expected_type_name = type_name::<E>();
+    let actual_type_name = if any.is::<&str>() {
+        "&str"
+    } else if any.is::<String>() {
+        "String"
+    } else {
+        // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
+        assertion.add_detail_message("A Box<dyn Any> means that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.");
+        type_name_of_val(any)
+    };
+
+    assertion.fail_extraction(|w: &mut String| {
+        writedoc! {w, r"
+            Expected value type: {expected_type_name}
+
+              Actual value type: {actual_type_name}
+        "}
+    });
+}
+
 /*
 TODO: implement for &Box?
 impl<'t, M: Mode> BoxAssertions<'t, M> for AssertThat<'t, &Box<dyn Any>, M> {
-    fn has_type<E: 'static>(self) -> AssertThat<'t, E, Panic> {}
+    fn has_type<E: 'static>(self) -> AssertThat<'t, E, M> {}
 
-    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, Panic> {}
+    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, M> {}
 }
 */
 
@@ -220,6 +117,28 @@ mod tests {
                     -------- assertr --------
                 "#});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_type_does_not_match() {
+            let boxed_any: Box<dyn Any> = Box::new("foo");
+
+            let failures = assert_that!(boxed_any)
+                .with_capture()
+                .with_location(false)
+                .has_type::<u32>()
+                .is_equal_to(42)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected value type: u32
+
+                  Actual value type: &str
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
     }
 
     mod has_type_ref {
@@ -0,0 +1,1171 @@
+use crate::assertions::core::range::check_btree_range;
+use crate::{
+    AssertThat, AssertrPartialEq, EqContext, Mode, actual::Actual, mode::Panic,
+    tracking::AssertionTracking, util::collection_diff::CollectionDiff,
+};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::fmt::Write;
+use core::ops::RangeBounds;
+use indoc::writedoc;
+
+/// Assertions for generic [`BTreeMap`]s.
+///
+/// Covers everything [`HashMapAssertions`](crate::prelude::HashMapAssertions) provides and adds
+/// assertions relying on the ordering of the map's keys.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait BTreeMapAssertions<'t, K, V, M: Mode> {
+    fn contains_key(self, expected: impl Borrow<K>) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug;
+
+    fn does_not_contain_key(self, not_expected: impl Borrow<K>) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug;
+
+    fn contains_value<E>(self, expected: E) -> Self
+    where
+        K: Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn does_not_contain_value<E>(self, not_expected: E) -> Self
+    where
+        K: Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn contains_entry<E>(self, key: impl Borrow<K>, value: impl Borrow<E>) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn does_not_contain_entry<E>(self, key: impl Borrow<K>, value: impl Borrow<E>) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn contains_keys<E, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug,
+        E: Borrow<K> + Debug,
+        I: IntoIterator<Item = E>;
+
+    /// Test that the map contains exactly the expected entries, in any order.
+    fn contains_exactly_entries<EK, EV, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<EV> + Debug,
+        EK: Borrow<K> + Debug,
+        EV: Debug,
+        I: IntoIterator<Item = (EK, EV)>;
+
+    /// Test that the map contains exactly the expected entries, given in ascending key order.
+    ///
+    /// Fails if the expected entries are not listed in the order the map iterates them in.
+    fn contains_exactly_entries_in_order<EK, EV, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<EV> + Debug,
+        EK: Borrow<K> + Debug,
+        EV: Debug,
+        I: IntoIterator<Item = (EK, EV)>;
+
+    /// Extract all keys lying in the given range, in ascending order.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) keys, on which all `Vec` assertions can be
+    /// used. Available in every mode. Fails for ranges starting after their end or excluding both
+    /// of their equal bounds, changing the subject to an empty `Vec`.
+    ///
+    /// ```rust
+    /// use assertr::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(1, "one"), (5, "five"), (9, "nine")]);
+    ///
+    /// assert_that!(map)
+    ///     .keys_in_range(2..=9)
+    ///     .contains_exactly([5, 9]);
+    /// ```
+    fn keys_in_range(self, range: impl RangeBounds<K>) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Ord + Clone + Debug;
+
+    /// Extract all keys of the map, in ascending order.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) keys, on which all `Vec` assertions can be
+    /// used. Extraction never fails and is therefore available in every mode.
+    fn keys(self) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Clone;
+
+    /// Extract all values of the map, in ascending order of their keys.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) values, on which all `Vec` assertions can be
+    /// used. Extraction never fails and is therefore available in every mode.
+    fn values(self) -> AssertThat<'t, Vec<V>, M>
+    where
+        V: Clone;
+
+    /// Run the given assertions on every entry of the map, in ascending key order.
+    ///
+    /// Failures name the key of the value they occurred on.
+    fn all_entries_satisfy<A>(self, assertions: A) -> Self
+    where
+        K: Debug,
+        A: for<'a> FnMut(&'a K, AssertThat<'a, &'a V, M>);
+
+    /// Test that at least one entry has a key matching `key_predicate` and a value matching
+    /// `value_predicate`.
+    fn contains_entries_matching<KP, VP>(self, key_predicate: KP, value_predicate: VP) -> Self
+    where
+        K: Debug,
+        V: Debug,
+        KP: Fn(&K) -> bool,
+        VP: Fn(&V) -> bool;
+}
+
+impl<'t, K, V, M: Mode> BTreeMapAssertions<'t, K, V, M> for AssertThat<'t, BTreeMap<K, V>, M> {
+    #[track_caller]
+    fn contains_key(self, expected: impl Borrow<K>) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.borrow();
+
+        if !self.actual().contains_key(expected) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not contain expected key: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn does_not_contain_key(self, not_expected: impl Borrow<K>) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let not_expected = not_expected.borrow();
+
+        if self.actual().contains_key(not_expected) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    contains unexpected key: {not_expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_value<E>(self, expected: E) -> Self
+    where
+        K: Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        if !self
+            .actual()
+            .values()
+            .any(|it| AssertrPartialEq::eq(it, &expected, None))
+        {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not contain expected value: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn does_not_contain_value<E>(self, not_expected: E) -> Self
+    where
+        K: Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        if self
+            .actual()
+            .values()
+            .any(|it| AssertrPartialEq::eq(it, &not_expected, None))
+        {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    contains unexpected value: {not_expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_entry<E>(self, key: impl Borrow<K>, value: impl Borrow<E>) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        let then = self.contains_key(key.borrow());
+
+        then.track_assertion();
+
+        let actual = then.actual();
+        let expected_key = key.borrow();
+        let expected_value = value.borrow();
+
+        match actual.get(expected_key) {
+            None => { /* Ignored: contains_key() already created an error in this case... */ }
+            Some(actual_value) => {
+                let mut ctx = EqContext::new();
+                if !AssertrPartialEq::eq(actual_value, expected_value, Some(&mut ctx)) {
+                    if !ctx.differences.differences.is_empty() {
+                        then.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+                    }
+                    then.fail(|w: &mut String| {
+                        writedoc! {w, r"
+                            Actual: BTreeMap {actual:#?}
+
+                            does not contain expected value at key: {expected_key:#?}
+
+                            Expected value: {expected_value:#?}
+                              Actual value: {actual_value:#?}
+                            ",
+                        }
+                    });
+                }
+            }
+        }
+
+        then
+    }
+
+    #[track_caller]
+    fn does_not_contain_entry<E>(self, key: impl Borrow<K>, value: impl Borrow<E>) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let unexpected_key = key.borrow();
+        let unexpected_value = value.borrow();
+
+        if self
+            .actual()
+            .get(unexpected_key)
+            .is_some_and(|actual_value| AssertrPartialEq::eq(actual_value, unexpected_value, None))
+        {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    contains unexpected entry at key: {unexpected_key:#?}
+
+                    Unexpected value: {unexpected_value:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_keys<E, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: Debug,
+        E: Borrow<K> + Debug,
+        I: IntoIterator<Item = E>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        let keys_not_found = expected
+            .iter()
+            .filter(|expected_key| !self.actual().contains_key((*expected_key).borrow()))
+            .collect::<Vec<_>>();
+
+        if !keys_not_found.is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not contain all expected keys
+
+                    Expected keys: {expected:#?}
+
+                    Keys not found: {keys_not_found:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_exactly_entries<EK, EV, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<EV> + Debug,
+        EK: Borrow<K> + Debug,
+        EV: Debug,
+        I: IntoIterator<Item = (EK, EV)>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        let diff =
+            CollectionDiff::of_map_entries(self.actual(), |key| self.actual().get(key), &expected);
+
+        if !diff.is_empty() {
+            for message in diff.messages() {
+                self.add_detail_message(message);
+            }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not exactly contain expected entries
+
+                    Expected entries: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_exactly_entries_in_order<EK, EV, I>(self, expected: I) -> Self
+    where
+        K: Ord + Debug,
+        V: AssertrPartialEq<EV> + Debug,
+        EK: Borrow<K> + Debug,
+        EV: Debug,
+        I: IntoIterator<Item = (EK, EV)>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+
+        let first_mismatch = self.actual().iter().zip(expected.iter()).enumerate().find(
+            |(_, ((actual_key, actual_value), (expected_key, expected_value)))| {
+                (*expected_key).borrow() != *actual_key
+                    || !AssertrPartialEq::eq(*actual_value, expected_value, None)
+            },
+        );
+
+        if first_mismatch.is_some() || self.actual().len() != expected.len() {
+            if self.actual().len() != expected.len() {
+                self.add_detail_message(format!(
+                    "Expected {} entries, but found {}",
+                    expected.len(),
+                    self.actual().len()
+                ));
+            }
+            if let Some((index, ((actual_key, actual_value), (expected_key, expected_value)))) =
+                first_mismatch
+            {
+                self.add_detail_message(format!(
+                    "First mismatch at index {index}: expected {:#?}, found {:#?}",
+                    (expected_key, expected_value),
+                    (actual_key, actual_value),
+                ));
+            }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not exactly contain expected entries in order
+
+                    Expected entries: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn keys_in_range(self, range: impl RangeBounds<K>) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Ord + Clone + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+
+        let Some(range) = check_btree_range(&self, range) else {
+            return self.map(|_actual| Actual::Owned(Vec::new()));
+        };
+        self.map(|actual| {
+            Actual::Owned(
+                actual
+                    .borrowed()
+                    .range(range)
+                    .map(|(key, _value)| key.clone())
+                    .collect(),
+            )
+        })
+    }
+
+    fn keys(self) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Clone,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().keys().cloned().collect()))
+    }
+
+    fn values(self) -> AssertThat<'t, Vec<V>, M>
+    where
+        V: Clone,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().values().cloned().collect()))
+    }
+
+    #[track_caller]
+    fn all_entries_satisfy<A>(self, mut assertions: A) -> Self
+    where
+        K: Debug,
+        A: for<'a> FnMut(&'a K, AssertThat<'a, &'a V, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        for (key, value) in self.actual() {
+            assertions(
+                key,
+                self.derive(|_| value)
+                    .with_subject_name(format!("value at key {key:?}")),
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_entries_matching<KP, VP>(self, key_predicate: KP, value_predicate: VP) -> Self
+    where
+        K: Debug,
+        V: Debug,
+        KP: Fn(&K) -> bool,
+        VP: Fn(&V) -> bool,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let entries_with_matching_key = self
+            .actual()
+            .iter()
+            .filter(|(key, _value)| key_predicate(key))
+            .collect::<Vec<_>>();
+
+        if !entries_with_matching_key
+            .iter()
+            .any(|(_key, value)| value_predicate(value))
+        {
+            if !entries_with_matching_key.is_empty() {
+                self.add_detail_message(format!(
+                    "Entries with matching key but non-matching value: {entries_with_matching_key:#?}"
+                ));
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    does not contain an entry matching the given key and value predicates
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+}
+
+/// Data-extracting assertions for [`BTreeMap`]s.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait BTreeMapExtractAssertions<'t, K, V> {
+    /// Test that the map is not empty and extract its smallest key.
+    ///
+    /// Only available in `Panic` mode, as the extracted `K` cannot be produced when the map is
+    /// empty.
+    fn first_key(self) -> AssertThat<'t, K, Panic>
+    where
+        K: Debug;
+
+    /// Test that the map is not empty and extract its largest key.
+    ///
+    /// Only available in `Panic` mode, as the extracted `K` cannot be produced when the map is
+    /// empty.
+    fn last_key(self) -> AssertThat<'t, K, Panic>
+    where
+        K: Debug;
+
+    /// Test that the map contains the given key and extract the value stored for it.
+    ///
+    /// Failures of assertions made on the extracted value name the key.
+    ///
+    /// Only available in `Panic` mode, as the extracted `V` cannot be produced when the key is
+    /// absent.
+    fn value_of(self, key: impl Borrow<K>) -> AssertThat<'t, V, Panic>
+    where
+        K: Ord + Debug;
+}
+
+impl<'t, K, V: Debug> BTreeMapExtractAssertions<'t, K, V>
+    for AssertThat<'t, BTreeMap<K, V>, Panic>
+{
+    #[track_caller]
+    fn first_key(self) -> AssertThat<'t, K, Panic>
+    where
+        K: Debug,
+    {
+        self.track_assertion();
+
+        if self.actual().is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    has no first key, as it is empty
+                ", actual = self.actual()}
+            });
+        }
+
+        self.map(|actual| match actual {
+            Actual::Owned(o) => Actual::Owned(o.into_keys().next().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.keys().next().unwrap()),
+        })
+    }
+
+    #[track_caller]
+    fn last_key(self) -> AssertThat<'t, K, Panic>
+    where
+        K: Debug,
+    {
+        self.track_assertion();
+
+        if self.actual().is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeMap {actual:#?}
+
+                    has no last key, as it is empty
+                ", actual = self.actual()}
+            });
+        }
+
+        self.map(|actual| match actual {
+            Actual::Owned(o) => Actual::Owned(o.into_keys().next_back().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.keys().next_back().unwrap()),
+        })
+    }
+
+    #[track_caller]
+    fn value_of(self, key: impl Borrow<K>) -> AssertThat<'t, V, Panic>
+    where
+        K: Ord + Debug,
+    {
+        let key = key.borrow();
+        let subject_name = format!("value at key {key:?}");
+
+        self.contains_key(key)
+            .map(|actual| match actual {
+                Actual::Owned(mut o) => Actual::Owned(o.remove(key).unwrap()),
+                Actual::Borrowed(b) => Actual::Borrowed(b.get(key).unwrap()),
+            })
+            .with_subject_name(subject_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod contains_key {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_key_is_present() {
+            assert_that!(BTreeMap::from([("foo", "bar")])).contains_key("foo");
+        }
+
+        #[test]
+        fn panics_when_key_is_absent() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([("foo", "bar")]))
+                    .with_location(false)
+                    .contains_key("baz");
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        "foo": "bar",
+                    }}
+
+                    does not contain expected key: "baz"
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod does_not_contain_key {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_key_is_absent() {
+            assert_that!(BTreeMap::from([("foo", "bar")])).does_not_contain_key("baz");
+        }
+
+        #[test]
+        fn fails_when_key_is_present() {
+            assert_that!(BTreeMap::from([("foo", "bar")]))
+                .with_capture()
+                .does_not_contain_key("foo")
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod contains_value {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_value_is_present() {
+            assert_that!(BTreeMap::from([("foo", "bar")])).contains_value("bar".to_string());
+        }
+
+        #[test]
+        fn fails_when_value_is_absent() {
+            assert_that!(BTreeMap::from([("foo", "bar")]))
+                .with_capture()
+                .contains_value("baz")
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod does_not_contain_value {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_value_is_absent() {
+            assert_that!(BTreeMap::from([("foo", "bar")])).does_not_contain_value("baz");
+        }
+    }
+
+    mod contains_entry {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_entry_is_present() {
+            assert_that!(BTreeMap::from([("foo", "bar")])).contains_entry::<&str>("foo", "bar");
+        }
+
+        #[test]
+        fn panics_when_key_is_present_but_value_is_not_equal() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([("foo", "bar")]))
+                    .with_location(false)
+                    .contains_entry::<&str>("foo", "baz");
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        "foo": "bar",
+                    }}
+
+                    does not contain expected value at key: "foo"
+
+                    Expected value: "baz"
+                      Actual value: "bar"
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod does_not_contain_entry {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_value_differs() {
+            assert_that!(BTreeMap::from([("foo", "bar")]))
+                .does_not_contain_entry::<&str>("foo", "baz");
+        }
+    }
+
+    mod contains_keys {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_all_keys_are_present() {
+            assert_that!(BTreeMap::from([(1, "one"), (2, "two")])).contains_keys([2, 1]);
+        }
+
+        #[test]
+        fn panics_when_a_key_is_missing() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([(1, "one")]))
+                    .with_location(false)
+                    .contains_keys([1, 3]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        1: "one",
+                    }}
+
+                    does not contain all expected keys
+
+                    Expected keys: [
+                        1,
+                        3,
+                    ]
+
+                    Keys not found: [
+                        3,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod contains_exactly_entries {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_entries_match_in_any_order() {
+            assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                .contains_exactly_entries([(2, "two"), (1, "one")]);
+        }
+
+        #[test]
+        fn fails_when_an_unexpected_entry_is_present() {
+            assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                .with_capture()
+                .contains_exactly_entries([(1, "one")])
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+
+        #[test]
+        fn panics_with_missing_unexpected_and_differing_entries() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                    .with_location(false)
+                    .contains_exactly_entries([(2, "zwei"), (3, "three")]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        1: "one",
+                        2: "two",
+                    }}
+
+                    does not exactly contain expected entries
+
+                    Expected entries: [
+                        (
+                            2,
+                            "zwei",
+                        ),
+                        (
+                            3,
+                            "three",
+                        ),
+                    ]
+
+                    Details: [
+                        Missing keys: [
+                            3,
+                        ],
+                        Unexpected entries: [
+                            (
+                                1,
+                                "one",
+                            ),
+                        ],
+                        Differing values: [
+                            2: expected "zwei", but was "two",
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod contains_exactly_entries_in_order {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_entries_match_in_order() {
+            assert_that!(BTreeMap::from([(2, "two"), (1, "one")]))
+                .contains_exactly_entries_in_order([(1, "one"), (2, "two")]);
+        }
+
+        #[test]
+        fn panics_when_entries_are_not_in_order() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                    .with_location(false)
+                    .contains_exactly_entries_in_order([(2, "two"), (1, "one")]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        1: "one",
+                        2: "two",
+                    }}
+
+                    does not exactly contain expected entries in order
+
+                    Expected entries: [
+                        (
+                            2,
+                            "two",
+                        ),
+                        (
+                            1,
+                            "one",
+                        ),
+                    ]
+
+                    Details: [
+                        First mismatch at index 0: expected (
+                            2,
+                            "two",
+                        ), found (
+                            1,
+                            "one",
+                        ),
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn panics_when_entries_are_missing() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                    .with_location(false)
+                    .contains_exactly_entries_in_order([(1, "one")]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeMap {{
+                        1: "one",
+                        2: "two",
+                    }}
+
+                    does not exactly contain expected entries in order
+
+                    Expected entries: [
+                        (
+                            1,
+                            "one",
+                        ),
+                    ]
+
+                    Details: [
+                        Expected 1 entries, but found 2,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod keys_in_range {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use core::ops::Bound;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_keys_in_range_in_ascending_order() {
+            let map = BTreeMap::from([(9, "nine"), (1, "one"), (5, "five")]);
+            assert_that!(&map)
+                .keys_in_range(..9)
+                .contains_exactly([1, 5]);
+            assert_that!(map).keys_in_range(10..).is_empty();
+        }
+
+        #[test]
+        #[allow(clippy::reversed_empty_ranges)]
+        fn captures_failure_for_range_starting_after_its_end() {
+            let map = BTreeMap::from([(1, "one"), (5, "five")]);
+            let keys = assert_that!(&map)
+                .with_capture()
+                .with_location(false)
+                .keys_in_range(5..2);
+
+            assert_that!(keys.actual()).is_empty();
+            assert_that!(keys.capture_failures()).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Range: 5..2
+
+                is not a valid range, as it starts after its end
+                -------- assertr --------
+            "}]);
+        }
+
+        #[test]
+        fn captures_failure_for_range_excluding_both_of_its_equal_bounds() {
+            let map = BTreeMap::from([(1, "one"), (5, "five")]);
+            let failures = assert_that!(map)
+                .with_capture()
+                .with_location(false)
+                .keys_in_range((Bound::Excluded(3), Bound::Excluded(3)))
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Range: (Excluded(3), Excluded(3))
+
+                is not a valid range, as it excludes both of its equal bounds
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod first_key {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_smallest_key() {
+            let map = BTreeMap::from([(3, "three"), (1, "one"), (2, "two")]);
+            assert_that!(&map).first_key().is_equal_to(1);
+            assert_that!(map).first_key().is_equal_to(1);
+        }
+
+        #[test]
+        fn panics_when_empty() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::<i32, i32>::new())
+                    .with_location(false)
+                    .first_key();
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {"
+                    -------- assertr --------
+                    Actual: BTreeMap {{}}
+
+                    has no first key, as it is empty
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod last_key {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn extracts_largest_key() {
+            let map = BTreeMap::from([(3, "three"), (1, "one"), (2, "two")]);
+            assert_that!(&map).last_key().is_equal_to(3);
+            assert_that!(map).last_key().is_equal_to(3);
+        }
+    }
+
+    mod has_length {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn works_on_owned_and_borrowed_maps() {
+            let map = BTreeMap::from([(1, "one"), (2, "two")]);
+            assert_that!(&map).has_length(2);
+            assert_that!(map).has_length(2);
+            assert_that!(BTreeMap::<i32, i32>::new()).is_empty();
+        }
+    }
+
+    mod value_of {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_value_of_owned_and_borrowed_map() {
+            let map = BTreeMap::from([("foo", 1), ("bar", 2)]);
+            assert_that!(&map).value_of("foo").is_equal_to(1);
+            assert_that!(map).value_of("bar").is_equal_to(2);
+        }
+
+        #[test]
+        fn names_the_key_in_nested_failures() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeMap::from([("foo", 1)]))
+                    .with_location(false)
+                    .value_of("foo")
+                    .is_equal_to(2);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Subject: value at key "foo"
+
+                    Expected: 2
+
+                      Actual: 1
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod keys {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn extracts_all_keys_in_ascending_order() {
+            assert_that!(BTreeMap::from([("foo", 1), ("bar", 2)]))
+                .keys()
+                .contains_exactly(["bar", "foo"]);
+        }
+    }
+
+    mod values {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn extracts_all_values_in_ascending_key_order() {
+            assert_that!(BTreeMap::from([("foo", 1), ("bar", 2)]))
+                .values()
+                .contains_exactly([2, 1]);
+        }
+    }
+
+    mod all_entries_satisfy {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_all_entries_satisfy_the_assertions() {
+            assert_that!(BTreeMap::from([(1, 10), (2, 20)])).all_entries_satisfy(|key, value| {
+                value.is_equal_to(&(key * 10));
+            });
+        }
+
+        #[test]
+        fn names_the_key_of_each_offending_value() {
+            let failures = assert_that!(BTreeMap::from([(1, 10), (2, 20)]))
+                .with_capture()
+                .with_location(false)
+                .all_entries_satisfy(|_key, value| {
+                    value.is_equal_to(&10);
+                })
+                .capture_failures();
+
+            assert_that!(failures).has_length(1).satisfies_ref(
+                |it| it[0].as_str(),
+                |failure| {
+                    failure.contains("Subject: value at key 2");
+                },
+            );
+        }
+    }
+
+    mod contains_entries_matching {
+        use crate::prelude::*;
+        use alloc::collections::BTreeMap;
+
+        #[test]
+        fn succeeds_when_an_entry_matches_both_predicates() {
+            assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                .contains_entries_matching(|key| *key > 1, |value| value.starts_with('t'));
+        }
+
+        #[test]
+        fn fails_when_no_entry_matches_both_predicates() {
+            assert_that!(BTreeMap::from([(1, "one"), (2, "two")]))
+                .with_capture()
+                .contains_entries_matching(|key| *key > 1, |value| value.starts_with('o'))
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+}
@@ -0,0 +1,852 @@
+use crate::assertions::core::range::check_btree_range;
+use crate::{
+    AssertThat, AssertrPartialEq, EqContext, Mode, actual::Actual,
+    assertions::core::partial_eq::fail_with_differences, cmp, mode::Panic,
+    tracking::AssertionTracking, util::collection_diff::CollectionDiff,
+};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt::Debug;
+use core::fmt::Write;
+use core::ops::RangeBounds;
+use indoc::writedoc;
+
+/// Assertions for generic [`BTreeSet`]s.
+///
+/// Covers everything [`HashSetAssertions`](crate::prelude::HashSetAssertions) provides and adds
+/// assertions relying on the ordering of the set's elements.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait BTreeSetAssertions<'t, T, M: Mode> {
+    fn contains<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn does_not_contain<E>(self, not_expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    fn contains_all<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>;
+
+    /// Test that the set contains exactly the expected elements.
+    ///
+    /// Failures report missing and unexpected elements separately.
+    fn contains_exactly<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>;
+
+    /// Test that the set contains exactly the expected elements, given in ascending order.
+    ///
+    /// Fails if the expected elements are not listed in the order the set iterates them in.
+    fn contains_exactly_in_order<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>;
+
+    fn is_subset_of(self, expected_superset: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug;
+
+    fn is_superset_of(self, expected_subset: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug;
+
+    fn is_disjoint_from(self, other: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug;
+
+    /// Extract all elements lying in the given range, in ascending order.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) elements, on which all `Vec` assertions can
+    /// be used. Available in every mode. Fails for ranges starting after their end or excluding
+    /// both of their equal bounds, changing the subject to an empty `Vec`.
+    fn elements_in_range(self, range: impl RangeBounds<T>) -> AssertThat<'t, Vec<T>, M>
+    where
+        T: Ord + Clone + Debug;
+}
+
+impl<'t, T, M: Mode> BTreeSetAssertions<'t, T, M> for AssertThat<'t, BTreeSet<T>, M> {
+    #[track_caller]
+    fn contains<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        if !self
+            .actual()
+            .iter()
+            .any(|it| AssertrPartialEq::eq(it, &expected, None))
+        {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    does not contain expected: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn does_not_contain<E>(self, not_expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        if self
+            .actual()
+            .iter()
+            .any(|it| AssertrPartialEq::eq(it, &not_expected, None))
+        {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    contains unexpected: {not_expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_all<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        let elements_not_found = expected
+            .iter()
+            .filter(|expected| {
+                !self
+                    .actual()
+                    .iter()
+                    .any(|actual| AssertrPartialEq::eq(actual, expected, None))
+            })
+            .collect::<Vec<_>>();
+
+        if !elements_not_found.is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    does not contain all expected elements
+
+                    Expected: {expected:#?}
+
+                    Elements not found: {elements_not_found:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_exactly<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        let mut diff = CollectionDiff::for_set();
+
+        for expected_element in &expected {
+            if !self
+                .actual()
+                .iter()
+                .any(|actual| AssertrPartialEq::eq(actual, expected_element, None))
+            {
+                diff.missing.push(expected_element);
+            }
+        }
+        for actual_element in self.actual() {
+            if !expected
+                .iter()
+                .any(|expected| AssertrPartialEq::eq(actual_element, expected, None))
+            {
+                diff.unexpected.push(actual_element);
+            }
+        }
+
+        if !diff.is_empty() {
+            for message in diff.messages() {
+                self.add_detail_message(message);
+            }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    does not exactly contain expected elements
+
+                    Expected: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_exactly_in_order<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+
+        let first_mismatch = self
+            .actual()
+            .iter()
+            .zip(expected.iter())
+            .enumerate()
+            .find(|(_, (actual, expected))| !AssertrPartialEq::eq(*actual, *expected, None));
+
+        if first_mismatch.is_some() || self.actual().len() != expected.len() {
+            if self.actual().len() != expected.len() {
+                self.add_detail_message(format!(
+                    "Expected {} elements, but found {}",
+                    expected.len(),
+                    self.actual().len()
+                ));
+            }
+            if let Some((index, (actual, expected))) = first_mismatch {
+                self.add_detail_message(format!(
+                    "First mismatch at index {index}: expected {expected:#?}, found {actual:#?}"
+                ));
+            }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    does not exactly contain expected elements in order
+
+                    Expected: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_subset_of(self, expected_superset: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected_superset = expected_superset.borrow();
+        let elements_not_in_expected = self
+            .actual()
+            .difference(expected_superset)
+            .collect::<Vec<_>>();
+
+        if !elements_not_in_expected.is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    is not a subset of expected
+
+                    Expected superset: {expected_superset:#?}
+
+                    Elements not in expected: {elements_not_in_expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_superset_of(self, expected_subset: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected_subset = expected_subset.borrow();
+        let elements_not_in_actual = expected_subset
+            .difference(self.actual())
+            .collect::<Vec<_>>();
+
+        if !elements_not_in_actual.is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    is not a superset of expected
+
+                    Expected subset: {expected_subset:#?}
+
+                    Elements not in actual: {elements_not_in_actual:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_disjoint_from(self, other: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let other = other.borrow();
+        let overlapping_elements = self.actual().intersection(other).collect::<Vec<_>>();
+
+        if !overlapping_elements.is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    is not disjoint from expected
+
+                    Expected disjoint set: {other:#?}
+
+                    Overlapping elements: {overlapping_elements:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn elements_in_range(self, range: impl RangeBounds<T>) -> AssertThat<'t, Vec<T>, M>
+    where
+        T: Ord + Clone + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+
+        let Some(range) = check_btree_range(&self, range) else {
+            return self.map(|_actual| Actual::Owned(Vec::new()));
+        };
+        self.map(|actual| Actual::Owned(actual.borrowed().range(range).cloned().collect()))
+    }
+}
+
+/// Data-extracting assertions for [`BTreeSet`]s.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait BTreeSetExtractAssertions<'t, T> {
+    /// Test that the set is not empty and extract its smallest element.
+    ///
+    /// Only available in `Panic` mode, as the extracted `T` cannot be produced when the set is
+    /// empty.
+    fn first_element(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Debug;
+
+    /// Test that the set is not empty and extract its largest element.
+    ///
+    /// Only available in `Panic` mode, as the extracted `T` cannot be produced when the set is
+    /// empty.
+    fn last_element(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Debug;
+}
+
+impl<'t, T> BTreeSetExtractAssertions<'t, T> for AssertThat<'t, BTreeSet<T>, Panic> {
+    #[track_caller]
+    fn first_element(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Debug,
+    {
+        self.track_assertion();
+
+        if self.actual().is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    has no first element, as it is empty
+                ", actual = self.actual()}
+            });
+        }
+
+        self.map(|actual| match actual {
+            Actual::Owned(o) => Actual::Owned(o.into_iter().next().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.iter().next().unwrap()),
+        })
+    }
+
+    #[track_caller]
+    fn last_element(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Debug,
+    {
+        self.track_assertion();
+
+        if self.actual().is_empty() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: BTreeSet {actual:#?}
+
+                    has no last element, as it is empty
+                ", actual = self.actual()}
+            });
+        }
+
+        self.map(|actual| match actual {
+            Actual::Owned(o) => Actual::Owned(o.into_iter().next_back().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.iter().next_back().unwrap()),
+        })
+    }
+}
+
+/// Equality assertions for [`BTreeSet`]s, taking precedence over
+/// [`PartialEqAssertions::is_equal_to`](crate::prelude::PartialEqAssertions::is_equal_to).
+impl<T, M: Mode> AssertThat<'_, BTreeSet<T>, M> {
+    /// Test that the set is equal to `expected`.
+    ///
+    /// Failures list the missing and unexpected elements, in addition to both sets.
+    #[track_caller]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn is_equal_to(self, expected: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.borrow();
+        let mut ctx = EqContext::default();
+
+        if !cmp::btree_set::compare(self.actual(), expected, Some(&mut ctx)) {
+            fail_with_differences(&self, expected, &ctx);
+        }
+        self
+    }
+
+    /// Fluent alias for [`is_equal_to`](Self::is_equal_to).
+    #[cfg(feature = "fluent")]
+    #[track_caller]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn be_equal_to(self, expected: impl Borrow<BTreeSet<T>>) -> Self
+    where
+        T: Ord + Debug,
+    {
+        self.is_equal_to(expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod contains {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_expected_is_present() {
+            assert_that!(BTreeSet::from(["foo"])).contains("foo".to_owned());
+        }
+
+        #[test]
+        fn panics_when_expected_is_absent() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from(["foo"]))
+                    .with_location(false)
+                    .contains("bar");
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeSet {{
+                        "foo",
+                    }}
+
+                    does not contain expected: "bar"
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod does_not_contain {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn succeeds_when_unexpected_is_absent() {
+            assert_that!(BTreeSet::from(["foo"])).does_not_contain("bar");
+        }
+
+        #[test]
+        fn fails_when_unexpected_is_present() {
+            assert_that!(BTreeSet::from(["foo"]))
+                .with_capture()
+                .does_not_contain("foo")
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod contains_all {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn succeeds_when_all_expected_values_are_present() {
+            assert_that!(BTreeSet::from([1, 2, 3])).contains_all([3, 1]);
+        }
+    }
+
+    mod contains_exactly {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_elements_match_in_any_order() {
+            assert_that!(BTreeSet::from([1, 2, 3])).contains_exactly([3, 1, 2]);
+        }
+
+        #[test]
+        fn panics_with_missing_and_unexpected_elements() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from([1, 2]))
+                    .with_location(false)
+                    .contains_exactly([2, 3]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeSet {{
+                        1,
+                        2,
+                    }}
+
+                    does not exactly contain expected elements
+
+                    Expected: [
+                        2,
+                        3,
+                    ]
+
+                    Details: [
+                        Missing elements: [
+                            3,
+                        ],
+                        Unexpected elements: [
+                            1,
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod contains_exactly_in_order {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_elements_match_in_order() {
+            assert_that!(BTreeSet::from([3, 1, 2])).contains_exactly_in_order([1, 2, 3]);
+        }
+
+        #[test]
+        fn panics_when_elements_are_not_in_order() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from([1, 2]))
+                    .with_location(false)
+                    .contains_exactly_in_order([2, 1]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {"
+                    -------- assertr --------
+                    Actual: BTreeSet {{
+                        1,
+                        2,
+                    }}
+
+                    does not exactly contain expected elements in order
+
+                    Expected: [
+                        2,
+                        1,
+                    ]
+
+                    Details: [
+                        First mismatch at index 0: expected 2, found 1,
+                    ]
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod is_equal_to {
+        use std::collections::BTreeSet;
+
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_equal() {
+            assert_that!(BTreeSet::from(["foo", "bar"]))
+                .is_equal_to(BTreeSet::from(["bar", "foo"]));
+        }
+
+        #[test]
+        fn accepts_a_borrowed_set() {
+            let expected = BTreeSet::from(["foo"]);
+            assert_that!(BTreeSet::from(["foo"])).is_equal_to(&expected);
+        }
+
+        #[test]
+        fn panics_with_missing_and_unexpected_elements() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from(["foo"]))
+                    .with_location(false)
+                    .is_equal_to(BTreeSet::from(["bar"]));
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected: {{
+                        "bar",
+                    }}
+
+                      Actual: {{
+                        "foo",
+                    }}
+
+                    Details: [
+                        Differences: [
+                            Missing elements: [
+                                "bar",
+                            ],
+                            Unexpected elements: [
+                                "foo",
+                            ],
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_subset_of {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_with_borrowed_actual_and_expected_sets() {
+            let actual = BTreeSet::from(["foo"]);
+            let expected = BTreeSet::from(["foo", "bar"]);
+            assert_that!(&actual).is_subset_of(&expected);
+        }
+
+        #[test]
+        fn panics_when_actual_contains_extra_elements() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from(["bar", "foo"]))
+                    .with_location(false)
+                    .is_subset_of(BTreeSet::from(["foo"]));
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: BTreeSet {{
+                        "bar",
+                        "foo",
+                    }}
+
+                    is not a subset of expected
+
+                    Expected superset: {{
+                        "foo",
+                    }}
+
+                    Elements not in expected: [
+                        "bar",
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_superset_of {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn succeeds_when_actual_is_superset() {
+            assert_that!(BTreeSet::from([1, 2])).is_superset_of(BTreeSet::from([2]));
+        }
+
+        #[test]
+        fn fails_when_elements_are_missing() {
+            assert_that!(BTreeSet::from([1, 2]))
+                .with_capture()
+                .is_superset_of(BTreeSet::from([3]))
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_disjoint_from {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn succeeds_when_sets_do_not_overlap() {
+            assert_that!(BTreeSet::from([1, 2])).is_disjoint_from(BTreeSet::from([3]));
+        }
+
+        #[test]
+        fn fails_when_sets_overlap() {
+            assert_that!(BTreeSet::from([1, 2]))
+                .with_capture()
+                .is_disjoint_from(BTreeSet::from([2, 3]))
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod elements_in_range {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_elements_in_range_in_ascending_order() {
+            assert_that!(BTreeSet::from([9, 1, 5, 3]))
+                .elements_in_range(2..=5)
+                .contains_exactly([3, 5]);
+        }
+
+        #[test]
+        #[allow(clippy::reversed_empty_ranges)]
+        fn panics_for_range_starting_after_its_end() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::from([1, 5]))
+                    .with_location(false)
+                    .elements_in_range(5..=2);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {"
+                -------- assertr --------
+                Range: 5..=2
+
+                is not a valid range, as it starts after its end
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod first_element {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_smallest_element() {
+            let set = BTreeSet::from(["b", "a", "c"]);
+            assert_that!(&set).first_element().is_equal_to("a");
+            assert_that!(set).first_element().is_equal_to("a");
+        }
+
+        #[test]
+        fn panics_when_empty() {
+            assert_that_panic_by(|| {
+                assert_that!(BTreeSet::<i32>::new())
+                    .with_location(false)
+                    .first_element();
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {"
+                    -------- assertr --------
+                    Actual: BTreeSet {{}}
+
+                    has no first element, as it is empty
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod last_element {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn extracts_largest_element() {
+            let set = BTreeSet::from(["b", "a", "c"]);
+            assert_that!(&set).last_element().is_equal_to("c");
+            assert_that!(set).last_element().is_equal_to("c");
+        }
+    }
+
+    mod has_length {
+        use crate::prelude::*;
+        use alloc::collections::BTreeSet;
+
+        #[test]
+        fn works_on_owned_and_borrowed_sets() {
+            let set = BTreeSet::from([1, 2, 3]);
+            assert_that!(&set).has_length(3);
+            assert_that!(set).has_length(3);
+            assert_that!(BTreeSet::<i32>::new()).is_empty();
+        }
+    }
+}
@@ -0,0 +1,640 @@
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+
+use crate::util::duplicates::find_duplicates_by_eq;
+#[cfg(feature = "std")]
+use crate::util::duplicates::{find_duplicates, find_duplicates_by_key};
+use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+
+/// Assertions on duplicated elements of slices, arrays, `Vec`s and `VecDeque`s.
+///
+/// Failures list every duplicate with the indices it occurs at. Use the assertions of the same
+/// name in `IteratorAssertions` for iterators.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait DuplicatesAssertions<'t, T> {
+    /// Test that no element occurs more than once. Elements are compared using their `Hash` and
+    /// `Eq` implementations.
+    ///
+    /// Requires the `std` feature. Use `has_no_duplicates_by_eq` for element types which cannot be
+    /// hashed, or without `std`.
+    #[cfg(feature = "std")]
+    fn has_no_duplicates(self) -> Self
+    where
+        T: Eq + core::hash::Hash + Debug;
+
+    /// Test that at least one element occurs more than once. Elements are compared using their
+    /// `Hash` and `Eq` implementations.
+    ///
+    /// Requires the `std` feature. Use `has_duplicates_by_eq` for element types which cannot be
+    /// hashed, or without `std`.
+    #[cfg(feature = "std")]
+    fn has_duplicates(self) -> Self
+    where
+        T: Eq + core::hash::Hash + Debug;
+
+    /// Test that no element occurs more than once. Elements are compared pairwise using
+    /// `AssertrPartialEq`, which takes O(n²) comparisons.
+    fn has_no_duplicates_by_eq(self) -> Self
+    where
+        T: AssertrPartialEq + Debug;
+
+    /// Test that at least one element occurs more than once. Elements are compared pairwise using
+    /// `AssertrPartialEq`, which takes O(n²) comparisons.
+    fn has_duplicates_by_eq(self) -> Self
+    where
+        T: AssertrPartialEq + Debug;
+
+    /// Test that the key extracted from every element is unique. Keys are compared using their
+    /// `Hash` and `Eq` implementations.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    fn has_unique_by_key<K>(self, key: impl Fn(&T) -> K) -> Self
+    where
+        T: Debug,
+        K: Eq + core::hash::Hash + Debug;
+}
+
+#[track_caller]
+fn fail_with_duplicates<S: Debug, M: Mode>(this: &AssertThat<'_, S, M>, duplicates: &[impl Debug]) {
+    this.fail(|w: &mut String| {
+        writedoc! {w, r"
+            Actual: {actual:#?}
+
+            was expected to contain no duplicates, but it does!
+
+            Duplicates: {duplicates:#?}
+        ", actual = this.actual()}
+    });
+}
+
+#[track_caller]
+fn fail_without_duplicates<S: Debug, M: Mode>(this: &AssertThat<'_, S, M>) {
+    this.fail(|w: &mut String| {
+        writedoc! {w, r"
+            Actual: {actual:#?}
+
+            was expected to contain duplicates, but all elements are unique!
+        ", actual = this.actual()}
+    });
+}
+
+/// Implements the duplicate assertions for each given subject type, accessing its elements
+/// through its `iter()` method.
+macro_rules! impl_duplicates_assertions {
+    ($([$($generics:tt)*] $subject:ty),* $(,)?) => {
+        $(
+            impl<'t, $($generics)*, M: Mode> DuplicatesAssertions<'t, T> for AssertThat<'t, $subject, M> {
+                #[cfg(feature = "std")]
+                #[track_caller]
+                fn has_no_duplicates(self) -> Self
+                where
+                    T: Eq + core::hash::Hash + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let duplicates = find_duplicates(self.actual().iter());
+                    if !duplicates.is_empty() {
+                        fail_with_duplicates(&self, &duplicates);
+                    }
+                    self
+                }
+
+                #[cfg(feature = "std")]
+                #[track_caller]
+                fn has_duplicates(self) -> Self
+                where
+                    T: Eq + core::hash::Hash + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    if find_duplicates(self.actual().iter()).is_empty() {
+                        fail_without_duplicates(&self);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn has_no_duplicates_by_eq(self) -> Self
+                where
+                    T: AssertrPartialEq + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let duplicates = find_duplicates_by_eq(self.actual().iter());
+                    if !duplicates.is_empty() {
+                        fail_with_duplicates(&self, &duplicates);
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn has_duplicates_by_eq(self) -> Self
+                where
+                    T: AssertrPartialEq + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    if find_duplicates_by_eq(self.actual().iter()).is_empty() {
+                        fail_without_duplicates(&self);
+                    }
+                    self
+                }
+
+                #[cfg(feature = "std")]
+                #[track_caller]
+                fn has_unique_by_key<K>(self, key: impl Fn(&T) -> K) -> Self
+                where
+                    T: Debug,
+                    K: Eq + core::hash::Hash + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let duplicates = find_duplicates_by_key(self.actual().iter(), |it| key(it));
+                    if !duplicates.is_empty() {
+                        let actual = self.actual();
+                        self.fail(|w: &mut String| {
+                            writedoc! {w, r"
+                                Actual: {actual:#?}
+
+                                was expected to have unique keys, but it does not!
+
+                                Duplicate keys: {duplicates:#?}
+                            "}
+                        });
+                    }
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_duplicates_assertions!(
+    [T] &[T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+);
+
+#[cfg(test)]
+mod tests {
+    mod slice {
+        mod has_no_duplicates {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!([1, 2, 3].as_slice()).has_no_duplicates();
+            }
+
+            #[test]
+            fn succeeds_when_empty() {
+                assert_that!(Vec::<i32>::new().as_slice()).has_no_duplicates();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!(["a", "b", "a", "c", "b", "a"].as_slice())
+                        .with_location(false)
+                        .has_no_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                        -------- assertr --------
+                        Actual: [
+                            "a",
+                            "b",
+                            "a",
+                            "c",
+                            "b",
+                            "a",
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            "a" at indices [0, 2, 5],
+                            "b" at indices [1, 4],
+                        ]
+                        -------- assertr --------
+                    "#});
+            }
+        }
+
+        mod has_duplicates {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!([1, 2, 1].as_slice()).has_duplicates();
+            }
+
+            #[test]
+            fn panics_when_all_elements_are_unique() {
+                assert_that_panic_by(|| {
+                    assert_that!([1, 2, 3].as_slice())
+                        .with_location(false)
+                        .has_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            1,
+                            2,
+                            3,
+                        ]
+
+                        was expected to contain duplicates, but all elements are unique!
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_no_duplicates_by_eq {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!([1.0, 2.0, 3.0].as_slice()).has_no_duplicates_by_eq();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!([0.5, 1.5, 0.5].as_slice())
+                        .with_location(false)
+                        .has_no_duplicates_by_eq();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            0.5,
+                            1.5,
+                            0.5,
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            0.5 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_duplicates_by_eq {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!([1.0, 2.0, 1.0].as_slice()).has_duplicates_by_eq();
+            }
+
+            #[test]
+            fn fails_when_all_elements_are_unique() {
+                assert_that!([1.0, 2.0, 3.0].as_slice())
+                    .with_capture()
+                    .has_duplicates_by_eq()
+                    .capture_failures()
+                    .must()
+                    .have_length(1);
+            }
+        }
+
+        mod has_unique_by_key {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[derive(Debug)]
+            struct User {
+                id: u32,
+                name: &'static str,
+            }
+
+            #[test]
+            fn succeeds_when_all_keys_are_unique() {
+                let users = [
+                    User {
+                        id: 1,
+                        name: "Alice",
+                    },
+                    User {
+                        id: 2,
+                        name: "Alice",
+                    },
+                ];
+                assert_that!(users.as_slice()).has_unique_by_key(|user| user.id);
+            }
+
+            #[test]
+            fn succeeds_with_composite_keys() {
+                let users = [
+                    User {
+                        id: 1,
+                        name: "Alice",
+                    },
+                    User { id: 1, name: "Bob" },
+                ];
+                assert_that!(users.as_slice()).has_unique_by_key(|user| (user.id, user.name));
+            }
+
+            #[test]
+            fn panics_listing_every_duplicated_key_with_its_indices() {
+                assert_that_panic_by(|| {
+                    let users = [
+                        User {
+                            id: 1,
+                            name: "Alice",
+                        },
+                        User { id: 2, name: "Bob" },
+                        User {
+                            id: 1,
+                            name: "Carol",
+                        },
+                    ];
+                    assert_that!(users.as_slice())
+                        .with_location(false)
+                        .has_unique_by_key(|user| user.id);
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                        -------- assertr --------
+                        Actual: [
+                            User {{
+                                id: 1,
+                                name: "Alice",
+                            }},
+                            User {{
+                                id: 2,
+                                name: "Bob",
+                            }},
+                            User {{
+                                id: 1,
+                                name: "Carol",
+                            }},
+                        ]
+
+                        was expected to have unique keys, but it does not!
+
+                        Duplicate keys: [
+                            1 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "#});
+            }
+        }
+    }
+
+    mod array {
+        mod has_no_duplicates {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!([1, 2, 3]).has_no_duplicates();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!([1, 2, 1])
+                        .with_location(false)
+                        .has_no_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            1,
+                            2,
+                            1,
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            1 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_duplicates {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!(["a", "b", "a"]).has_duplicates();
+            }
+        }
+
+        mod has_no_duplicates_by_eq {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_for_unhashable_elements() {
+                assert_that!([0.1, 0.2, 0.3]).has_no_duplicates_by_eq();
+            }
+
+            #[test]
+            fn detects_duplicates_of_unhashable_elements() {
+                assert_that!([0.1, 0.2, 0.1]).has_duplicates_by_eq();
+            }
+        }
+
+        mod has_unique_by_key {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_all_keys_are_unique() {
+                assert_that!(["a", "bb", "ccc"]).has_unique_by_key(|it| it.len());
+            }
+
+            #[test]
+            fn fails_when_a_key_is_duplicated() {
+                assert_that!(["a", "bb", "c"])
+                    .with_capture()
+                    .has_unique_by_key(|it| it.len())
+                    .capture_failures()
+                    .must()
+                    .have_length(1);
+            }
+        }
+    }
+
+    mod vec {
+        mod has_no_duplicates {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!(vec![1, 2, 3]).has_no_duplicates();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!(vec![1, 2, 1])
+                        .with_location(false)
+                        .has_no_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            1,
+                            2,
+                            1,
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            1 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_duplicates {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!(vec!["a", "b", "a"]).has_duplicates();
+            }
+        }
+
+        mod has_no_duplicates_by_eq {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_for_unhashable_elements() {
+                assert_that!(vec![0.1, 0.2, 0.3]).has_no_duplicates_by_eq();
+            }
+
+            #[test]
+            fn detects_duplicates_of_unhashable_elements() {
+                assert_that!(vec![0.1, 0.2, 0.1]).has_duplicates_by_eq();
+            }
+        }
+
+        mod has_unique_by_key {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_all_keys_are_unique() {
+                assert_that!(vec!["a", "bb", "ccc"]).has_unique_by_key(|it| it.len());
+            }
+
+            #[test]
+            fn fails_when_a_key_is_duplicated() {
+                assert_that!(vec!["a", "bb", "c"])
+                    .with_capture()
+                    .has_unique_by_key(|it| it.len())
+                    .capture_failures()
+                    .must()
+                    .have_length(1);
+            }
+        }
+    }
+
+    mod vec_deque {
+        mod has_no_duplicates {
+            use crate::prelude::*;
+            use alloc::collections::VecDeque;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!(VecDeque::from([1, 2, 3])).has_no_duplicates();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!(VecDeque::from([1, 2, 1]))
+                        .with_location(false)
+                        .has_no_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            1,
+                            2,
+                            1,
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            1 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_duplicates {
+            use crate::prelude::*;
+            use alloc::collections::VecDeque;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!(VecDeque::from(["a", "b", "a"])).has_duplicates();
+            }
+        }
+
+        mod has_no_duplicates_by_eq {
+            use crate::prelude::*;
+            use alloc::collections::VecDeque;
+
+            #[test]
+            fn succeeds_for_unhashable_elements() {
+                assert_that!(VecDeque::from([0.1, 0.2, 0.3])).has_no_duplicates_by_eq();
+            }
+
+            #[test]
+            fn detects_duplicates_of_unhashable_elements() {
+                assert_that!(VecDeque::from([0.1, 0.2, 0.1])).has_duplicates_by_eq();
+            }
+        }
+
+        mod has_unique_by_key {
+            use crate::prelude::*;
+            use alloc::collections::VecDeque;
+
+            #[test]
+            fn succeeds_when_all_keys_are_unique() {
+                assert_that!(VecDeque::from(["a", "bb", "ccc"])).has_unique_by_key(|it| it.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::{AssertThat, Mode, actual::Actual};
+
+/// Data-extracting assertions for slice-like collections.
+///
+/// These replace the subject with a `Vec` of values extracted from every element, allowing all
+/// `Vec` assertions to be used on the projected values. Extraction never fails and is therefore
+/// available in every mode.
+///
+/// Elements are only ever handed out by reference, so extraction works on borrowed and owned
+/// subjects alike. The collection itself is never cloned.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait ExtractingAssertions<'t, T, M: Mode> {
+    /// Extract a single value from every element.
+    ///
+    /// ```rust
+    /// use assertr::prelude::*;
+    ///
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let people = vec![
+    ///     Person { name: "Alice", age: 42 },
+    ///     Person { name: "Bob", age: 21 },
+    /// ];
+    ///
+    /// assert_that!(&people)
+    ///     .extracting(|person| person.name)
+    ///     .contains_exactly(["Alice", "Bob"]);
+    /// ```
+    fn extracting<U>(self, extractor: impl FnMut(&T) -> U) -> AssertThat<'t, Vec<U>, M>;
+
+    /// Extract any number of values from every element, flattening them into a single `Vec`.
+    ///
+    /// The returned collections must not borrow from the element they were extracted from.
+    /// Return an owned collection (or an `Option`) instead of, for example, a borrowing iterator.
+    fn flat_extracting<U, I>(self, extractor: impl FnMut(&T) -> I) -> AssertThat<'t, Vec<U>, M>
+    where
+        I: IntoIterator<Item = U>;
+}
+
+impl<'t, T, M: Mode> ExtractingAssertions<'t, T, M> for AssertThat<'t, &[T], M> {
+    fn extracting<U>(self, extractor: impl FnMut(&T) -> U) -> AssertThat<'t, Vec<U>, M> {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().map(extractor).collect()))
+    }
+
+    fn flat_extracting<U, I>(self, extractor: impl FnMut(&T) -> I) -> AssertThat<'t, Vec<U>, M>
+    where
+        I: IntoIterator<Item = U>,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().flat_map(extractor).collect()))
+    }
+}
+
+impl<'t, T, const N: usize, M: Mode> ExtractingAssertions<'t, T, M> for AssertThat<'t, [T; N], M> {
+    fn extracting<U>(self, extractor: impl FnMut(&T) -> U) -> AssertThat<'t, Vec<U>, M> {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().map(extractor).collect()))
+    }
+
+    fn flat_extracting<U, I>(self, extractor: impl FnMut(&T) -> I) -> AssertThat<'t, Vec<U>, M>
+    where
+        I: IntoIterator<Item = U>,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().flat_map(extractor).collect()))
+    }
+}
+
+impl<'t, T, M: Mode> ExtractingAssertions<'t, T, M> for AssertThat<'t, Vec<T>, M> {
+    fn extracting<U>(self, extractor: impl FnMut(&T) -> U) -> AssertThat<'t, Vec<U>, M> {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().map(extractor).collect()))
+    }
+
+    fn flat_extracting<U, I>(self, extractor: impl FnMut(&T) -> I) -> AssertThat<'t, Vec<U>, M>
+    where
+        I: IntoIterator<Item = U>,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().flat_map(extractor).collect()))
+    }
+}
+
+impl<'t, T, M: Mode> ExtractingAssertions<'t, T, M> for AssertThat<'t, VecDeque<T>, M> {
+    fn extracting<U>(self, extractor: impl FnMut(&T) -> U) -> AssertThat<'t, Vec<U>, M> {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().map(extractor).collect()))
+    }
+
+    fn flat_extracting<U, I>(self, extractor: impl FnMut(&T) -> I) -> AssertThat<'t, Vec<U>, M>
+    where
+        I: IntoIterator<Item = U>,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().iter().flat_map(extractor).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    struct Person {
+        name: &'static str,
+        age: u32,
+        pets: Vec<&'static str>,
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person {
+                name: "Alice",
+                age: 42,
+                pets: vec!["Cat", "Dog"],
+            },
+            Person {
+                name: "Bob",
+                age: 21,
+                pets: vec![],
+            },
+            Person {
+                name: "Carol",
+                age: 33,
+                pets: vec!["Fish"],
+            },
+        ]
+    }
+
+    mod extracting {
+        use super::{Person, people};
+        use crate::prelude::*;
+        use alloc::collections::VecDeque;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_from_borrowed_vec() {
+            let people = people();
+            assert_that!(&people)
+                .extracting(|person| person.name)
+                .contains_exactly(["Alice", "Bob", "Carol"]);
+
+            // The original subject is still usable after extracting from it.
+            assert_that!(people).has_length(3);
+        }
+
+        #[test]
+        fn extracts_from_owned_vec() {
+            assert_that!(people())
+                .extracting(|person| person.age)
+                .contains_exactly([42, 21, 33]);
+        }
+
+        #[test]
+        fn extracts_from_slice() {
+            let people = people();
+            assert_that!(people.as_slice())
+                .extracting(|person| person.name.to_owned())
+                .contains_exactly(["Alice", "Bob", "Carol"]);
+        }
+
+        #[test]
+        fn extracts_from_array() {
+            assert_that!([(1, 'a'), (2, 'b'), (3, 'c')])
+                .extracting(|(_, c)| *c)
+                .contains_exactly(['a', 'b', 'c']);
+        }
+
+        #[test]
+        fn extracts_from_vec_deque() {
+            let people: VecDeque<Person> = people().into_iter().collect();
+            assert_that!(people)
+                .extracting(|person| person.age)
+                .contains_exactly_in_any_order([21, 33, 42]);
+        }
+
+        #[test]
+        fn extracts_nothing_from_empty_collection() {
+            assert_that!(Vec::<Person>::new())
+                .extracting(|person| person.age)
+                .is_empty();
+        }
+
+        #[test]
+        fn panics_when_extracted_values_do_not_match() {
+            assert_that_panic_by(|| {
+                assert_that!(people())
+                    .with_location(false)
+                    .extracting(|person| person.name)
+                    .contains_exactly(["Alice", "Bob"]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Actual: [
+                    "Alice",
+                    "Bob",
+                    "Carol",
+                ],
+
+                did not exactly match
+
+                Expected: [
+                    "Alice",
+                    "Bob",
+                ]
+
+                Details: [
+                    Elements not expected: [
+                        "Carol",
+                    ],
+                ]
+                -------- assertr --------
+            "#});
+        }
+
+        #[test]
+        fn keeps_capturing_failures_after_extraction() {
+            let failures = assert_that!(people())
+                .with_capture()
+                .with_location(false)
+                .extracting(|person| person.age)
+                .contains(99)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Actual: [
+                    42,
+                    21,
+                    33,
+                ]
+
+                does not contain expected: 99
+                -------- assertr --------
+            "}]);
+        }
+
+        #[test]
+        fn can_be_used_on_derived_assertions() {
+            assert_that!(people()).has_length(3).satisfies_ref(
+                |it| it.as_slice(),
+                |slice: AssertThat<&[Person], _>| {
+                    slice.extracting(|person| person.age).contains(42);
+                },
+            );
+        }
+    }
+
+    mod flat_extracting {
+        use super::people;
+        use crate::prelude::*;
+
+        #[test]
+        fn flattens_extracted_values_from_vec() {
+            assert_that!(people())
+                .flat_extracting(|person| person.pets.clone())
+                .contains_exactly(["Cat", "Dog", "Fish"]);
+        }
+
+        #[test]
+        fn flattens_extracted_values_from_slice() {
+            let people = people();
+            assert_that!(people.as_slice())
+                .flat_extracting(|person| {
+                    person.pets.iter().map(|pet| pet.len()).collect::<Vec<_>>()
+                })
+                .contains_exactly([3, 3, 4]);
+        }
+
+        #[test]
+        fn flattens_extracted_values_from_array() {
+            assert_that!([[1, 2], [3, 4]])
+                .flat_extracting(|inner| *inner)
+                .contains_exactly([1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn flattens_optional_values() {
+            assert_that!(vec![Some(1), None, Some(3)])
+                .flat_extracting(|it| *it)
+                .contains_exactly([1, 3]);
+        }
+    }
+}
@@ -1,12 +1,25 @@
 pub mod boxed;
+pub mod btree_map;
+pub mod btree_set;
+pub mod duplicates;
+pub mod extracting;
 pub mod panic_value;
+pub mod rc;
 pub mod string;
 pub mod vec;
 pub mod vec_deque;
 
 pub mod prelude {
     pub use super::boxed::BoxAssertions;
-    pub use super::panic_value::PanicValueAssertions;
+    pub use super::btree_map::{BTreeMapAssertions, BTreeMapExtractAssertions};
+    pub use super::btree_set::{BTreeSetAssertions, BTreeSetExtractAssertions};
+    pub use super::duplicates::DuplicatesAssertions;
+    pub use super::extracting::ExtractingAssertions;
+    pub use super::panic_value::{
+        PanicValueAssertions, PanicValueLocationAssertions, PanicValueMessageAssertions,
+        PanicValueTypeAssertions,
+    };
+    pub use super::rc::RefCountedAssertions;
     pub use super::string::StringAssertions;
     pub use super::vec::VecAssertions;
     pub use super::vec_deque::VecDequeAssertions;
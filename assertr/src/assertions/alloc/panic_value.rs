@@ -1,7 +1,9 @@
-use crate::{AssertThat, PanicValue, actual::Actual, mode::Panic, tracking::AssertionTracking};
-use alloc::borrow::Cow;
+use crate::{
+    AssertThat, PanicLocation, PanicValue, actual::Actual, failure::strip_banner, mode::Mode,
+    tracking::AssertionTracking,
+};
 use alloc::boxed::Box;
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use core::any::{Any, type_name, type_name_of_val};
 use core::fmt::Write;
 use indoc::writedoc;
@@ -9,17 +11,22 @@ use indoc::writedoc;
 use super::boxed::BoxAssertions;
 
 /// Data-extracting assertions for `PanicValue`'s, the output of a panic occurred within an `assert_that_panic_by`.
-/// Only available in Panic mode, as the extracted type cannot be produced when the downcast fails.
+/// In `Capture` mode, the subject is poisoned when the extraction fails, skipping all subsequent
+/// assertions.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait PanicValueAssertions<'t> {
-    fn has_type<E: 'static>(self) -> AssertThat<'t, E, Panic>;
+pub trait PanicValueAssertions<'t, M: Mode> {
+    fn has_type<E: 'static>(self) -> AssertThat<'t, E, M>;
 
-    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, Panic>;
+    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, M>;
+
+    /// Test if the panic was raised by a failed assertr assertion and change the subject to its
+    /// failure text, which excludes the surrounding banners and the location of the assertion.
+    fn is_assertr_failure(self) -> AssertThat<'t, String, M>;
 }
 
-impl<'t> PanicValueAssertions<'t> for AssertThat<'t, PanicValue, Panic> {
+impl<'t, M: Mode> PanicValueAssertions<'t, M> for AssertThat<'t, PanicValue, M> {
     #[track_caller]
-    fn has_type<E: 'static>(self) -> AssertThat<'t, E, Panic> {
+    fn has_type<E: 'static>(self) -> AssertThat<'t, E, M> {
         self.map::<Box<dyn Any>>(|it| match it {
             Actual::Borrowed(b) => Actual::Borrowed(&b.0),
             Actual::Owned(o) => Actual::Owned(o.0),
@@ -28,40 +35,362 @@ impl<'t> PanicValueAssertions<'t> for AssertThat<'t, PanicValue, Panic> {
     }
 
     #[track_caller]
-    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, Panic> {
+    fn has_type_ref<E: 'static>(&'t self) -> AssertThat<'t, &'t E, M> {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.derive_poisoned();
+        }
 
         let any = &self.actual().0;
         if let Some(casted) = any.downcast_ref::<E>() {
             self.derive(|_actual| casted)
         } else {
-            let expected_type_name = type_name::<E>();
+            let actual_type_name = actual_type_name(&**any);
+            if actual_type_name.is_none() {
+                self.add_detail_message(ERASED_TYPE_DETAIL);
+            }
+            self.fail_extraction(|w: &mut String| {
+                write_type_mismatch::<E>(w, &**any, actual_type_name)
+            });
+            self.derive_poisoned()
+        }
+    }
 
-            let is_str = any.downcast_ref::<&str>().is_some();
-            let is_string = any.downcast_ref::<String>().is_some();
+    #[track_caller]
+    fn is_assertr_failure(self) -> AssertThat<'t, String, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
-            let actual_type_name = if is_str {
-                Cow::Borrowed("&str")
-            } else if is_string {
-                Cow::Borrowed("String")
-            } else {
-                // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
-                self.add_detail_message("The panic value can only be captured as Box<dyn Any>, meaning that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.");
-                Cow::Borrowed(type_name_of_val(&*self.actual().0))
-            };
+        let Some(message) = panic_message(&*self.actual().0) else {
+            self.add_detail_message(ERASED_TYPE_DETAIL);
+            self.fail_extraction(|w: &mut String| write_not_a_message(w, &*self.actual().0));
+            return self.poison();
+        };
+        let Some(failure) = strip_banner(message) else {
+            self.fail_extraction(|w: &mut String| write_not_an_assertr_failure(w, message));
+            return self.poison();
+        };
+        let failure = failure.to_string();
+        self.map(|_actual| Actual::Owned(failure))
+    }
+}
 
-            self.fail(|w: &mut String| {
+/// Non-extracting assertions for `PanicValue`'s.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait PanicValueTypeAssertions<'t, M: Mode> {
+    /// Test if the panic value is of type `E`, then run additional assertions on the value.
+    fn has_type_satisfying<E: 'static, A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>);
+}
+
+impl<'t, M: Mode> PanicValueTypeAssertions<'t, M> for AssertThat<'t, PanicValue, M> {
+    #[track_caller]
+    fn has_type_satisfying<E: 'static, A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let any = &*self.actual().0;
+        if !any.is::<E>() && actual_type_name(any).is_none() {
+            self.add_detail_message(ERASED_TYPE_DETAIL);
+        }
+        self.satisfies_extracted(
+            |it| it.0.downcast_ref::<E>(),
+            |actual, w| write_type_mismatch::<E>(w, &*actual.0, actual_type_name(&*actual.0)),
+            assertions,
+        )
+    }
+}
+
+/// Assertions on the message of a `PanicValue`.
+/// These work in any mode (Panic or Capture).
+///
+/// Panic values of type `&'static str` and `String` are both treated as messages, which covers
+/// every `panic!` invocation: literal messages are passed as `&'static str`, while formatted ones
+/// (`fmt::Arguments`) end up as a `String`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait PanicValueMessageAssertions<'t, M: Mode> {
+    /// Test if the panic message equals `expected`.
+    fn has_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the panic message contains `expected`.
+    fn has_message_containing(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the panic message matches the regular expression `pattern`.
+    ///
+    /// The pattern may match any part of the message. Use `^` and `$` to match the whole message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    fn has_message_matching(self, pattern: impl AsRef<str>) -> Self;
+
+    /// Test if the panic was raised by a failed assertr assertion, then run additional assertions
+    /// on its failure text, which excludes the surrounding banners and the location of the
+    /// assertion.
+    fn is_assertr_failure_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a str, M>);
+}
+
+impl<'t, M: Mode> PanicValueMessageAssertions<'t, M> for AssertThat<'t, PanicValue, M> {
+    #[track_caller]
+    fn has_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.as_ref();
+        match panic_message(&*self.actual().0) {
+            Some(actual) if actual == expected => {}
+            Some(actual) => self.fail(|w: &mut String| {
                 writedoc! {w, r"
-                    Expected panic value type: {expected_type_name}
+                    Expected panic message: {expected:?}
 
-                      Actual panic value type: {actual_type_name}
+                      Actual panic message: {actual:?}
                 "}
+            }),
+            None => fail_not_a_message(&self),
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_message_containing(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.as_ref();
+        match panic_message(&*self.actual().0) {
+            Some(actual) if actual.contains(expected) => {}
+            Some(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual panic message: {actual:?}
+
+                    does not contain
+
+                    Expected: {expected:?}
+                "}
+            }),
+            None => fail_not_a_message(&self),
+        }
+        self
+    }
+
+    #[cfg(feature = "regex")]
+    #[track_caller]
+    fn has_message_matching(self, pattern: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let pattern = pattern.as_ref();
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|err| panic!("Invalid regular expression {pattern:?}: {err}"));
+        match panic_message(&*self.actual().0) {
+            Some(actual) if regex.is_match(actual) => {}
+            Some(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual panic message: {actual:?}
+
+                    does not match
+
+                    Pattern: {pattern:?}
+                "}
+            }),
+            None => fail_not_a_message(&self),
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_assertr_failure_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a str, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let any = &*self.actual().0;
+        if panic_message(any).is_none() {
+            self.add_detail_message(ERASED_TYPE_DETAIL);
+        }
+        self.satisfies_extracted(
+            |it| panic_message(&*it.0).and_then(strip_banner),
+            |actual, w| match panic_message(&*actual.0) {
+                Some(message) => write_not_an_assertr_failure(w, message),
+                None => write_not_a_message(w, &*actual.0),
+            },
+            assertions,
+        )
+    }
+}
+
+/// Assertions on the location a panic occurred at.
+/// These work in any mode (Panic or Capture).
+///
+/// The location is recorded by `assert_that_panic_by` and `assert_that_panic_by_async`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait PanicValueLocationAssertions<'t, M: Mode> {
+    /// Test if the panic occurred in the file `expected`.
+    ///
+    /// The recorded path depends on the directory the crate was compiled from, e.g. `src/lib.rs`
+    /// or `my-crate/src/lib.rs` when part of a workspace. `expected` therefore also matches when
+    /// it is a trailing part of the recorded path.
+    #[cfg_attr(feature = "fluent", fluent_alias("have_panicked_at_file"))]
+    fn panicked_at_file(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the panic occurred at line `expected`.
+    #[cfg_attr(feature = "fluent", fluent_alias("have_panicked_at_line"))]
+    fn panicked_at_line(self, expected: u32) -> Self;
+}
+
+impl<'t, M: Mode> PanicValueLocationAssertions<'t, M> for AssertThat<'t, PanicValue, M> {
+    #[track_caller]
+    fn panicked_at_file(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.as_ref();
+        let location = self.actual().location();
+        if !location.is_some_and(|it| is_same_file(&it.file, expected)) {
+            if location.is_none() {
+                self.add_detail_message(UNKNOWN_LOCATION_DETAIL);
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected panic in file: {expected:?}
+
+                      Actual panic location: {location}
+                ", location = DisplayLocation(location)}
             });
-            unreachable!("Panic mode always panics on fail")
+        }
+        self
+    }
+
+    #[track_caller]
+    fn panicked_at_line(self, expected: u32) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let location = self.actual().location();
+        if location.is_none_or(|it| it.line != expected) {
+            if location.is_none() {
+                self.add_detail_message(UNKNOWN_LOCATION_DETAIL);
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected panic at line: {expected}
+
+                      Actual panic location: {location}
+                ", location = DisplayLocation(location)}
+            });
+        }
+        self
+    }
+}
+
+const UNKNOWN_LOCATION_DETAIL: &str = "The panic location is recorded by the panic hook assertr installs when first calling `assert_that_panic_by`. It is unknown when the PanicValue was not created by assert_that_panic_by or when the panic hook was replaced afterward.";
+
+fn is_same_file(actual: &str, expected: &str) -> bool {
+    actual
+        .strip_suffix(expected)
+        .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with(['/', '\\']))
+}
+
+struct DisplayLocation<'a>(Option<&'a PanicLocation>);
+
+impl core::fmt::Display for DisplayLocation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(location) => core::fmt::Display::fmt(location, f),
+            None => f.write_str("unknown"),
         }
     }
 }
 
+/// The message of the panic, if the panic value is one of the common message types.
+fn panic_message(any: &dyn Any) -> Option<&str> {
+    if let Some(message) = any.downcast_ref::<&str>() {
+        Some(message)
+    } else {
+        any.downcast_ref::<String>().map(String::as_str)
+    }
+}
+
+#[track_caller]
+fn fail_not_a_message<M: Mode>(assertion: &AssertThat<'_, PanicValue, M>) {
+    assertion.add_detail_message(ERASED_TYPE_DETAIL);
+    assertion.fail(|w: &mut String| write_not_a_message(w, &*assertion.actual().0));
+}
+
+fn write_not_a_message(w: &mut String, any: &dyn Any) -> core::fmt::Result {
+    let actual_type_name = type_name_of_val(any);
+    writedoc! {w, r"
+        Expected panic value to be a message of type &str or String
+
+          Actual panic value type: {actual_type_name}
+    "}
+}
+
+fn write_not_an_assertr_failure(w: &mut String, message: &str) -> core::fmt::Result {
+    writedoc! {w, r"
+        Expected panic to be an assertr failure
+
+          Actual panic message: {message:?}
+    "}
+}
+
+const ERASED_TYPE_DETAIL: &str = "The panic value can only be captured as Box<dyn Any>, meaning that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.";
+
+/// The name of the panic values type, if it is one of the common panic value types.
+fn actual_type_name(any: &dyn Any) -> Option<&'static str> {
+    if any.is::<&str>() {
+        Some("&str")
+    } else if any.is::<String>() {
+        Some("String")
+    } else {
+        None
+    }
+}
+
+fn write_type_mismatch<E: 'static>(
+    w: &mut String,
+    any: &dyn Any,
+    actual_type_name: Option<&'static str>,
+) -> core::fmt::Result {
+    let expected_type_name = type_name::<E>();
+    // Note: This call to `type_name_of_val` will just return "dyn core::any::Any"...
+    let actual_type_name = actual_type_name.unwrap_or_else(|| type_name_of_val(any));
+    writedoc! {w, r"
+        Expected panic value type: {expected_type_name}
+
+          Actual panic value type: {actual_type_name}
+    "}
+}
+
 #[cfg(test)]
 mod tests {
     mod has_type {
@@ -70,13 +399,13 @@ mod tests {
 
         #[test]
         fn succeeds_when_type_matches() {
-            let actual = PanicValue(Box::new(String::from("foo")));
+            let actual = PanicValue(Box::new(String::from("foo")), None);
 
             assert_that!(actual)
                 .has_type::<String>()
                 .is_equal_to(String::from("foo"));
 
-            let actual = PanicValue(Box::new(String::from("foo")));
+            let actual = PanicValue(Box::new(String::from("foo")), None);
 
             assert_that!(actual)
                 .has_type::<String>()
@@ -85,7 +414,7 @@ mod tests {
 
         #[test]
         fn panics_when_type_does_not_match() {
-            let actual = PanicValue(Box::new(String::from("foo")));
+            let actual = PanicValue(Box::new(String::from("foo")), None);
 
             assert_that_panic_by(|| {
                 assert_that!(actual).with_location(false).has_type::<u32>();
@@ -99,6 +428,28 @@ mod tests {
                     -------- assertr --------
                 "#});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_type_does_not_match() {
+            let actual = PanicValue(Box::new(String::from("foo")), None);
+
+            let failures = assert_that!(actual)
+                .with_capture()
+                .with_location(false)
+                .has_type::<u32>()
+                .is_equal_to(42)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected value type: u32
+
+                  Actual value type: String
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
     }
 
     mod has_type_ref {
@@ -107,7 +458,7 @@ mod tests {
 
         #[test]
         fn succeeds_when_type_matches() {
-            let actual = PanicValue(Box::new(String::from("foo")));
+            let actual = PanicValue(Box::new(String::from("foo")), None);
 
             assert_that!(actual)
                 .has_type_ref::<String>()
@@ -116,7 +467,7 @@ mod tests {
 
         #[test]
         fn panics_when_type_does_not_match_showing_actual_type_when_string() {
-            let actual = PanicValue(Box::new(String::from("foo")));
+            let actual = PanicValue(Box::new(String::from("foo")), None);
 
             assert_that_panic_by(|| {
                 assert_that!(actual)
@@ -135,7 +486,7 @@ mod tests {
 
         #[test]
         fn panics_when_type_does_not_match_showing_actual_type_when_str() {
-            let actual = PanicValue(Box::new("foo"));
+            let actual = PanicValue(Box::new("foo"), None);
 
             assert_that_panic_by(|| {
                 assert_that!(actual)
@@ -155,7 +506,7 @@ mod tests {
         #[test]
         fn panics_when_type_does_not_match_showing_actual_type_as_any_when_not_deducible() {
             struct Foo {}
-            let actual = PanicValue(Box::new(Foo {}));
+            let actual = PanicValue(Box::new(Foo {}), None);
 
             assert_that_panic_by(|| {
                 assert_that!(actual)
@@ -175,5 +526,344 @@ mod tests {
                 -------- assertr --------
             "#});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_type_does_not_match() {
+            let actual = PanicValue(Box::new("foo"), None);
+
+            let subject = assert_that!(actual).with_capture().with_location(false);
+            subject.has_type_ref::<u32>().is_equal_to(&42);
+
+            assert_that!(subject.capture_failures()).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic value type: u32
+
+                  Actual panic value type: &str
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_type_satisfying {
+        use crate::{PanicValue, prelude::*};
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_type_matches_and_assertions_pass() {
+            let actual = PanicValue(Box::new(String::from("foo")), None);
+
+            assert_that!(actual).has_type_satisfying::<String, _>(|value| {
+                value.is_equal_to(&String::from("foo"));
+            });
+        }
+
+        #[test]
+        fn captures_type_mismatch_without_running_assertions() {
+            let actual = PanicValue(Box::new("foo"), None);
+
+            let failures = assert_that!(actual)
+                .with_capture()
+                .with_location(false)
+                .has_type_satisfying::<u32, _>(|_| panic!("assertions should not run"))
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic value type: u32
+
+                  Actual panic value type: &str
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_message {
+        use crate::{PanicValue, prelude::*};
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_for_str_and_string_payloads() {
+            assert_that_panic_by(|| panic!("boom")).has_message("boom");
+            assert_that_panic_by(|| panic!("boom {}", 42)).has_message("boom 42");
+            assert_that_panic_by(|| std::panic::panic_any(String::from("boom")))
+                .has_message("boom");
+        }
+
+        #[test]
+        fn panics_when_message_differs() {
+            assert_that_panic_by(|| {
+                assert_that_panic_by(|| panic!("boom {}", 42))
+                    .with_location(false)
+                    .has_message("boom 43");
+            })
+            .has_message(formatdoc! {r#"
+                -------- assertr --------
+                Expected panic message: "boom 43"
+
+                  Actual panic message: "boom 42"
+                -------- assertr --------
+            "#});
+        }
+
+        #[test]
+        fn captures_failure_when_payload_is_not_a_message() {
+            let actual = PanicValue(Box::new(42), None);
+
+            let failures = assert_that!(actual)
+                .with_capture()
+                .with_location(false)
+                .has_message("boom")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic value to be a message of type &str or String
+
+                  Actual panic value type: dyn core::any::Any
+
+                Details: [
+                    The panic value can only be captured as Box<dyn Any>, meaning that the concrete type was erased. It will be shown as `dyn Any`. We already checked for both `&str` and `String`. Try other common types used for panic values or analyze your panicking code.,
+                ]
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_message_containing {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_message_contains_expected() {
+            assert_that_panic_by(|| panic!("index {} out of range", 7))
+                .has_message_containing("out of range");
+        }
+
+        #[test]
+        fn panics_when_message_does_not_contain_expected() {
+            assert_that_panic_by(|| {
+                assert_that_panic_by(|| panic!("boom"))
+                    .with_location(false)
+                    .has_message_containing("bang");
+            })
+            .has_message(formatdoc! {r#"
+                -------- assertr --------
+                Actual panic message: "boom"
+
+                does not contain
+
+                Expected: "bang"
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod has_message_matching {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_message_matches_pattern() {
+            assert_that_panic_by(|| panic!("index {} out of range", 7))
+                .has_message_matching(r"^index \d+ out of range$");
+        }
+
+        #[test]
+        fn panics_when_message_does_not_match_pattern() {
+            assert_that_panic_by(|| {
+                assert_that_panic_by(|| panic!("boom"))
+                    .with_location(false)
+                    .has_message_matching(r"^\d+$");
+            })
+            .has_message(formatdoc! {r#"
+                -------- assertr --------
+                Actual panic message: "boom"
+
+                does not match
+
+                Pattern: "^\\d+$"
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod is_assertr_failure {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_failure_text_without_banners_and_location() {
+            assert_that_panic_by(|| assert_that!(1).is_equal_to(2))
+                .is_assertr_failure()
+                .is_equal_to(formatdoc! {r#"
+                    Expected: 2
+
+                      Actual: 1
+                "#});
+        }
+
+        #[test]
+        fn panics_when_panic_was_not_raised_by_assertr() {
+            assert_that_panic_by(|| {
+                assert_that_panic_by(|| panic!("boom"))
+                    .with_location(false)
+                    .is_assertr_failure();
+            })
+            .has_message(formatdoc! {r#"
+                -------- assertr --------
+                Expected panic to be an assertr failure
+
+                  Actual panic message: "boom"
+                -------- assertr --------
+            "#});
+        }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_panic_was_not_raised_by_assertr() {
+            let failures = assert_that_panic_by(|| panic!("boom"))
+                .with_capture()
+                .with_location(false)
+                .is_assertr_failure()
+                .contains("Expected")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic to be an assertr failure
+
+                  Actual panic message: "boom"
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod is_assertr_failure_satisfying {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_failure_text_satisfies_assertions() {
+            assert_that_panic_by(|| assert_that!(1).with_subject_name("answer").is_equal_to(2))
+                .is_assertr_failure_satisfying(|failure| {
+                    failure.starts_with("Subject: answer");
+                });
+        }
+
+        #[test]
+        fn captures_non_assertr_panic_without_running_assertions() {
+            let failures = assert_that_panic_by(|| panic!("boom"))
+                .with_capture()
+                .with_location(false)
+                .is_assertr_failure_satisfying(|_| panic!("assertions should not run"))
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic to be an assertr failure
+
+                  Actual panic message: "boom"
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod panicked_at_file {
+        use crate::{PanicValue, prelude::*};
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_for_full_and_trailing_paths() {
+            assert_that_panic_by(|| panic!("boom"))
+                .panicked_at_file(file!())
+                .panicked_at_file("src/assertions/alloc/panic_value.rs")
+                .panicked_at_file("panic_value.rs");
+        }
+
+        #[test]
+        fn panics_when_file_differs() {
+            let failures = assert_that_panic_by(|| panic!("boom"))
+                .with_capture()
+                .with_location(false)
+                .panicked_at_file("value.rs")
+                .capture_failures();
+            let line = line!() - 5;
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic in file: "value.rs"
+
+                  Actual panic location: {file}:{line}:52
+                -------- assertr --------
+            "#, file = file!()}]);
+        }
+
+        #[test]
+        fn captures_failure_when_location_is_unknown() {
+            let actual = PanicValue(Box::new("boom"), None);
+
+            let failures = assert_that!(actual)
+                .with_capture()
+                .with_location(false)
+                .panicked_at_file("src/lib.rs")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected panic in file: "src/lib.rs"
+
+                  Actual panic location: unknown
+
+                Details: [
+                    The panic location is recorded by the panic hook assertr installs when first calling `assert_that_panic_by`. It is unknown when the PanicValue was not created by assert_that_panic_by or when the panic hook was replaced afterward.,
+                ]
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod panicked_at_line {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_line_matches() {
+            let line = line!() + 1;
+            assert_that_panic_by(|| panic!("boom")).panicked_at_line(line);
+        }
+
+        #[tokio::test]
+        async fn succeeds_for_async_panics() {
+            let line = line!() + 1;
+            assert_that_panic_by_async(async || panic!("boom"))
+                .await
+                .panicked_at_line(line);
+        }
+
+        #[test]
+        fn panics_when_line_differs() {
+            let line = line!() + 3;
+            assert_that_panic_by(|| {
+                assert_that_panic_by(|| {
+                    panic!("boom");
+                })
+                .with_location(false)
+                .panicked_at_line(1);
+            })
+            .has_message(formatdoc! {r#"
+                -------- assertr --------
+                Expected panic at line: 1
+
+                  Actual panic location: {file}:{line}:21
+                -------- assertr --------
+            "#, file = file!()});
+        }
     }
 }
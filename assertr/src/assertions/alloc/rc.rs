@@ -0,0 +1,287 @@
+use alloc::rc::Rc;
+use alloc::string::String;
+use core::fmt::Write;
+use indoc::writedoc;
+
+use crate::{AssertThat, mode::Mode, tracking::AssertionTracking};
+
+/// Abstracts over the reference-counted pointers `Rc` and `Arc`.
+pub trait RefCounted {
+    /// Names the pointer in failure messages.
+    const NAME: &'static str;
+
+    fn strong_count(&self) -> usize;
+
+    fn weak_count(&self) -> usize;
+
+    /// Whether both pointers point to the same allocation.
+    fn ptr_eq(&self, other: &Self) -> bool;
+
+    /// The address of the allocation, used in failure messages.
+    fn address(&self) -> *const ();
+}
+
+impl<T: ?Sized> RefCounted for Rc<T> {
+    const NAME: &'static str = "Rc";
+
+    fn strong_count(&self) -> usize {
+        Rc::strong_count(self)
+    }
+
+    fn weak_count(&self) -> usize {
+        Rc::weak_count(self)
+    }
+
+    fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(self, other)
+    }
+
+    fn address(&self) -> *const () {
+        Rc::as_ptr(self).cast()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: ?Sized> RefCounted for alloc::sync::Arc<T> {
+    const NAME: &'static str = "Arc";
+
+    fn strong_count(&self) -> usize {
+        alloc::sync::Arc::strong_count(self)
+    }
+
+    fn weak_count(&self) -> usize {
+        alloc::sync::Arc::weak_count(self)
+    }
+
+    fn ptr_eq(&self, other: &Self) -> bool {
+        alloc::sync::Arc::ptr_eq(self, other)
+    }
+
+    fn address(&self) -> *const () {
+        alloc::sync::Arc::as_ptr(self).cast()
+    }
+}
+
+/// Reference-count assertions for `Rc` and `Arc`, e.g. for detecting leaked clones of shared
+/// state.
+/// These work in any mode (Panic or Capture).
+///
+/// Assert on a borrowed pointer, e.g. `assert_that!(&rc)`, as passing a clone increases the
+/// strong count.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait RefCountedAssertions<P> {
+    fn has_strong_count(self, expected: usize) -> Self;
+
+    fn has_weak_count(self, expected: usize) -> Self;
+
+    /// Test that this is the only pointer to the allocation, strong or weak. Mutable access
+    /// through `get_mut` is then possible.
+    fn is_unique(self) -> Self;
+
+    /// Test that both pointers point to the same allocation, using `ptr_eq`.
+    #[cfg_attr(feature = "fluent", fluent_alias("point_to_same_as"))]
+    fn points_to_same_as(self, other: &P) -> Self;
+}
+
+impl<P: RefCounted, M: Mode> RefCountedAssertions<P> for AssertThat<'_, P, M> {
+    #[track_caller]
+    fn has_strong_count(self, expected: usize) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().strong_count();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected strong count: {expected}
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_weak_count(self, expected: usize) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().weak_count();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected weak count: {expected}
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_unique(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let strong = self.actual().strong_count();
+        let weak = self.actual().weak_count();
+        if strong != 1 || weak != 0 {
+            let name = P::NAME;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {name} to be unique
+
+                      Actual: {name} has {strong} strong and {weak} weak references
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn points_to_same_as(self, other: &P) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().ptr_eq(other) {
+            let name = P::NAME;
+            let expected = other.address();
+            let actual = self.actual().address();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {name} pointing to {expected:p}
+
+                      Actual: {name} pointing to {actual:p}
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_strong_count {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        #[test]
+        fn succeeds_when_count_matches() {
+            let rc = Rc::new(42);
+            let _clone = Rc::clone(&rc);
+            let arc = Arc::new("foo");
+
+            assert_that!(&rc).has_strong_count(2);
+            assert_that!(&arc).has_strong_count(1);
+        }
+
+        #[test]
+        fn panics_when_a_clone_leaked() {
+            let arc = Arc::new(42);
+            let leaked = Arc::clone(&arc);
+
+            assert_that_panic_by(|| assert_that!(&arc).with_location(false).has_strong_count(1))
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected strong count: 1
+
+                      Actual: 2
+                    -------- assertr --------
+                "});
+            drop(leaked);
+        }
+    }
+
+    mod has_weak_count {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::rc::Rc;
+
+        #[test]
+        fn succeeds_when_count_matches() {
+            let rc = Rc::new(42);
+            let _weak = Rc::downgrade(&rc);
+
+            assert_that!(&rc).has_weak_count(1);
+        }
+
+        #[test]
+        fn captures_failure_when_count_differs() {
+            let failures = assert_that!(Rc::new(42))
+                .with_capture()
+                .with_location(false)
+                .has_weak_count(1)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected weak count: 1
+
+                  Actual: 0
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod is_unique {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::sync::Arc;
+
+        #[test]
+        fn succeeds_when_unique() {
+            assert_that!(Arc::new(42)).is_unique();
+        }
+
+        #[test]
+        fn panics_when_weak_references_exist() {
+            let arc = Arc::new(42);
+            let weak = Arc::downgrade(&arc);
+
+            assert_that_panic_by(|| assert_that!(&arc).with_location(false).is_unique())
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected: Arc to be unique
+
+                      Actual: Arc has 1 strong and 1 weak references
+                    -------- assertr --------
+                "});
+            drop(weak);
+        }
+    }
+
+    mod points_to_same_as {
+        use crate::prelude::*;
+        use std::rc::Rc;
+
+        #[test]
+        fn succeeds_for_clones() {
+            let rc = Rc::new(42);
+            let clone = Rc::clone(&rc);
+
+            assert_that!(&clone).points_to_same_as(&rc);
+        }
+
+        #[test]
+        fn panics_for_equal_values_in_different_allocations() {
+            let rc = Rc::new(42);
+            let other = Rc::new(42);
+
+            assert_that_panic_by(|| {
+                assert_that!(&rc)
+                    .with_location(false)
+                    .points_to_same_as(&other)
+            })
+            .has_message_containing("Expected: Rc pointing to 0x");
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
+use crate::approx::ApproxEq;
 use crate::{AssertThat, AssertrPartialEq, Mode, prelude::SliceAssertions};
 
 #[allow(clippy::return_self_not_must_use)]
@@ -21,6 +22,15 @@ pub trait VecAssertions<'t, T: Debug> {
         E: Debug + 't,
         T: AssertrPartialEq<E> + Debug;
 
+    /// Test that every element is approximately equal to the expected element at the same index,
+    /// allowing floating point values to deviate by `tolerance`. Lengths must be identical.
+    ///
+    /// Failures list every out-of-tolerance index together with its deviation.
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug;
+
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
         T: PartialEq + Debug;
@@ -62,6 +72,17 @@ impl<'t, T: Debug, M: Mode> VecAssertions<'t, T> for AssertThat<'t, Vec<T>, M> {
         self
     }
 
+    #[track_caller]
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug,
+    {
+        self.derive(Vec::as_slice)
+            .is_close_to_elementwise(expected, tolerance);
+        self
+    }
+
     #[track_caller]
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
@@ -246,6 +267,26 @@ mod tests {
         }
     }
 
+    mod is_close_to_elementwise {
+        use crate::prelude::*;
+
+        #[test]
+        fn succeeds_when_all_elements_are_within_tolerance() {
+            assert_that!(vec![1.0, 2.0, 3.0])
+                .is_close_to_elementwise(vec![1.001, 2.0, 2.999], 0.01);
+        }
+
+        #[test]
+        fn fails_when_an_element_is_outside_tolerance() {
+            assert_that!(vec![1.0, 2.0, 3.0])
+                .with_capture()
+                .is_close_to_elementwise([1.0, 2.1, 3.0], 0.01)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
     mod contains_exactly_in_any_order {
         use crate::prelude::*;
         use indoc::formatdoc;
@@ -107,6 +107,9 @@ impl<'t, T: Debug, M: Mode> VecDequeAssertions<'t, T> for AssertThat<'t, VecDequ
         T: AssertrPartialEq<E> + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual
             .iter()
@@ -130,6 +133,9 @@ impl<'t, T: Debug, M: Mode> VecDequeAssertions<'t, T> for AssertThat<'t, VecDequ
         T: AssertrPartialEq<E> + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if actual
             .iter()
@@ -153,6 +159,9 @@ impl<'t, T: Debug, M: Mode> VecDequeAssertions<'t, T> for AssertThat<'t, VecDequ
         T: AssertrPartialEq<E> + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected = expected.as_ref();
 
@@ -191,6 +200,9 @@ impl<'t, T: Debug, M: Mode> VecDequeAssertions<'t, T> for AssertThat<'t, VecDequ
         T: PartialEq + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected: &[T] = expected.as_ref();
 
@@ -236,6 +248,9 @@ impl<'t, T: Debug, M: Mode> VecDequeAssertions<'t, T> for AssertThat<'t, VecDequ
         P: Fn(&T) -> bool,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected = expected.as_ref();
 
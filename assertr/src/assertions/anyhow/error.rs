@@ -0,0 +1,490 @@
+use alloc::string::{String, ToString};
+use core::any::type_name;
+use core::error::Error;
+use core::fmt::{Debug, Display, Write};
+use indoc::writedoc;
+
+use crate::assertions::core::error::{AsError, display_chain};
+use crate::{
+    AssertThat,
+    mode::{Mode, Panic},
+    tracking::AssertionTracking,
+};
+
+/// Marks `anyhow::Error` subjects, which do not implement `Error`.
+pub struct Anyhow;
+
+impl AsError<Anyhow> for anyhow::Error {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        &**self
+    }
+}
+
+/// Assertions for `anyhow::Error`, complementing the `ErrorAssertions` also available for it.
+/// These work in any mode (Panic or Capture).
+///
+/// Context attached using `context()` or `with_context()` is part of the chain these assertions
+/// look at, with the outermost context coming first.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait AnyhowErrorAssertions<'t, M: Mode> {
+    /// Test if any error in the chain, context or cause, has a `Display` representation equal
+    /// to `expected`.
+    fn has_context_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if any error in the chain, context or cause, is of type `E`.
+    ///
+    /// This includes the sources of the error the `anyhow::Error` was created from.
+    #[cfg_attr(feature = "fluent", fluent_alias("contain_type_in_chain"))]
+    fn chain_contains_type<E>(self) -> Self
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Test if the `Display` representation of the root cause, the last error in the chain,
+    /// equals `expected`.
+    fn has_root_cause_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the error can be downcast to `E`, then run additional assertions on the
+    /// downcast value.
+    #[cfg_attr(feature = "fluent", fluent_alias("downcast_to_satisfying"))]
+    fn downcasts_to_satisfying<E, A>(self, assertions: A) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static,
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>);
+
+    /// Test if a backtrace was captured when the error was created.
+    ///
+    /// Whether anyhow captures backtraces is controlled by the `RUST_BACKTRACE` and
+    /// `RUST_LIB_BACKTRACE` environment variables.
+    #[cfg(feature = "std")]
+    fn has_backtrace(self) -> Self;
+
+    /// Test if no backtrace was captured when the error was created.
+    #[cfg(feature = "std")]
+    fn has_no_backtrace(self) -> Self;
+}
+
+impl<'t, M: Mode> AnyhowErrorAssertions<'t, M> for AssertThat<'t, anyhow::Error, M> {
+    #[track_caller]
+    fn has_context_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let expected = expected.as_ref();
+        let chain = display_chain(self.actual().as_error());
+        if !chain.iter().any(|message| message == expected) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected chain to contain message: {expected:?}
+
+                      Actual chain: {chain:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn chain_contains_type<E>(self) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let error = self.actual();
+        if error.downcast_ref::<E>().is_none() && !error.chain().any(<dyn Error>::is::<E>) {
+            let expected_type_name = type_name::<E>();
+            let chain = display_chain(error.as_error());
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected chain to contain type: {expected_type_name}
+
+                      Actual chain: {chain:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_root_cause_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().root_cause().to_string();
+        let expected = expected.as_ref();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected root cause message: {expected:?}
+
+                      Actual root cause message: {actual:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn downcasts_to_satisfying<E, A>(self, assertions: A) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static,
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        self.satisfies_extracted(
+            anyhow::Error::downcast_ref::<E>,
+            |error, w| write_downcast_failure::<E>(error, w),
+            assertions,
+        )
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn has_backtrace(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let status = self.actual().backtrace().status();
+        if status != std::backtrace::BacktraceStatus::Captured {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected a captured backtrace
+
+                      Actual backtrace status: {status:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn has_no_backtrace(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if self.actual().backtrace().status() == std::backtrace::BacktraceStatus::Captured {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected no captured backtrace, but one was captured
+                "}
+            });
+        }
+        self
+    }
+}
+
+fn write_downcast_failure<E>(error: &anyhow::Error, w: &mut String) -> core::fmt::Result {
+    let expected_type_name = type_name::<E>();
+    let chain = display_chain(error.as_error());
+    writedoc! {w, r"
+        Expected error downcastable to: {expected_type_name}
+
+          Actual chain: {chain:#?}
+    "}
+}
+
+/// Data-extracting assertions for `anyhow::Error`.
+/// Only available in Panic mode, as the downcast value cannot be produced when the types differ.
+/// Use `AnyhowErrorAssertions::downcasts_to_satisfying` for capture mode.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait AnyhowErrorExtractAssertions<'t> {
+    /// Test if the error can be downcast to `E` and change the subject to the downcast value.
+    ///
+    /// Just like `anyhow::Error::downcast_ref`, this finds context attached to the error as
+    /// well as the error it was created from.
+    #[cfg_attr(feature = "fluent", fluent_alias("downcast_to"))]
+    fn downcasts_to<E>(&'t self) -> AssertThat<'t, &'t E, Panic>
+    where
+        E: Display + Debug + Send + Sync + 'static;
+}
+
+impl<'t> AnyhowErrorExtractAssertions<'t> for AssertThat<'t, anyhow::Error, Panic> {
+    #[track_caller]
+    fn downcasts_to<E>(&'t self) -> AssertThat<'t, &'t E, Panic>
+    where
+        E: Display + Debug + Send + Sync + 'static,
+    {
+        self.track_assertion();
+        if let Some(downcast) = self.actual().downcast_ref::<E>() {
+            self.derive(|_actual| downcast)
+        } else {
+            self.fail(|w: &mut String| write_downcast_failure::<E>(self.actual(), w));
+            unreachable!("Panic mode always panics on fail")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    fn request_error() -> anyhow::Error {
+        anyhow::Error::new(io::Error::new(
+            io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        ))
+        .context("could not load user")
+        .context("request failed")
+    }
+
+    mod error_assertions {
+        use super::request_error;
+        use crate::prelude::*;
+
+        #[test]
+        fn are_available_for_anyhow_errors() {
+            assert_that!(request_error())
+                .has_message("request failed")
+                .source_chain()
+                .contains_exactly([
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]);
+        }
+    }
+
+    mod has_context_message {
+        use super::request_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_any_context_has_message() {
+            assert_that!(request_error())
+                .has_context_message("request failed")
+                .has_context_message("could not load user");
+        }
+
+        #[test]
+        fn captures_failure_listing_the_chain() {
+            let failures = assert_that!(request_error())
+                .with_capture()
+                .with_location(false)
+                .has_context_message("could not save user")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected chain to contain message: "could not save user"
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod chain_contains_type {
+        use super::request_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::io;
+
+        #[derive(Debug)]
+        struct LoadError(io::Error);
+
+        impl core::fmt::Display for LoadError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("could not load user")
+            }
+        }
+
+        impl core::error::Error for LoadError {
+            fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        #[test]
+        fn succeeds_when_anyhow_error_was_created_from_type() {
+            assert_that!(request_error()).chain_contains_type::<io::Error>();
+        }
+
+        #[test]
+        fn succeeds_when_type_is_a_source_of_the_wrapped_error() {
+            let error = anyhow::Error::new(LoadError(io::Error::other("connection refused")));
+
+            assert_that!(error)
+                .chain_contains_type::<LoadError>()
+                .chain_contains_type::<io::Error>();
+        }
+
+        #[test]
+        fn panics_when_no_error_in_chain_has_type() {
+            assert_that_panic_by(|| {
+                assert_that!(request_error())
+                    .with_location(false)
+                    .chain_contains_type::<core::fmt::Error>()
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected chain to contain type: core::fmt::Error
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod has_root_cause_message {
+        use super::request_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_equal() {
+            assert_that!(request_error()).has_root_cause_message("connection refused");
+        }
+
+        #[test]
+        fn panics_when_not_equal() {
+            assert_that_panic_by(|| {
+                assert_that!(request_error())
+                    .with_location(false)
+                    .has_root_cause_message("request failed")
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected root cause message: "request failed"
+
+                  Actual root cause message: "connection refused"
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod downcasts_to {
+        use super::request_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::io;
+
+        #[test]
+        fn extracts_the_error_the_anyhow_error_was_created_from() {
+            assert_that!(request_error())
+                .downcasts_to::<io::Error>()
+                .has_display_value("connection refused");
+        }
+
+        #[test]
+        fn extracts_context() {
+            assert_that!(request_error())
+                .downcasts_to::<&str>()
+                .is_equal_to(&"request failed");
+        }
+
+        #[test]
+        fn panics_when_type_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(request_error())
+                    .with_location(false)
+                    .downcasts_to::<core::fmt::Error>();
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected error downcastable to: core::fmt::Error
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod downcasts_to_satisfying {
+        use super::request_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::io;
+
+        #[test]
+        fn succeeds_when_downcast_value_satisfies_assertions() {
+            assert_that!(request_error()).downcasts_to_satisfying::<io::Error, _>(|error| {
+                error.has_display_value("connection refused");
+            });
+        }
+
+        #[test]
+        fn captures_failed_downcast_without_running_assertions() {
+            let failures = assert_that!(request_error())
+                .with_capture()
+                .with_location(false)
+                .downcasts_to_satisfying::<core::fmt::Error, _>(|_| {
+                    panic!("assertions should not run")
+                })
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected error downcastable to: core::fmt::Error
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_backtrace {
+        use super::request_error;
+        use crate::prelude::*;
+        use std::backtrace::{Backtrace, BacktraceStatus};
+
+        #[test]
+        fn matches_whether_backtraces_are_enabled() {
+            // Capturing is controlled through the environment the tests run in.
+            if Backtrace::capture().status() == BacktraceStatus::Captured {
+                assert_that!(request_error()).has_backtrace();
+            } else {
+                assert_that!(request_error()).has_no_backtrace();
+            }
+        }
+
+        #[test]
+        fn captures_failure_when_backtrace_presence_differs() {
+            let failures = if Backtrace::capture().status() == BacktraceStatus::Captured {
+                assert_that!(request_error())
+                    .with_capture()
+                    .has_no_backtrace()
+                    .capture_failures()
+            } else {
+                assert_that!(request_error())
+                    .with_capture()
+                    .has_backtrace()
+                    .capture_failures()
+            };
+
+            assert_that!(failures).has_length(1);
+        }
+    }
+}
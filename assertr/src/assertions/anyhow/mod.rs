@@ -0,0 +1,6 @@
+pub mod error;
+
+pub mod prelude {
+    pub use super::error::AnyhowErrorAssertions;
+    pub use super::error::AnyhowErrorExtractAssertions;
+}
@@ -14,6 +14,9 @@ impl<T, M: Mode> ConditionAssertions<T> for AssertThat<'_, T, M> {
     #[track_caller]
     fn is<C: Condition<T>>(self, condition: C) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         match condition.test(self.actual()) {
             Ok(()) => {}
             Err(err) => self.fail(|w: &mut String| {
@@ -49,6 +52,9 @@ where
     #[track_caller]
     fn are<C: Condition<T>>(self, condition: C) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let iter = self.actual().into_iter();
         for actual in iter {
             match condition.test(actual) {
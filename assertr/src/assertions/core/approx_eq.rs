@@ -0,0 +1,104 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+use core::fmt::Write;
+use indoc::writedoc;
+
+use crate::approx::ApproxEq;
+use crate::{AssertThat, EqContext, Mode, tracking::AssertionTracking};
+
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait ApproxEqAssertions<T> {
+    /// Fails if actual is not equal to expected, allowing every floating point value contained
+    /// to deviate by `tolerance`.
+    ///
+    /// Failures list every deviating value together with its deviation.
+    fn is_approx_equal_to<E>(self, expected: E, tolerance: f64) -> Self
+    where
+        T: ApproxEq<E> + Debug,
+        E: Debug;
+}
+
+impl<T, M: Mode> ApproxEqAssertions<T> for AssertThat<'_, T, M> {
+    #[track_caller]
+    fn is_approx_equal_to<E>(self, expected: E, tolerance: f64) -> Self
+    where
+        T: ApproxEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let actual = self.actual();
+        let expected = &expected;
+
+        let mut ctx = EqContext::default();
+
+        if !ApproxEq::approx_eq(actual, expected, tolerance, Some(&mut ctx)) {
+            if !ctx.differences.differences.is_empty() {
+                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {expected:#?}
+
+                      Actual: {actual:#?}
+
+                    Allowed deviation: {tolerance:?}
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod is_approx_equal_to {
+        use indoc::formatdoc;
+
+        use crate::prelude::*;
+
+        #[test]
+        fn succeeds_when_within_tolerance() {
+            assert_that!(0.1 + 0.2).is_approx_equal_to(0.3, 1e-9);
+            assert_that!(vec![1.0, 2.0]).is_approx_equal_to(vec![1.01, 1.99], 0.05);
+        }
+
+        #[test]
+        fn panics_when_not_within_tolerance() {
+            assert_that_panic_by(|| {
+                assert_that!([1.0f32, 2.0, 3.0])
+                    .with_location(false)
+                    .is_approx_equal_to([1.0, 2.5, 3.0], 0.1)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected: [
+                        1.0,
+                        2.5,
+                        3.0,
+                    ]
+
+                      Actual: [
+                        1.0,
+                        2.0,
+                        3.0,
+                    ]
+
+                    Allowed deviation: 0.1
+
+                    Details: [
+                        Differences: [
+                            [1]: expected 2.5, but was 2.0, deviating by 0.5,
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use core::fmt::Debug;
 
+use crate::approx::ApproxEq;
 use crate::{AssertThat, AssertrPartialEq, Mode, prelude::SliceAssertions};
 
 #[allow(clippy::return_self_not_must_use)]
@@ -20,6 +21,15 @@ pub trait ArrayAssertions<'t, T: Debug> {
         E: Debug + 't,
         T: AssertrPartialEq<E> + Debug;
 
+    /// Test that every element is approximately equal to the expected element at the same index,
+    /// allowing floating point values to deviate by `tolerance`. Lengths must be identical.
+    ///
+    /// Failures list every out-of-tolerance index together with its deviation.
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug;
+
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
         T: PartialEq;
@@ -63,6 +73,17 @@ impl<'t, T: Debug, const N: usize, M: Mode> ArrayAssertions<'t, T> for AssertTha
         self
     }
 
+    #[track_caller]
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug,
+    {
+        self.derive(<[T; N]>::as_slice)
+            .is_close_to_elementwise(expected, tolerance);
+        self
+    }
+
     #[track_caller]
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
@@ -194,6 +215,25 @@ mod tests {
         }
     }
 
+    mod is_close_to_elementwise {
+        use crate::prelude::*;
+
+        #[test]
+        fn succeeds_when_all_elements_are_within_tolerance() {
+            assert_that!([1.0f32, 2.0, 3.0]).is_close_to_elementwise([1.001, 2.0, 2.999], 0.01);
+        }
+
+        #[test]
+        fn fails_when_an_element_is_outside_tolerance() {
+            assert_that!([1.0f32, 2.0, 3.0])
+                .with_capture()
+                .is_close_to_elementwise([1.0, 2.1, 3.0], 0.01)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
     mod contains_exactly_in_any_order {
         use crate::prelude::*;
         use indoc::formatdoc;
@@ -0,0 +1,209 @@
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use core::sync::atomic::Ordering;
+use indoc::writedoc;
+
+use crate::{AssertThat, actual::Actual, mode::Mode, tracking::AssertionTracking};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Abstracts over the types of `core::sync::atomic`. This trait is sealed.
+pub trait Atomic: sealed::Sealed {
+    /// The type of value stored, e.g. `u32` for `AtomicU32`.
+    type Value;
+
+    fn load(&self, ordering: Ordering) -> Self::Value;
+}
+
+macro_rules! impl_atomic {
+    ($($width:literal => $atomic:ty => $value:ty),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl sealed::Sealed for $atomic {}
+
+            #[cfg(target_has_atomic = $width)]
+            impl Atomic for $atomic {
+                type Value = $value;
+
+                fn load(&self, ordering: Ordering) -> $value {
+                    <$atomic>::load(self, ordering)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic!(
+    "8" => core::sync::atomic::AtomicBool => bool,
+    "8" => core::sync::atomic::AtomicI8 => i8,
+    "8" => core::sync::atomic::AtomicU8 => u8,
+    "16" => core::sync::atomic::AtomicI16 => i16,
+    "16" => core::sync::atomic::AtomicU16 => u16,
+    "32" => core::sync::atomic::AtomicI32 => i32,
+    "32" => core::sync::atomic::AtomicU32 => u32,
+    "64" => core::sync::atomic::AtomicI64 => i64,
+    "64" => core::sync::atomic::AtomicU64 => u64,
+    "ptr" => core::sync::atomic::AtomicIsize => isize,
+    "ptr" => core::sync::atomic::AtomicUsize => usize,
+);
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> sealed::Sealed for core::sync::atomic::AtomicPtr<T> {}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Atomic for core::sync::atomic::AtomicPtr<T> {
+    type Value = *mut T;
+
+    fn load(&self, ordering: Ordering) -> *mut T {
+        core::sync::atomic::AtomicPtr::load(self, ordering)
+    }
+}
+
+/// Assertions for the types of `core::sync::atomic`.
+/// These work in any mode (Panic or Capture).
+///
+/// Values are loaded using `Ordering::SeqCst`, unless stated otherwise.
+/// The `_with_ordering` variants panic when given `Ordering::Release` or `Ordering::AcqRel`, which
+/// are not valid for loads.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait AtomicAssertions<'t, V, M: Mode> {
+    fn has_value(self, expected: V) -> Self
+    where
+        V: PartialEq + Debug;
+
+    fn has_value_with_ordering(self, expected: V, ordering: Ordering) -> Self
+    where
+        V: PartialEq + Debug;
+
+    /// Changes the subject to the loaded value, e.g. to use numeric assertions on it.
+    fn value(self) -> AssertThat<'t, V, M>;
+
+    /// Changes the subject to the value loaded using the given `ordering`.
+    fn value_with_ordering(self, ordering: Ordering) -> AssertThat<'t, V, M>;
+}
+
+impl<'t, A: Atomic, M: Mode> AtomicAssertions<'t, A::Value, M> for AssertThat<'t, A, M> {
+    #[track_caller]
+    fn has_value(self, expected: A::Value) -> Self
+    where
+        A::Value: PartialEq + Debug,
+    {
+        self.has_value_with_ordering(expected, Ordering::SeqCst)
+    }
+
+    #[track_caller]
+    fn has_value_with_ordering(self, expected: A::Value, ordering: Ordering) -> Self
+    where
+        A::Value: PartialEq + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().load(ordering);
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn value(self) -> AssertThat<'t, A::Value, M> {
+        self.value_with_ordering(Ordering::SeqCst)
+    }
+
+    #[track_caller]
+    fn value_with_ordering(self, ordering: Ordering) -> AssertThat<'t, A::Value, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        self.map(|it| Actual::Owned(it.borrowed().load(ordering)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_value {
+        use crate::prelude::*;
+        use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_equal() {
+            let counter = AtomicUsize::new(0);
+            counter.fetch_add(2, Ordering::Relaxed);
+            let mut value = 42;
+            let ptr = &raw mut value;
+
+            assert_that!(&counter).has_value(2);
+            assert_that!(AtomicBool::new(true)).has_value(true);
+            assert_that!(AtomicPtr::new(ptr)).has_value(ptr);
+        }
+
+        #[test]
+        fn loads_with_given_ordering() {
+            assert_that!(AtomicU64::new(7)).has_value_with_ordering(7, Ordering::Acquire);
+        }
+
+        #[test]
+        fn panics_when_not_equal() {
+            assert_that_panic_by(|| {
+                assert_that!(AtomicUsize::new(1))
+                    .with_location(false)
+                    .has_value(2)
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected value: 2
+
+                  Actual: 1
+                -------- assertr --------
+            "});
+        }
+
+        #[test]
+        fn panics_on_invalid_load_ordering() {
+            assert_that_panic_by(|| {
+                assert_that!(AtomicUsize::new(1)).has_value_with_ordering(1, Ordering::Release)
+            })
+            .has_message_containing("there is no such thing as a release load");
+        }
+    }
+
+    mod value {
+        use crate::prelude::*;
+        use core::sync::atomic::{AtomicI32, Ordering};
+
+        #[test]
+        fn changes_subject_to_loaded_value() {
+            assert_that!(AtomicI32::new(-3))
+                .value()
+                .is_negative()
+                .is_less_than(-2);
+            assert_that!(AtomicI32::new(3))
+                .value_with_ordering(Ordering::Relaxed)
+                .is_equal_to(3);
+        }
+
+        #[test]
+        fn captures_failures_on_loaded_value() {
+            let failures = assert_that!(AtomicI32::new(3))
+                .with_capture()
+                .value()
+                .is_greater_than(4)
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
+    }
+}
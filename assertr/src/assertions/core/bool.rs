@@ -17,6 +17,9 @@ impl<M: Mode> BoolAssertions for AssertThat<'_, bool, M> {
     #[track_caller]
     fn is_true(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected = &true;
         if actual != expected {
@@ -34,6 +37,9 @@ impl<M: Mode> BoolAssertions for AssertThat<'_, bool, M> {
     #[track_caller]
     fn is_false(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected = &false;
         if actual != expected {
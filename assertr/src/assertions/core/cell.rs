@@ -0,0 +1,49 @@
+use core::cell::Cell;
+
+use crate::{AssertThat, actual::Actual, mode::Mode, tracking::AssertionTracking};
+
+/// Assertions for `Cell` values.
+/// These work in any mode (Panic or Capture).
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait CellAssertions<'t, T: Copy, M: Mode> {
+    /// Changes the subject to a copy of the contained value.
+    fn value(self) -> AssertThat<'t, T, M>;
+}
+
+impl<'t, T: Copy, M: Mode> CellAssertions<'t, T, M> for AssertThat<'t, Cell<T>, M> {
+    #[track_caller]
+    fn value(self) -> AssertThat<'t, T, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        self.map(|it| Actual::Owned(it.borrowed().get()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod value {
+        use crate::prelude::*;
+        use std::cell::Cell;
+
+        #[test]
+        fn changes_subject_to_contained_value() {
+            let cell = Cell::new(5);
+            cell.set(6);
+
+            assert_that!(&cell).value().is_equal_to(6);
+        }
+
+        #[test]
+        fn captures_failures_on_contained_value() {
+            let failures = assert_that!(Cell::new(5))
+                .with_capture()
+                .value()
+                .is_equal_to(6)
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
+    }
+}
@@ -30,6 +30,9 @@ impl<M: Mode> CharAssertions for AssertThat<'_, char, M> {
     #[track_caller]
     fn is_equal_to_ignoring_ascii_case(self, expected: char) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.eq_ignore_ascii_case(&expected) {
             self.add_detail_message("Actual is not equal to expected, even when ignoring casing.");
@@ -47,6 +50,9 @@ impl<M: Mode> CharAssertions for AssertThat<'_, char, M> {
     #[track_caller]
     fn is_lowercase(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_lowercase() {
             self.fail(|w: &mut String| {
@@ -61,6 +67,9 @@ impl<M: Mode> CharAssertions for AssertThat<'_, char, M> {
     #[track_caller]
     fn is_uppercase(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_uppercase() {
             self.fail(|w: &mut String| {
@@ -75,6 +84,9 @@ impl<M: Mode> CharAssertions for AssertThat<'_, char, M> {
     #[track_caller]
     fn is_ascii_lowercase(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_ascii_lowercase() {
             self.fail(|w: &mut String| {
@@ -89,6 +101,9 @@ impl<M: Mode> CharAssertions for AssertThat<'_, char, M> {
     #[track_caller]
     fn is_ascii_uppercase(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_ascii_uppercase() {
             self.fail(|w: &mut String| {
@@ -0,0 +1,135 @@
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+
+use crate::AssertThat;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+
+/// Assertions for the outcome of checked arithmetic, e.g. `checked_add` or `checked_mul`,
+/// applied to the subject.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait CheckedArithmeticAssertions<T> {
+    /// Fails if the checked `operation`, applied to actual, does not return `None`.
+    ///
+    /// ```
+    /// use assertr::prelude::*;
+    ///
+    /// assert_that!(u8::MAX).overflows_when(|x| x.checked_add(1));
+    /// ```
+    #[cfg_attr(feature = "fluent", fluent_alias("overflow_when"))]
+    fn overflows_when<R: Debug>(self, operation: impl FnOnce(T) -> Option<R>) -> Self
+    where
+        T: Copy + Debug;
+
+    /// Fails if the checked `operation`, applied to actual, returns `None`.
+    fn does_not_overflow_when<R>(self, operation: impl FnOnce(T) -> Option<R>) -> Self
+    where
+        T: Copy + Debug;
+}
+
+impl<T, M: Mode> CheckedArithmeticAssertions<T> for AssertThat<'_, T, M> {
+    #[track_caller]
+    fn overflows_when<R: Debug>(self, operation: impl FnOnce(T) -> Option<R>) -> Self
+    where
+        T: Copy + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if let Some(result) = operation(*actual) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected checked operation to overflow, but it returned: {result:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn does_not_overflow_when<R>(self, operation: impl FnOnce(T) -> Option<R>) -> Self
+    where
+        T: Copy + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if operation(*actual).is_none() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected checked operation not to overflow, but it returned None
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod overflows_when {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_operation_overflows() {
+            assert_that!(u8::MAX).overflows_when(|x| x.checked_add(1));
+            assert_that!(i32::MIN).overflows_when(|x| x.checked_neg());
+            assert_that!(1u32).overflows_when(|x| x.checked_div(0));
+        }
+
+        #[test]
+        fn panics_when_operation_does_not_overflow() {
+            assert_that_panic_by(|| {
+                assert_that!(254u8)
+                    .with_location(false)
+                    .overflows_when(|x| x.checked_add(1))
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected checked operation to overflow, but it returned: 255
+
+                      Actual: 254
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod does_not_overflow_when {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_operation_does_not_overflow() {
+            assert_that!(254u8).does_not_overflow_when(|x| x.checked_add(1));
+        }
+
+        #[test]
+        fn panics_when_operation_overflows() {
+            assert_that_panic_by(|| {
+                assert_that!(2u64)
+                    .with_location(false)
+                    .does_not_overflow_when(|x| x.checked_pow(64))
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected checked operation not to overflow, but it returned None
+
+                      Actual: 2
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
@@ -21,6 +21,9 @@ impl<T: Debug, M: Mode> DebugAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn has_debug_string(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual_string = format!("{:?}", self.actual());
 
@@ -48,6 +51,9 @@ impl<T: Debug, M: Mode> DebugAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn has_debug_value(self, expected: impl Debug) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual_string = format!("{:?}", self.actual());
         let expected_string = format!("{expected:?}");
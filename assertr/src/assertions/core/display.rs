@@ -17,6 +17,9 @@ impl<T: Display, M: Mode> DisplayAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn has_display_value(self, expected: impl Display) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual_string = format!("{}", self.actual());
         let expected_string = format!("{expected}");
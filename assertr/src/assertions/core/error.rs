@@ -0,0 +1,555 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter, Write};
+use indoc::writedoc;
+
+use crate::{
+    AssertThat,
+    actual::Actual,
+    failure::NESTED_ASSERTIONS_SKIPPED,
+    mode::{Mode, Panic},
+    tracking::AssertionTracking,
+};
+
+/// Marks error subjects implementing `Error` themselves.
+pub struct Concrete;
+
+/// Marks boxed `dyn Error` subjects, which do not implement `Error`.
+pub struct Boxed;
+
+/// Marks [`ErrorRef`] subjects.
+pub struct Borrowed;
+
+/// A borrowed error, e.g. the source extracted using `has_source()`.
+///
+/// Unlike `&dyn Error`, this does not implement `Error` itself, keeping the [`AsError`]
+/// implementation used for it unambiguous.
+#[derive(Clone, Copy)]
+pub struct ErrorRef<'e>(pub &'e (dyn Error + 'static));
+
+impl Debug for ErrorRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl Display for ErrorRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+/// Any value usable as an error subject. `K` is a marker like [`Concrete`], [`Boxed`] or [`Borrowed`],
+/// allowing implementations for boxed errors next to the blanket implementation for all `Error`s.
+pub trait AsError<K> {
+    fn as_error(&self) -> &(dyn Error + 'static);
+}
+
+impl<T: Error + 'static> AsError<Concrete> for T {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        self
+    }
+}
+
+impl AsError<Boxed> for Box<dyn Error> {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        &**self
+    }
+}
+
+impl AsError<Boxed> for Box<dyn Error + Send + Sync> {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        &**self
+    }
+}
+
+impl AsError<Borrowed> for ErrorRef<'_> {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        self.0
+    }
+}
+
+/// Iterates over `error` and all of its (transitive) sources.
+pub(crate) fn chain<'e>(
+    error: &'e (dyn Error + 'static),
+) -> impl Iterator<Item = &'e (dyn Error + 'static)> {
+    core::iter::successors(Some(error), |&it| it.source())
+}
+
+/// Assertions for errors and their source chain.
+/// These work in any mode (Panic or Capture).
+///
+/// Available for all `T: Error` as well as `Box<dyn Error>` and `Box<dyn Error + Send + Sync>`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait ErrorAssertions<'t, M: Mode, K> {
+    /// Test if the `Display` representation of the error equals `expected`.
+    fn has_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the `Display` representation of the error contains `expected`.
+    fn has_message_containing(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the error has a source, then run additional assertions on it.
+    fn has_source_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, ErrorRef<'a>, M>);
+
+    /// Test if the direct source of the error is of type `E`.
+    fn has_source_of_type<E: Error + 'static>(self) -> Self;
+
+    /// Test if the root cause of the error, the last error in its source chain, is of type `E`.
+    ///
+    /// An error without a source is its own root cause.
+    fn has_root_cause_of_type<E: Error + 'static>(self) -> Self;
+
+    /// Change the subject to the `Display` representations of the error and all of its
+    /// (transitive) sources, starting with the error itself.
+    fn source_chain(self) -> AssertThat<'t, Vec<String>, M>;
+}
+
+impl<'t, T: AsError<K>, K, M: Mode> ErrorAssertions<'t, M, K> for AssertThat<'t, T, M> {
+    #[track_caller]
+    fn has_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().as_error().to_string();
+        let expected = expected.as_ref();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected message: {expected:?}
+
+                      Actual message: {actual:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_message_containing(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().as_error().to_string();
+        let expected = expected.as_ref();
+        if !actual.contains(expected) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual message: {actual:?}
+
+                    does not contain
+
+                    Expected: {expected:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_source_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, ErrorRef<'a>, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let error = self.actual().as_error();
+        if error.source().is_some() {
+            assertions(
+                self.derive(|it| ErrorRef(it.as_error().source().expect("already checked"))),
+            );
+        } else {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: {error:#?}
+
+                    has no source
+                "}?;
+                w.write_str(NESTED_ASSERTIONS_SKIPPED)
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_source_of_type<E: Error + 'static>(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let error = self.actual().as_error();
+        let source = error.source();
+        if !source.is_some_and(<dyn Error>::is::<E>) {
+            self.add_detail_message(format!("Source chain: {:#?}", display_chain(error)));
+            let expected_type_name = type_name::<E>();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected source of type: {expected_type_name}
+
+                      Actual source: {source:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_root_cause_of_type<E: Error + 'static>(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let error = self.actual().as_error();
+        let root_cause = chain(error)
+            .last()
+            .expect("chain contains the error itself");
+        if !root_cause.is::<E>() {
+            self.add_detail_message(format!("Source chain: {:#?}", display_chain(error)));
+            let expected_type_name = type_name::<E>();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected root cause of type: {expected_type_name}
+
+                      Actual root cause: {root_cause:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    fn source_chain(self) -> AssertThat<'t, Vec<String>, M> {
+        self.map(|actual| Actual::Owned(display_chain(actual.borrowed().as_error())))
+    }
+}
+
+pub(crate) fn display_chain(error: &(dyn Error + 'static)) -> Vec<String> {
+    chain(error).map(ToString::to_string).collect()
+}
+
+/// Data-extracting assertions for errors.
+/// Only available in Panic mode, as the source cannot be produced when there is none.
+/// Use `ErrorAssertions::has_source_satisfying` for capture mode.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait ErrorExtractAssertions<'t, K> {
+    /// Test if the error has a source and change the subject to it.
+    ///
+    /// As the source is again an error, this allows walking down the source chain.
+    fn has_source(&'t self) -> AssertThat<'t, ErrorRef<'t>, Panic>;
+}
+
+impl<'t, T: AsError<K>, K> ErrorExtractAssertions<'t, K> for AssertThat<'t, T, Panic> {
+    #[track_caller]
+    fn has_source(&'t self) -> AssertThat<'t, ErrorRef<'t>, Panic> {
+        self.track_assertion();
+        let error = self.actual().as_error();
+        if let Some(source) = error.source() {
+            self.derive(|_actual| ErrorRef(source))
+        } else {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: {error:#?}
+
+                    has no source
+                "}
+            });
+            unreachable!("Panic mode always panics on fail")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::error::Error;
+    use core::fmt::{Display, Formatter};
+
+    #[derive(Debug)]
+    pub struct ConnectionError;
+
+    impl Display for ConnectionError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("connection refused")
+        }
+    }
+
+    impl Error for ConnectionError {}
+
+    #[derive(Debug)]
+    pub struct RepositoryError {
+        source: ConnectionError,
+    }
+
+    impl Display for RepositoryError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("could not load user")
+        }
+    }
+
+    impl Error for RepositoryError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct ServiceError {
+        source: RepositoryError,
+    }
+
+    impl Display for ServiceError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("request failed")
+        }
+    }
+
+    impl Error for ServiceError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    pub fn service_error() -> ServiceError {
+        ServiceError {
+            source: RepositoryError {
+                source: ConnectionError,
+            },
+        }
+    }
+
+    mod has_message {
+        use super::service_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_equal() {
+            assert_that!(service_error()).has_message("request failed");
+        }
+
+        #[test]
+        fn panics_when_not_equal() {
+            assert_that_panic_by(|| {
+                assert_that!(service_error())
+                    .with_location(false)
+                    .has_message("request succeeded")
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected message: "request succeeded"
+
+                  Actual message: "request failed"
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod has_message_containing {
+        use super::service_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_contained() {
+            assert_that!(service_error()).has_message_containing("failed");
+        }
+
+        #[test]
+        fn captures_failure_when_not_contained() {
+            let failures = assert_that!(service_error())
+                .with_capture()
+                .with_location(false)
+                .has_message_containing("timeout")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual message: "request failed"
+
+                does not contain
+
+                Expected: "timeout"
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_source {
+        use super::service_error;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn allows_walking_down_the_source_chain() {
+            assert_that!(service_error())
+                .has_source()
+                .has_message("could not load user")
+                .has_source()
+                .has_message("connection refused");
+        }
+
+        #[test]
+        fn panics_when_there_is_no_source() {
+            assert_that_panic_by(|| {
+                assert_that!(super::ConnectionError)
+                    .with_location(false)
+                    .has_source();
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Actual: ConnectionError
+
+                has no source
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod has_source_satisfying {
+        use super::{ConnectionError, service_error};
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_source_satisfies_assertions() {
+            assert_that!(service_error()).has_source_satisfying(|source| {
+                source
+                    .has_message("could not load user")
+                    .has_source_of_type::<ConnectionError>();
+            });
+        }
+
+        #[test]
+        fn captures_missing_source_without_running_assertions() {
+            let failures = assert_that!(ConnectionError)
+                .with_capture()
+                .with_location(false)
+                .has_source_satisfying(|_| panic!("assertions should not run"))
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual: ConnectionError
+
+                has no source
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod has_source_of_type {
+        use super::{ConnectionError, RepositoryError, service_error};
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_direct_source_has_type() {
+            assert_that!(service_error()).has_source_of_type::<RepositoryError>();
+        }
+
+        #[test]
+        fn panics_listing_the_source_chain_when_type_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(service_error())
+                    .with_location(false)
+                    .has_source_of_type::<ConnectionError>()
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected source of type: assertr::assertions::core::error::tests::ConnectionError
+
+                  Actual source: Some(
+                    RepositoryError {{
+                        source: ConnectionError,
+                    }},
+                )
+
+                Details: [
+                    Source chain: [
+                        "request failed",
+                        "could not load user",
+                        "connection refused",
+                    ],
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod has_root_cause_of_type {
+        use super::{ConnectionError, ServiceError, service_error};
+        use crate::prelude::*;
+        use alloc::boxed::Box;
+        use core::error::Error;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_last_error_in_chain_has_type() {
+            assert_that!(service_error()).has_root_cause_of_type::<ConnectionError>();
+            assert_that!(ConnectionError).has_root_cause_of_type::<ConnectionError>();
+        }
+
+        #[test]
+        fn succeeds_for_boxed_errors() {
+            let boxed: Box<dyn Error + Send + Sync> = Box::new(service_error());
+
+            assert_that!(boxed)
+                .has_message("request failed")
+                .has_root_cause_of_type::<ConnectionError>();
+        }
+
+        #[test]
+        fn panics_when_type_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(service_error())
+                    .with_location(false)
+                    .has_root_cause_of_type::<ServiceError>()
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected root cause of type: assertr::assertions::core::error::tests::ServiceError
+
+                  Actual root cause: ConnectionError
+
+                Details: [
+                    Source chain: [
+                        "request failed",
+                        "could not load user",
+                        "connection refused",
+                    ],
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod source_chain {
+        use super::service_error;
+        use crate::prelude::*;
+
+        #[test]
+        fn extracts_display_strings_of_all_errors() {
+            assert_that!(service_error())
+                .source_chain()
+                .contains_exactly([
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]);
+        }
+    }
+}
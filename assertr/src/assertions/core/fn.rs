@@ -2,11 +2,16 @@ use crate::actual::Actual;
 use crate::mode::Panic;
 use crate::prelude::ResultExtractAssertions;
 use crate::tracking::AssertionTracking;
+#[cfg(feature = "std")]
+use crate::util::unwind::unwind_safe;
 use crate::{AssertThat, PanicValue};
+#[cfg(feature = "std")]
+use crate::{panic_hook, timeout};
 use alloc::{boxed::Box, string::String};
 use core::any::Any;
 use core::fmt::{Debug, Write};
 use core::panic::UnwindSafe;
+use core::time::Duration;
 use futures::FutureExt;
 use indoc::writedoc;
 
@@ -23,6 +28,21 @@ pub trait FnOnceAssertions<'t, R> {
     fn does_not_panic(self) -> AssertThat<'t, R, Panic>
     where
         R: Debug;
+
+    /// Calls the function and tests if it returned within `max`, changing the subject to its
+    /// output.
+    ///
+    /// The function is not interrupted when exceeding `max`, as this is not possible for
+    /// synchronous code.
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "fluent", fluent_alias("complete_within"))]
+    fn completes_within(self, max: Duration) -> AssertThat<'t, R, Panic>;
+
+    /// Calls the function and tests if it took at least `min` to return, changing the subject to
+    /// its output.
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "fluent", fluent_alias("take_at_least"))]
+    fn takes_at_least(self, min: Duration) -> AssertThat<'t, R, Panic>;
 }
 
 impl<'t, R, F: FnOnce() -> R> FnOnceAssertions<'t, R> for AssertThat<'t, F, Panic> {
@@ -31,20 +51,28 @@ impl<'t, R, F: FnOnce() -> R> FnOnceAssertions<'t, R> for AssertThat<'t, F, Pani
     fn panics(self) -> AssertThat<'t, PanicValue, Panic> {
         self.track_assertion();
 
+        let mut location = None;
         let this: AssertThat<Result<(), Box<dyn Any + Send + 'static>>, Panic> =
             self.map(|it| match it {
                 Actual::Borrowed(_) => panic!("panics() can only be called on an owned FnOnce!"),
                 Actual::Owned(f) => {
-                    // First, call the closure, receiving its output.
-                    let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(f));
+                    // The panic is expected, so it should not be printed by the panic hook.
+                    let (res, panic_location) = panic_hook::silenced(move || {
+                        // First, call the closure, receiving its output.
+                        let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(f));
+
+                        // Then, we drop the output,
+                        // while catching any panics resulting from the `Drop` implementation.
+                        let res =
+                            std::panic::catch_unwind(core::panic::AssertUnwindSafe(move || {
+                                res.map(|value| drop(value))
+                            }));
+
+                        res.flatten()
+                    });
+                    location = panic_location;
 
-                    // Then, we drop the output,
-                    // while catching any panics resulting from the `Drop` implementation.
-                    let res = std::panic::catch_unwind(core::panic::AssertUnwindSafe(move || {
-                        res.map(|value| drop(value))
-                    }));
-
-                    Actual::Owned(res.flatten())
+                    Actual::Owned(res)
                 }
             });
 
@@ -58,12 +86,10 @@ impl<'t, R, F: FnOnce() -> R> FnOnceAssertions<'t, R> for AssertThat<'t, F, Pani
             });
         }
 
-        this.is_err()
-            .with_detail_message("Function did not panic as expected!")
-            .map(|it| {
-                let boxed_any = it.unwrap_owned();
-                PanicValue(boxed_any).into()
-            })
+        this.is_err().map(|it| {
+            let boxed_any = it.unwrap_owned();
+            PanicValue(boxed_any, location).into()
+        })
     }
 
     #[track_caller]
@@ -106,6 +132,61 @@ impl<'t, R, F: FnOnce() -> R> FnOnceAssertions<'t, R> for AssertThat<'t, F, Pani
             .with_detail_message("Function panicked unexpectedly!")
             .map(|it| it.unwrap_owned().into())
     }
+
+    #[track_caller]
+    #[cfg(feature = "std")]
+    fn completes_within(self, max: Duration) -> AssertThat<'t, R, Panic> {
+        self.track_assertion();
+
+        let (this, elapsed) = call_timed(self, "completes_within");
+        if elapsed > max {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Function to complete within {max:?}
+
+                      Actual: Function completed after {elapsed:?}
+                "}
+            });
+        }
+        this
+    }
+
+    #[track_caller]
+    #[cfg(feature = "std")]
+    fn takes_at_least(self, min: Duration) -> AssertThat<'t, R, Panic> {
+        self.track_assertion();
+
+        let (this, elapsed) = call_timed(self, "takes_at_least");
+        if elapsed < min {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Function to take at least {min:?}
+
+                      Actual: Function completed after {elapsed:?}
+                "}
+            });
+        }
+        this
+    }
+}
+
+/// Calls the function, measuring the time it takes to return.
+#[cfg(feature = "std")]
+fn call_timed<'t, R, F: FnOnce() -> R>(
+    assertion: AssertThat<'t, F, Panic>,
+    method: &str,
+) -> (AssertThat<'t, R, Panic>, Duration) {
+    let mut elapsed = Duration::ZERO;
+    let this = assertion.map(|it| match it {
+        Actual::Borrowed(_) => panic!("{method}() can only be called on an owned FnOnce!"),
+        Actual::Owned(f) => {
+            let start = std::time::Instant::now();
+            let output = f();
+            elapsed = start.elapsed();
+            Actual::Owned(output)
+        }
+    });
+    (this, elapsed)
 }
 
 /// Data-extracting assertions for async `FnOnce` values.
@@ -118,6 +199,30 @@ pub trait AsyncFnOnceAssertions<'t, R> {
     fn does_not_panic_async(self) -> impl Future<Output = AssertThat<'t, R, Panic>>
     where
         R: Debug + 't;
+
+    /// Awaits the future for at most `max`, changing the subject to its output.
+    ///
+    /// The future is dropped when exceeding `max`. Uses tokio's timer when awaited within a tokio
+    /// runtime (requires the `tokio` feature), a timer thread otherwise.
+    #[cfg(feature = "std")]
+    fn completes_within_async(
+        self,
+        max: Duration,
+    ) -> impl Future<Output = AssertThat<'t, R, Panic>>
+    where
+        R: 't;
+
+    /// Awaits the future for `duration`, testing that it does not complete in that time.
+    ///
+    /// The future is dropped afterward. Uses tokio's timer when awaited within a tokio runtime
+    /// (requires the `tokio` feature), a timer thread otherwise.
+    #[cfg(feature = "std")]
+    fn does_not_complete_within_async(
+        self,
+        duration: Duration,
+    ) -> impl Future<Output = AssertThat<'t, (), Panic>>
+    where
+        R: 't;
 }
 
 impl<'t, Fut, R, F> AsyncFnOnceAssertions<'t, R> for AssertThat<'t, F, Panic>
@@ -131,7 +236,7 @@ where
         self.track_assertion();
 
         // Execute the user function
-        let this: AssertThat<Result<(), Box<dyn Any + Send>>, Panic> = self
+        let this = self
             .map_async(|it| {
                 let f = match it {
                     Actual::Borrowed(_) => {
@@ -139,7 +244,8 @@ where
                     }
                     Actual::Owned(f) => f,
                 };
-                async move {
+                // The panic is expected, so it should not be printed by the panic hook.
+                panic_hook::silenced_async(async move {
                     // First, we await the future, receiving its output.
                     let res = FutureExt::catch_unwind(f()).await;
 
@@ -150,9 +256,15 @@ where
                     }));
 
                     res.flatten()
-                }
+                })
             })
             .await;
+        let mut location = None;
+        let this = this.map(|it| {
+            let (res, panic_location) = it.unwrap_owned();
+            location = panic_location;
+            Actual::Owned(res)
+        });
 
         if this.actual().is_ok() {
             this.fail(|w: &mut String| {
@@ -164,12 +276,10 @@ where
             });
         }
 
-        this.is_err()
-            .with_detail_message("Function did not panic as expected!")
-            .map(|it| {
-                let boxed_any: Box<dyn Any + Send> = it.unwrap_owned();
-                Actual::Owned(PanicValue(boxed_any))
-            })
+        this.is_err().map(|it| {
+            let boxed_any: Box<dyn Any + Send> = it.unwrap_owned();
+            Actual::Owned(PanicValue(boxed_any, location))
+        })
     }
 
     // #[track_caller] // This is implied in the default async desugaring.
@@ -215,6 +325,78 @@ where
             .with_detail_message("Function panicked unexpectedly!")
             .map(|it| Actual::Owned(it.unwrap_owned()))
     }
+
+    // #[track_caller] // This is implied in the default async desugaring.
+    #[cfg(feature = "std")]
+    async fn completes_within_async(self, max: Duration) -> AssertThat<'t, R, Panic>
+    where
+        R: 't,
+    {
+        self.track_assertion();
+
+        let (this, elapsed) = await_timed(self, max, "completes_within_async").await;
+        if this.actual().is_none() {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Function to complete within {max:?}
+
+                      Actual: Function was still pending after {elapsed:?}
+                "}
+            });
+        }
+        this.map(|it| Actual::Owned(it.unwrap_owned().expect("completed in time")))
+    }
+
+    // #[track_caller] // This is implied in the default async desugaring.
+    #[cfg(feature = "std")]
+    async fn does_not_complete_within_async(self, duration: Duration) -> AssertThat<'t, (), Panic>
+    where
+        R: 't,
+    {
+        self.track_assertion();
+
+        let (this, elapsed) = await_timed(self, duration, "does_not_complete_within_async").await;
+        if this.actual().is_some() {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Function to not complete within {duration:?}
+
+                      Actual: Function completed after {elapsed:?}
+                "}
+            });
+        }
+        this.map(|_it| Actual::Owned(()))
+    }
+}
+
+/// Awaits the future for at most `max`, measuring the time it takes to complete.
+#[cfg(feature = "std")]
+async fn await_timed<'t, R, Fut, F>(
+    assertion: AssertThat<'t, F, Panic>,
+    max: Duration,
+    method: &str,
+) -> (AssertThat<'t, Option<R>, Panic>, Duration)
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = R>,
+    R: 't,
+{
+    let this = assertion
+        .map_async(|it| {
+            let f = match it {
+                Actual::Borrowed(_) => panic!("{method}() can only be called on an owned FnOnce!"),
+                Actual::Owned(f) => f,
+            };
+            unwind_safe(timeout::timeout(max, f()))
+        })
+        .await;
+    let mut elapsed = Duration::ZERO;
+    let this = this.map(|it| {
+        let (output, timed_elapsed) = it.unwrap_owned();
+        elapsed = timed_elapsed;
+        Actual::Owned(output)
+    });
+    (this, elapsed)
 }
 
 #[cfg(test)]
@@ -272,6 +454,60 @@ mod tests {
                 "#});
             }
         }
+
+        mod completes_within {
+            use crate::prelude::*;
+            use core::time::Duration;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_and_extracts_output_when_completing_in_time() {
+                assert_that!(|| 42)
+                    .completes_within(Duration::from_secs(10))
+                    .is_equal_to(42);
+            }
+
+            #[test]
+            fn panics_reporting_elapsed_time_when_too_slow() {
+                assert_that_panic_by(|| {
+                    assert_that!(|| std::thread::sleep(Duration::from_millis(20)))
+                        .completes_within(Duration::from_millis(1))
+                })
+                .is_assertr_failure()
+                .starts_with(formatdoc! {r#"
+                    Expected: Function to complete within 1ms
+
+                      Actual: Function completed after "#});
+            }
+        }
+
+        mod takes_at_least {
+            use crate::prelude::*;
+            use core::time::Duration;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_and_extracts_output_when_taking_long_enough() {
+                assert_that!(|| {
+                    std::thread::sleep(Duration::from_millis(20));
+                    42
+                })
+                .takes_at_least(Duration::from_millis(20))
+                .is_equal_to(42);
+            }
+
+            #[test]
+            fn panics_reporting_elapsed_time_when_too_fast() {
+                assert_that_panic_by(|| {
+                    assert_that!(|| 42).takes_at_least(Duration::from_secs(10))
+                })
+                .is_assertr_failure()
+                .starts_with(formatdoc! {r#"
+                    Expected: Function to take at least 10s
+
+                      Actual: Function completed after "#});
+            }
+        }
     }
 
     mod async_fn_once {
@@ -338,5 +574,84 @@ mod tests {
                 "#});
             }
         }
+
+        mod completes_within_async {
+            use crate::prelude::*;
+            use core::time::Duration;
+            use futures::executor::block_on;
+            use indoc::formatdoc;
+
+            #[tokio::test]
+            async fn succeeds_and_extracts_output_when_completing_in_time() {
+                assert_that!(async || 42)
+                    .completes_within_async(Duration::from_secs(10))
+                    .await
+                    .is_equal_to(42);
+            }
+
+            #[test]
+            fn succeeds_without_tokio_runtime() {
+                block_on(async {
+                    assert_that!(async || 42)
+                        .completes_within_async(Duration::from_secs(10))
+                        .await
+                        .is_equal_to(42);
+                });
+            }
+
+            #[tokio::test]
+            async fn panics_when_still_pending() {
+                assert_that_panic_by_async(async || {
+                    assert_that!(async || core::future::pending::<()>().await)
+                        .completes_within_async(Duration::from_millis(10))
+                        .await
+                })
+                .await
+                .is_assertr_failure()
+                .starts_with(formatdoc! {r#"
+                    Expected: Function to complete within 10ms
+
+                      Actual: Function was still pending after "#});
+            }
+
+            #[test]
+            fn panics_when_still_pending_without_tokio_runtime() {
+                assert_that_panic_by(|| {
+                    block_on(
+                        assert_that!(async || core::future::pending::<()>().await)
+                            .completes_within_async(Duration::from_millis(10)),
+                    )
+                })
+                .has_message_containing("Function was still pending after");
+            }
+        }
+
+        mod does_not_complete_within_async {
+            use crate::prelude::*;
+            use core::time::Duration;
+            use indoc::formatdoc;
+
+            #[tokio::test]
+            async fn succeeds_when_still_pending() {
+                assert_that!(async || core::future::pending::<()>().await)
+                    .does_not_complete_within_async(Duration::from_millis(10))
+                    .await;
+            }
+
+            #[tokio::test]
+            async fn panics_when_completing_in_time() {
+                assert_that_panic_by_async(async || {
+                    assert_that!(async || 42)
+                        .does_not_complete_within_async(Duration::from_secs(10))
+                        .await
+                })
+                .await
+                .is_assertr_failure()
+                .starts_with(formatdoc! {r#"
+                    Expected: Function to not complete within 10s
+
+                      Actual: Function completed after "#});
+            }
+        }
     }
 }
@@ -0,0 +1,220 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use core::future::Future;
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll, Waker};
+use indoc::writedoc;
+
+use crate::actual::Actual;
+use crate::mode::{Mode, Panic};
+use crate::tracking::AssertionTracking;
+use crate::{AssertThat, AssertrPartialEq};
+
+/// Assertions for `Future` values.
+/// These work in any mode (Panic or Capture).
+///
+/// All assertions consume the future, so they can only be called on owned subjects.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait FutureAssertions<'t, F: Future, M: Mode> {
+    /// Polls the future once, using a waker doing nothing, testing that it is still pending.
+    ///
+    /// Changes the subject to the pinned future, which can then be polled further, e.g. using
+    /// `resolves_to`.
+    fn is_pending_now(self) -> AssertThat<'t, Pin<Box<F>>, M>
+    where
+        F::Output: Debug;
+
+    /// Awaits the future, testing that its output equals `expected`, and changes the subject to
+    /// the output.
+    #[cfg_attr(feature = "fluent", fluent_alias("resolve_to"))]
+    fn resolves_to<E>(self, expected: E) -> impl Future<Output = AssertThat<'t, F::Output, M>>
+    where
+        F::Output: AssertrPartialEq<E> + Debug + 't,
+        E: Debug;
+}
+
+impl<'t, F: Future, M: Mode> FutureAssertions<'t, F, M> for AssertThat<'t, F, M> {
+    #[track_caller]
+    fn is_pending_now(self) -> AssertThat<'t, Pin<Box<F>>, M>
+    where
+        F::Output: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        let mut output = None;
+        let this = self.map(|it| {
+            let mut future = Box::pin(owned_future(it, "is_pending_now"));
+            if let Poll::Ready(ready) = poll_once(future.as_mut()) {
+                output = Some(ready);
+            }
+            Actual::Owned(future)
+        });
+        if let Some(output) = output {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Future to be pending
+
+                      Actual: Future completed with {output:#?}
+                "}
+            });
+        }
+        this
+    }
+
+    async fn resolves_to<E>(self, expected: E) -> AssertThat<'t, F::Output, M>
+    where
+        F::Output: AssertrPartialEq<E> + Debug + 't,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        let this = self.map_async(|it| owned_future(it, "resolves_to")).await;
+        let actual = this.actual();
+        if !AssertrPartialEq::eq(actual, &expected, None) {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected future to resolve to: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        this
+    }
+}
+
+/// Data-extracting assertions for `Future` values.
+/// Only available in Panic mode, as the output cannot be produced when the future is pending.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait FutureExtractAssertions<'t, F: Future> {
+    /// Polls the future once, using a waker doing nothing, testing that it is ready, and changes
+    /// the subject to its output.
+    fn is_ready_now(self) -> AssertThat<'t, F::Output, Panic>;
+}
+
+impl<'t, F: Future> FutureExtractAssertions<'t, F> for AssertThat<'t, F, Panic> {
+    #[track_caller]
+    fn is_ready_now(self) -> AssertThat<'t, F::Output, Panic> {
+        self.track_assertion();
+        let this = self.map(|it| {
+            let future = pin!(owned_future(it, "is_ready_now"));
+            Actual::Owned(poll_once(future))
+        });
+        if this.actual().is_pending() {
+            this.fail("Expected: Future to be ready\n\n  Actual: Future is pending\n");
+        }
+        this.map(|it| match it.unwrap_owned() {
+            Poll::Ready(output) => Actual::Owned(output),
+            Poll::Pending => unreachable!("Panic mode always panics on fail"),
+        })
+    }
+}
+
+fn owned_future<F>(actual: Actual<'_, F>, method: &str) -> F {
+    match actual {
+        Actual::Borrowed(_) => panic!("{method}() can only be called on an owned Future!"),
+        Actual::Owned(future) => future,
+    }
+}
+
+fn poll_once<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+    future.poll(&mut Context::from_waker(Waker::noop()))
+}
+
+#[cfg(test)]
+mod tests {
+    mod is_ready_now {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_output_of_ready_future() {
+            assert_that!(async { 42 }).is_ready_now().is_equal_to(42);
+        }
+
+        #[test]
+        fn panics_when_pending() {
+            assert_that_panic_by(|| {
+                assert_that!(core::future::pending::<u32>())
+                    .with_location(false)
+                    .is_ready_now();
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: Future to be ready
+
+                  Actual: Future is pending
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod is_pending_now {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_pending_and_keeps_future() {
+            let (sender, receiver) = futures::channel::oneshot::channel::<u32>();
+
+            let pending = assert_that!(receiver).is_pending_now();
+            sender.send(42).unwrap();
+
+            pending.resolves_to(Ok(42)).await;
+        }
+
+        #[test]
+        fn captures_failure_when_ready() {
+            let failures = assert_that!(async { 42 })
+                .with_capture()
+                .with_location(false)
+                .is_pending_now()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected: Future to be pending
+
+                  Actual: Future completed with 42
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod resolves_to {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_output_is_equal() {
+            assert_that!(async { String::from("foo") })
+                .resolves_to("foo")
+                .await
+                .has_length(3);
+        }
+
+        #[tokio::test]
+        async fn panics_when_output_differs() {
+            assert_that_panic_by_async(|| async {
+                assert_that!(async { 42 })
+                    .with_location(false)
+                    .resolves_to(43)
+                    .await;
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected future to resolve to: 43
+
+                  Actual: 42
+                -------- assertr --------
+            "});
+        }
+    }
+}
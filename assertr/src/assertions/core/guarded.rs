@@ -0,0 +1,292 @@
+use alloc::string::String;
+use core::cell::{Ref, RefCell};
+use core::fmt::Write;
+use core::ops::Deref;
+use indoc::writedoc;
+
+use crate::{
+    AssertThat,
+    actual::Actual,
+    failure::NESTED_ASSERTIONS_SKIPPED,
+    mode::{Mode, Panic},
+    tracking::AssertionTracking,
+};
+
+/// A value only accessible through a guard, like the value of a `Mutex` or `RefCell`.
+pub trait GuardedValue<T> {
+    type Guard<'a>: Deref<Target = T>
+    where
+        Self: 'a;
+
+    /// Tries to acquire the guard without blocking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of why the value is inaccessible, e.g. "Mutex is locked".
+    fn try_guard(&self) -> Result<Self::Guard<'_>, &'static str>;
+}
+
+impl<T> GuardedValue<T> for RefCell<T> {
+    type Guard<'a>
+        = Ref<'a, T>
+    where
+        T: 'a;
+
+    fn try_guard(&self) -> Result<Ref<'_, T>, &'static str> {
+        self.try_borrow()
+            .map_err(|_err| "RefCell is mutably borrowed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> GuardedValue<T> for std::sync::Mutex<T> {
+    type Guard<'a>
+        = std::sync::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn try_guard(&self) -> Result<std::sync::MutexGuard<'_, T>, &'static str> {
+        self.try_lock().map_err(|err| match err {
+            std::sync::TryLockError::Poisoned(_) => "Mutex is poisoned",
+            std::sync::TryLockError::WouldBlock => "Mutex is locked",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> GuardedValue<T> for std::sync::RwLock<T> {
+    type Guard<'a>
+        = std::sync::RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+
+    fn try_guard(&self) -> Result<std::sync::RwLockReadGuard<'_, T>, &'static str> {
+        self.try_read().map_err(|err| match err {
+            std::sync::TryLockError::Poisoned(_) => "RwLock is poisoned",
+            std::sync::TryLockError::WouldBlock => "RwLock is write-locked",
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> GuardedValue<T> for tokio::sync::Mutex<T> {
+    type Guard<'a>
+        = tokio::sync::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn try_guard(&self) -> Result<tokio::sync::MutexGuard<'_, T>, &'static str> {
+        self.try_lock().map_err(|_err| "Mutex is locked")
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<T> GuardedValue<T> for tokio::sync::RwLock<T> {
+    type Guard<'a>
+        = tokio::sync::RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+
+    fn try_guard(&self) -> Result<tokio::sync::RwLockReadGuard<'_, T>, &'static str> {
+        self.try_read().map_err(|_err| "RwLock is write-locked")
+    }
+}
+
+/// Assertions on the value behind a guard, for `RefCell`, std's `Mutex` and `RwLock`, and,
+/// with the `tokio` feature, tokio's `Mutex` and `RwLock`.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait GuardedValueAssertions<'t, T, M: Mode> {
+    /// Borrows or read-locks the value without blocking, then runs additional assertions on it
+    /// while the guard is held.
+    ///
+    /// Fails if the value is currently inaccessible, i.e. exclusively locked or borrowed, or if
+    /// the lock is poisoned.
+    #[cfg_attr(feature = "fluent", fluent_alias("have_value_satisfying"))]
+    fn value_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a T, M>);
+}
+
+impl<'t, T, G: GuardedValue<T>, M: Mode> GuardedValueAssertions<'t, T, M> for AssertThat<'t, G, M> {
+    #[track_caller]
+    fn value_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a T, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        match self.actual().try_guard() {
+            Ok(guard) => assertions(self.derive(|_it| &*guard)),
+            Err(reason) => self.fail(|w: &mut String| {
+                write_inaccessible(w, reason)?;
+                w.write_str(NESTED_ASSERTIONS_SKIPPED)
+            }),
+        }
+        self
+    }
+}
+
+/// Data-extracting assertions on the value behind a guard.
+/// Only available in Panic mode, as the value cannot be produced when it is inaccessible.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait GuardedValueExtractAssertions<'t, T> {
+    /// Borrows or read-locks the value without blocking, changing the subject to a clone of it.
+    /// The guard is released immediately, so the new subject is a snapshot, not affected by later
+    /// changes to the guarded value.
+    ///
+    /// Use `GuardedValueAssertions::value_satisfying` for values which cannot be cloned, or in
+    /// capture mode.
+    fn value(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Clone;
+}
+
+impl<'t, T, G: GuardedValue<T>> GuardedValueExtractAssertions<'t, T> for AssertThat<'t, G, Panic> {
+    #[track_caller]
+    fn value(self) -> AssertThat<'t, T, Panic>
+    where
+        T: Clone,
+    {
+        self.track_assertion();
+        let value = match self.actual().try_guard() {
+            Ok(guard) => T::clone(&guard),
+            Err(reason) => {
+                self.fail(|w: &mut String| write_inaccessible(w, reason));
+                unreachable!("Panic mode always panics on fail")
+            }
+        };
+        self.map(|_it| Actual::Owned(value))
+    }
+}
+
+fn write_inaccessible(w: &mut String, reason: &str) -> core::fmt::Result {
+    writedoc! {w, r"
+        Expected: Value to be accessible
+
+          Actual: {reason}
+    "}
+}
+
+#[cfg(test)]
+mod tests {
+    mod value {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::cell::RefCell;
+        use std::sync::{Mutex, RwLock};
+
+        #[test]
+        fn extracts_value_of_ref_cell() {
+            let cell = RefCell::new(5);
+            let borrow = cell.borrow();
+
+            assert_that!(&cell).value().is_equal_to(5);
+            drop(borrow);
+        }
+
+        #[test]
+        fn extracts_value_of_std_locks() {
+            assert_that!(Mutex::new(5)).value().is_equal_to(5);
+            assert_that!(RwLock::new("foo")).value().is_equal_to("foo");
+        }
+
+        #[tokio::test]
+        async fn extracts_value_of_tokio_locks() {
+            let lock = tokio::sync::RwLock::new(5);
+            let read_guard = lock.read().await;
+
+            assert_that!(tokio::sync::Mutex::new(5))
+                .value()
+                .is_equal_to(5);
+            assert_that!(&lock).value().is_equal_to(5);
+            drop(read_guard);
+        }
+
+        #[test]
+        fn panics_when_mutex_is_locked() {
+            let mutex = Mutex::new(5);
+            let guard = mutex.lock().unwrap();
+
+            assert_that_panic_by(|| assert_that!(&mutex).with_location(false).value()).has_message(
+                formatdoc! {"
+                    -------- assertr --------
+                    Expected: Value to be accessible
+
+                      Actual: Mutex is locked
+                    -------- assertr --------
+                "},
+            );
+            drop(guard);
+        }
+
+        #[test]
+        fn panics_when_mutex_is_poisoned() {
+            let mutex = Mutex::new(5);
+            assert_that_panic_by(|| {
+                let _guard = mutex.lock().unwrap();
+                panic!("poisoning the mutex");
+            })
+            .has_message("poisoning the mutex");
+
+            assert_that_panic_by(|| assert_that!(&mutex).with_location(false).value())
+                .has_message_containing("Actual: Mutex is poisoned");
+        }
+
+        #[test]
+        fn panics_when_ref_cell_is_mutably_borrowed() {
+            let cell = RefCell::new(5);
+            let borrow = cell.borrow_mut();
+
+            assert_that_panic_by(|| assert_that!(&cell).with_location(false).value())
+                .has_message_containing("Actual: RefCell is mutably borrowed");
+            drop(borrow);
+        }
+    }
+
+    mod value_satisfying {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::sync::RwLock;
+
+        #[derive(Debug, PartialEq)]
+        struct Counter {
+            count: u32,
+        }
+
+        #[test]
+        fn runs_assertions_on_values_which_cannot_be_cloned() {
+            assert_that!(RwLock::new(Counter { count: 3 })).value_satisfying(|counter| {
+                counter.is_equal_to(&Counter { count: 3 });
+            });
+        }
+
+        #[test]
+        fn captures_failure_when_write_locked() {
+            let lock = RwLock::new(Counter { count: 3 });
+            let guard = lock.write().unwrap();
+
+            let failures = assert_that!(&lock)
+                .with_capture()
+                .with_location(false)
+                .value_satisfying(|counter| {
+                    counter.is_equal_to(&Counter { count: 4 });
+                })
+                .capture_failures();
+            drop(guard);
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected: Value to be accessible
+
+                  Actual: RwLock is write-locked
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "}]);
+        }
+    }
+}
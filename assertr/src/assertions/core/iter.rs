@@ -6,6 +6,7 @@ use core::fmt::{Debug, Write};
 use indoc::writedoc;
 
 use crate::actual::Actual;
+use crate::prelude::DuplicatesAssertions;
 use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
 
 #[allow(clippy::return_self_not_must_use)]
@@ -32,6 +33,73 @@ pub trait IteratorAssertions<'t, T: Debug, M: Mode> {
         T: PartialEq<E>,
         T: AssertrPartialEq<E> + Debug,
         't: 'u;
+
+    /// Extract a single value from every element yielded by the iterator, collecting them into a
+    /// new `Vec` subject.
+    ///
+    /// This consumes the underlying iterator. Elements are handed to the extractor by value.
+    fn extracting<U>(self, extractor: impl FnMut(T) -> U) -> AssertThat<'t, Vec<U>, M>;
+
+    /// Extract any number of values from every element yielded by the iterator, flattening them
+    /// into a new `Vec` subject.
+    ///
+    /// This consumes the underlying iterator. Elements are handed to the extractor by value.
+    fn flat_extracting<U, C>(self, extractor: impl FnMut(T) -> C) -> AssertThat<'t, Vec<U>, M>
+    where
+        C: IntoIterator<Item = U>;
+
+    /// Collect all elements yielded by the iterator into a new `Vec` subject, e.g. to assert on
+    /// statistics of the yielded values.
+    ///
+    /// This consumes the underlying iterator.
+    fn collected(self) -> AssertThat<'t, Vec<T>, M>;
+
+    /// This is a terminal assertion, as it must consume the underlying iterator.
+    ///
+    /// Elements are compared using their `Hash` and `Eq` implementations.
+    /// Requires the `std` feature. Use `has_no_duplicates_by_eq` for element types which cannot be hashed,
+    /// or without `std`.
+    #[cfg(feature = "std")]
+    fn has_no_duplicates<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: Eq + core::hash::Hash,
+        't: 'u;
+
+    /// This is a terminal assertion, as it must consume the underlying iterator.
+    ///
+    /// Elements are compared using their `Hash` and `Eq` implementations.
+    /// Requires the `std` feature. Use `has_duplicates_by_eq` for element types which cannot be hashed,
+    /// or without `std`.
+    #[cfg(feature = "std")]
+    fn has_duplicates<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: Eq + core::hash::Hash,
+        't: 'u;
+
+    /// This is a terminal assertion, as it must consume the underlying iterator.
+    ///
+    /// Elements are compared pairwise using `AssertrPartialEq`, which takes O(n²) comparisons.
+    fn has_no_duplicates_by_eq<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: AssertrPartialEq,
+        't: 'u;
+
+    /// This is a terminal assertion, as it must consume the underlying iterator.
+    ///
+    /// Elements are compared pairwise using `AssertrPartialEq`, which takes O(n²) comparisons.
+    fn has_duplicates_by_eq<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: AssertrPartialEq,
+        't: 'u;
+
+    /// This is a terminal assertion, as it must consume the underlying iterator.
+    ///
+    /// Keys are compared using their `Hash` and `Eq` implementations. Requires the `std` feature.
+    #[cfg(feature = "std")]
+    fn has_unique_by_key<'u, K>(self, key: impl Fn(&T) -> K) -> AssertThat<'u, (), M>
+    where
+        K: Eq + core::hash::Hash + Debug,
+        't: 'u;
 }
 
 impl<'t, T, I, M: Mode> IteratorAssertions<'t, T, M> for AssertThat<'t, I, M>
@@ -49,7 +117,9 @@ where
         self.track_assertion();
         // Any iterator can only be iterated once! Take it.
         let (actual, this) = self.replace_actual_with(Actual::Owned(()));
-
+        let Some(actual) = actual else {
+            return this;
+        };
         let actual = actual.unwrap_owned().collect::<Vec<_>>();
         let expected = expected;
         if !actual
@@ -77,6 +147,10 @@ where
         self.track_assertion();
 
         let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+
+        let Some(actual) = actual else {
+            return this;
+        };
         let actual = actual.unwrap_owned().collect::<Vec<_>>();
 
         if actual
@@ -106,6 +180,9 @@ where
 
         let (actual, this) = self.replace_actual_with(Actual::Owned(()));
 
+        let Some(actual) = actual else {
+            return this;
+        };
         let actual = actual.unwrap_owned().collect::<Vec<_>>();
         let expected = expected.as_ref();
 
@@ -134,6 +211,100 @@ where
         }
         this
     }
+
+    fn extracting<U>(self, extractor: impl FnMut(T) -> U) -> AssertThat<'t, Vec<U>, M> {
+        self.map(|actual| Actual::Owned(actual.unwrap_owned().map(extractor).collect()))
+    }
+
+    fn flat_extracting<U, C>(self, extractor: impl FnMut(T) -> C) -> AssertThat<'t, Vec<U>, M>
+    where
+        C: IntoIterator<Item = U>,
+    {
+        self.map(|actual| Actual::Owned(actual.unwrap_owned().flat_map(extractor).collect()))
+    }
+
+    fn collected(self) -> AssertThat<'t, Vec<T>, M> {
+        self.map(|actual| Actual::Owned(actual.unwrap_owned().collect()))
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn has_no_duplicates<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: Eq + core::hash::Hash,
+        't: 'u,
+    {
+        let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+        let Some(actual) = actual else {
+            return this;
+        };
+        let actual = actual.unwrap_owned().collect::<Vec<_>>();
+        this.derive(|()| actual.as_slice()).has_no_duplicates();
+        this
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn has_duplicates<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: Eq + core::hash::Hash,
+        't: 'u,
+    {
+        let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+        let Some(actual) = actual else {
+            return this;
+        };
+        let actual = actual.unwrap_owned().collect::<Vec<_>>();
+        this.derive(|()| actual.as_slice()).has_duplicates();
+        this
+    }
+
+    #[track_caller]
+    fn has_no_duplicates_by_eq<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: AssertrPartialEq,
+        't: 'u,
+    {
+        let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+        let Some(actual) = actual else {
+            return this;
+        };
+        let actual = actual.unwrap_owned().collect::<Vec<_>>();
+        this.derive(|()| actual.as_slice())
+            .has_no_duplicates_by_eq();
+        this
+    }
+
+    #[track_caller]
+    fn has_duplicates_by_eq<'u>(self) -> AssertThat<'u, (), M>
+    where
+        T: AssertrPartialEq,
+        't: 'u,
+    {
+        let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+        let Some(actual) = actual else {
+            return this;
+        };
+        let actual = actual.unwrap_owned().collect::<Vec<_>>();
+        this.derive(|()| actual.as_slice()).has_duplicates_by_eq();
+        this
+    }
+
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn has_unique_by_key<'u, K>(self, key: impl Fn(&T) -> K) -> AssertThat<'u, (), M>
+    where
+        K: Eq + core::hash::Hash + Debug,
+        't: 'u,
+    {
+        let (actual, this) = self.replace_actual_with(Actual::Owned(()));
+        let Some(actual) = actual else {
+            return this;
+        };
+        let actual = actual.unwrap_owned().collect::<Vec<_>>();
+        this.derive(|()| actual.as_slice()).has_unique_by_key(key);
+        this
+    }
 }
 
 /// Assertions for any type convertable to some `Iterator` using the `IntoIterator` trait.
@@ -175,6 +346,9 @@ where
         T: Debug + AssertrPartialEq<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().into_iter().collect::<Vec<_>>();
         let expected = expected;
         if !self
@@ -200,6 +374,9 @@ where
         T: Debug + AssertrPartialEq<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().into_iter().collect::<Vec<_>>();
 
         if actual
@@ -225,6 +402,9 @@ where
         T: AssertrPartialEq<E> + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().into_iter().collect::<Vec<_>>();
         let expected = expected.as_ref().iter().collect::<Vec<_>>();
 
@@ -258,6 +438,9 @@ where
     #[track_caller]
     fn into_iter_iterator_is_empty(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().into_iter().count() != 0 {
             let actual = self.actual().into_iter().collect::<Vec<_>>();
             self.fail(|w: &mut String| {
@@ -325,6 +508,148 @@ mod tests {
                     "});
             }
         }
+
+        mod extracting {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn extracts_from_borrowing_iterator() {
+                let values = [(1, "one"), (2, "two"), (3, "three")];
+                assert_that!(values.iter())
+                    .extracting(|(_, name)| *name)
+                    .contains_exactly(["one", "two", "three"]);
+            }
+
+            #[test]
+            fn extracts_from_owning_iterator() {
+                assert_that!(vec!["a".to_owned(), "bb".to_owned()].into_iter())
+                    .extracting(|it| it.len())
+                    .contains_exactly([1, 2]);
+            }
+
+            #[test]
+            fn panics_when_extracted_values_do_not_match() {
+                assert_that_panic_by(|| {
+                    assert_that!(1..4)
+                        .with_location(false)
+                        .extracting(|it| it * 2)
+                        .contains(3);
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            2,
+                            4,
+                            6,
+                        ]
+
+                        does not contain expected: 3
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod flat_extracting {
+            use crate::prelude::*;
+
+            #[test]
+            fn flattens_extracted_values() {
+                let words = ["ab", "", "c"];
+                assert_that!(words.iter())
+                    .flat_extracting(|word| word.chars())
+                    .contains_exactly(['a', 'b', 'c']);
+            }
+        }
+
+        mod collected {
+            use crate::prelude::*;
+
+            #[test]
+            fn collects_all_yielded_values() {
+                assert_that!((1..=3).map(|it| it * 2))
+                    .collected()
+                    .contains_exactly([2, 4, 6]);
+            }
+        }
+
+        mod has_no_duplicates {
+            use crate::prelude::*;
+            use indoc::formatdoc;
+
+            #[test]
+            fn succeeds_when_all_elements_are_unique() {
+                assert_that!(1..4).has_no_duplicates();
+            }
+
+            #[test]
+            fn panics_listing_every_duplicate_with_its_indices() {
+                assert_that_panic_by(|| {
+                    assert_that!([1, 2, 1].into_iter())
+                        .with_location(false)
+                        .has_no_duplicates();
+                })
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {"
+                        -------- assertr --------
+                        Actual: [
+                            1,
+                            2,
+                            1,
+                        ]
+
+                        was expected to contain no duplicates, but it does!
+
+                        Duplicates: [
+                            1 at indices [0, 2],
+                        ]
+                        -------- assertr --------
+                    "});
+            }
+        }
+
+        mod has_duplicates {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_an_element_is_duplicated() {
+                assert_that!("abca".chars()).has_duplicates();
+            }
+        }
+
+        mod has_no_duplicates_by_eq {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_for_unhashable_elements() {
+                assert_that!([0.1, 0.2].into_iter()).has_no_duplicates_by_eq();
+            }
+
+            #[test]
+            fn detects_duplicates_of_unhashable_elements() {
+                assert_that!([0.1, 0.1].into_iter()).has_duplicates_by_eq();
+            }
+        }
+
+        mod has_unique_by_key {
+            use crate::prelude::*;
+
+            #[test]
+            fn succeeds_when_all_keys_are_unique() {
+                assert_that!(["a", "bb"].iter()).has_unique_by_key(|it| it.len());
+            }
+
+            #[test]
+            fn fails_when_a_key_is_duplicated() {
+                assert_that!(["a", "b"].iter())
+                    .with_capture()
+                    .has_unique_by_key(|it| it.len())
+                    .capture_failures()
+                    .must()
+                    .have_length(1);
+            }
+        }
     }
 
     mod into_iterator_assertions {
@@ -22,6 +22,9 @@ impl<T: HasLength + Debug, M: Mode> LengthAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn is_empty(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if !self.actual().is_empty() {
             let actual = self.actual();
             let type_name = core::any::type_name::<T>();
@@ -39,6 +42,9 @@ impl<T: HasLength + Debug, M: Mode> LengthAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn is_not_empty(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().is_empty() {
             let actual = self.actual();
             let type_name = core::any::type_name::<T>();
@@ -56,6 +62,9 @@ impl<T: HasLength + Debug, M: Mode> LengthAssertions for AssertThat<'_, T, M> {
     #[track_caller]
     fn has_length(self, expected: usize) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual_len = self.actual().length();
         if actual_len != expected {
             let type_name = core::any::type_name::<T>();
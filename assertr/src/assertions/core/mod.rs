@@ -1,12 +1,21 @@
+pub mod approx_eq;
 pub mod array;
+pub mod atomic;
 pub mod bool;
+pub mod cell;
 pub mod char;
+pub mod checked;
 pub mod debug;
 pub mod display;
+pub mod error;
 #[cfg(feature = "std")]
 pub mod r#fn;
+pub mod future;
+pub mod guarded;
 pub mod iter;
 pub mod length;
+pub mod non_zero;
+pub mod once;
 pub mod option;
 pub mod partial_eq;
 pub mod partial_ord;
@@ -14,24 +23,40 @@ pub mod poll;
 pub mod range;
 pub mod ref_cell;
 pub mod result;
+pub mod saturating;
 pub mod slice;
 pub mod str_slice;
+pub mod stream;
+pub mod wrapping;
 
 pub mod prelude {
+    pub use super::approx_eq::ApproxEqAssertions;
     pub use super::array::ArrayAssertions;
+    pub use super::atomic::AtomicAssertions;
     pub use super::bool::BoolAssertions;
+    pub use super::cell::CellAssertions;
     pub use super::char::CharAssertions;
+    pub use super::checked::CheckedArithmeticAssertions;
     pub use super::debug::DebugAssertions;
     pub use super::display::DisplayAssertions;
+    pub use super::error::ErrorAssertions;
+    pub use super::error::ErrorExtractAssertions;
     // All inner fn's are already std-gated, so we remove this otherwise noise-generating export.
     #[cfg(feature = "std")]
     pub use super::r#fn::AsyncFnOnceAssertions;
     // All inner fn's are already std-gated, so we remove this otherwise noise-generating export.
     #[cfg(feature = "std")]
     pub use super::r#fn::FnOnceAssertions;
+    pub use super::future::FutureAssertions;
+    pub use super::future::FutureExtractAssertions;
+    pub use super::guarded::GuardedValueAssertions;
+    pub use super::guarded::GuardedValueExtractAssertions;
     pub use super::iter::IntoIteratorAssertions;
     pub use super::iter::IteratorAssertions;
     pub use super::length::LengthAssertions;
+    pub use super::non_zero::NonZeroAssertions;
+    pub use super::once::OnceValueAssertions;
+    pub use super::once::OnceValueExtractAssertions;
     pub use super::option::OptionAssertions;
     pub use super::option::OptionExtractAssertions;
     pub use super::partial_eq::PartialEqAssertions;
@@ -43,8 +68,11 @@ pub mod prelude {
     pub use super::ref_cell::RefCellAssertions;
     pub use super::result::ResultAssertions;
     pub use super::result::ResultExtractAssertions;
+    pub use super::saturating::SaturatingAssertions;
     pub use super::slice::SliceAssertions;
     pub use super::str_slice::StrSliceAssertions;
+    pub use super::stream::StreamAssertions;
+    pub use super::wrapping::WrappingAssertions;
 }
 
 pub(crate) fn strip_quotation_marks(mut str: &str) -> &str {
@@ -0,0 +1,82 @@
+use core::num::NonZero;
+
+use crate::AssertThat;
+use crate::actual::Actual;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+
+/// Assertions for `NonZero` integers.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait NonZeroAssertions<'t, T, M: Mode> {
+    /// Extracts the inner integer, allowing it to be checked using all integer assertions.
+    ///
+    /// ```
+    /// use assertr::prelude::*;
+    /// use std::num::NonZeroU32;
+    ///
+    /// assert_that!(NonZeroU32::new(42).unwrap())
+    ///     .value()
+    ///     .is_even()
+    ///     .is_greater_than(40);
+    /// ```
+    fn value(self) -> AssertThat<'t, T, M>;
+}
+
+macro_rules! impl_non_zero_assertions {
+    ($($t:ty),*) => {
+        $(
+            impl<'t, M: Mode> NonZeroAssertions<'t, $t, M> for AssertThat<'t, NonZero<$t>, M> {
+                #[track_caller]
+                fn value(self) -> AssertThat<'t, $t, M> {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self.poison();
+                    }
+                    self.map(|actual| Actual::Owned(actual.borrowed().get()))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_assertions!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[cfg(test)]
+mod tests {
+    mod value {
+        use crate::prelude::*;
+        use core::num::{NonZeroI8, NonZeroU32};
+        use indoc::formatdoc;
+
+        #[test]
+        fn extracts_inner_value() {
+            assert_that!(NonZeroU32::new(42).unwrap())
+                .value()
+                .is_equal_to(42)
+                .is_greater_than(40);
+            assert_that!(NonZeroI8::new(-1).unwrap())
+                .value()
+                .is_negative();
+        }
+
+        #[test]
+        fn panics_when_inner_value_does_not_match() {
+            assert_that_panic_by(|| {
+                assert_that!(NonZeroU32::new(3).unwrap())
+                    .with_location(false)
+                    .value()
+                    .is_equal_to(4);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected: 4
+
+                      Actual: 3
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
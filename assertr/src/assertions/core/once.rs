@@ -0,0 +1,336 @@
+use alloc::string::String;
+use core::cell::OnceCell;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+
+use crate::{
+    AssertThat,
+    actual::Actual,
+    mode::{Mode, Panic},
+    tracking::AssertionTracking,
+};
+
+/// A value initialized at most once, like the value of a `OnceCell`.
+pub trait OnceValue<T> {
+    /// Names the type in failure messages.
+    const NAME: &'static str;
+
+    /// Returns the value, if initialized, without initializing it.
+    fn get_initialized(&self) -> Option<&T>;
+}
+
+/// A [`OnceValue`] whose value can be taken out of it.
+pub trait IntoOnceValue<T>: OnceValue<T> + Sized {
+    /// Consumes `self`, returning the value, if initialized, without initializing it.
+    fn into_initialized(self) -> Option<T>;
+}
+
+impl<T> OnceValue<T> for OnceCell<T> {
+    const NAME: &'static str = "OnceCell";
+
+    fn get_initialized(&self) -> Option<&T> {
+        self.get()
+    }
+}
+
+impl<T> IntoOnceValue<T> for OnceCell<T> {
+    fn into_initialized(self) -> Option<T> {
+        self.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> OnceValue<T> for std::sync::OnceLock<T> {
+    const NAME: &'static str = "OnceLock";
+
+    fn get_initialized(&self) -> Option<&T> {
+        self.get()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> IntoOnceValue<T> for std::sync::OnceLock<T> {
+    fn into_initialized(self) -> Option<T> {
+        self.into_inner()
+    }
+}
+
+/// `LazyLock::get` is stable since Rust 1.94. `LazyLock::into_inner` is not stable yet, so there
+/// is no [`IntoOnceValue`] implementation.
+#[cfg(feature = "std")]
+#[rustversion::since(1.94)]
+impl<T, F: FnOnce() -> T> OnceValue<T> for std::sync::LazyLock<T, F> {
+    const NAME: &'static str = "LazyLock";
+
+    fn get_initialized(&self) -> Option<&T> {
+        std::sync::LazyLock::get(self)
+    }
+}
+
+/// Assertions for the initialization state of `OnceCell`, `OnceLock` and `LazyLock` values.
+/// These work in any mode (Panic or Capture).
+///
+/// None of these assertions initialize the value.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait OnceValueAssertions<'t, T, M: Mode> {
+    fn is_initialized(self) -> Self;
+
+    fn is_uninitialized(self) -> Self
+    where
+        T: Debug;
+
+    /// Test that the value is initialized, then run additional assertions on it.
+    #[cfg_attr(feature = "fluent", fluent_alias("have_initialized_value_satisfying"))]
+    fn initialized_value_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a T, M>);
+}
+
+impl<'t, T, O: OnceValue<T>, M: Mode> OnceValueAssertions<'t, T, M> for AssertThat<'t, O, M> {
+    #[track_caller]
+    fn is_initialized(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if self.actual().get_initialized().is_none() {
+            self.fail(|w: &mut String| write_uninitialized::<O, T>(w));
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_uninitialized(self) -> Self
+    where
+        T: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if let Some(value) = self.actual().get_initialized() {
+            let name = O::NAME;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {name} to be uninitialized
+
+                      Actual: {name} is initialized with {value:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn initialized_value_satisfying<A>(self, assertions: A) -> Self
+    where
+        A: for<'a> FnOnce(AssertThat<'a, &'a T, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        self.satisfies_extracted(
+            O::get_initialized,
+            |_actual, w| write_uninitialized::<O, T>(w),
+            assertions,
+        )
+    }
+}
+
+fn write_uninitialized<O: OnceValue<T>, T>(w: &mut String) -> core::fmt::Result {
+    let name = O::NAME;
+    writedoc! {w, r"
+        Expected: {name} to be initialized
+
+          Actual: {name} is uninitialized
+    "}
+}
+
+/// Data-extracting assertions for `OnceCell` and `OnceLock` values.
+/// Only available in Panic mode, as the value cannot be produced when it is uninitialized.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait OnceValueExtractAssertions<'t, T> {
+    /// Test that the value is initialized and change the subject to it.
+    ///
+    /// Use `OnceValueAssertions::initialized_value_satisfying` for capture mode.
+    fn initialized_value(self) -> AssertThat<'t, T, Panic>;
+}
+
+impl<'t, T, O: IntoOnceValue<T>> OnceValueExtractAssertions<'t, T> for AssertThat<'t, O, Panic> {
+    #[track_caller]
+    fn initialized_value(self) -> AssertThat<'t, T, Panic> {
+        self.track_assertion();
+
+        if self.actual().get_initialized().is_none() {
+            self.fail(|w: &mut String| write_uninitialized::<O, T>(w));
+        }
+
+        self.map(|actual| match actual {
+            Actual::Owned(o) => Actual::Owned(o.into_initialized().unwrap()),
+            Actual::Borrowed(b) => Actual::Borrowed(b.get_initialized().unwrap()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod is_initialized {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::cell::OnceCell;
+        use std::sync::OnceLock;
+
+        #[test]
+        fn succeeds_when_initialized() {
+            assert_that!(OnceCell::from(42)).is_initialized();
+            assert_that!(OnceLock::from(42)).is_initialized();
+        }
+
+        #[rustversion::since(1.94)]
+        #[test]
+        fn succeeds_when_lazy_lock_was_forced() {
+            let lazy = std::sync::LazyLock::new(|| 42);
+            let _ = *lazy;
+
+            assert_that!(&lazy).is_initialized();
+        }
+
+        #[test]
+        fn panics_when_uninitialized() {
+            assert_that_panic_by(|| {
+                assert_that!(OnceLock::<u32>::new())
+                    .with_location(false)
+                    .is_initialized()
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: OnceLock to be initialized
+
+                  Actual: OnceLock is uninitialized
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod is_uninitialized {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::cell::OnceCell;
+        use std::sync::OnceLock;
+
+        #[test]
+        fn succeeds_when_uninitialized() {
+            let lock = OnceLock::<u32>::new();
+
+            assert_that!(&lock).is_uninitialized().is_uninitialized();
+        }
+
+        #[rustversion::since(1.94)]
+        #[test]
+        fn does_not_initialize_lazy_lock() {
+            let lazy = std::sync::LazyLock::new(|| 42);
+
+            assert_that!(&lazy).is_uninitialized().is_uninitialized();
+        }
+
+        #[test]
+        fn captures_failure_when_initialized() {
+            let failures = assert_that!(OnceCell::from("foo"))
+                .with_capture()
+                .with_location(false)
+                .is_uninitialized()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected: OnceCell to be uninitialized
+
+                  Actual: OnceCell is initialized with "foo"
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod initialized_value {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::cell::OnceCell;
+        use std::sync::OnceLock;
+
+        #[test]
+        fn extracts_value_when_initialized() {
+            let lock = OnceLock::new();
+            lock.set(42).unwrap();
+
+            assert_that!(&lock).initialized_value().is_equal_to(42);
+        }
+
+        #[test]
+        fn takes_value_out_of_owned_subject() {
+            assert_that!(OnceCell::from(String::from("foo")))
+                .initialized_value()
+                .is_equal_to(String::from("foo"));
+        }
+
+        #[test]
+        fn panics_when_uninitialized() {
+            let cell = OnceCell::<u32>::new();
+
+            assert_that_panic_by(|| {
+                assert_that!(&cell)
+                    .with_location(false)
+                    .initialized_value()
+                    .is_equal_to(42);
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: OnceCell to be initialized
+
+                  Actual: OnceCell is uninitialized
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod initialized_value_satisfying {
+        use crate::prelude::*;
+        use std::cell::OnceCell;
+
+        #[test]
+        fn succeeds_when_value_satisfies_assertions() {
+            assert_that!(OnceCell::from(42)).initialized_value_satisfying(|value| {
+                value.is_greater_than(&40);
+            });
+        }
+
+        #[rustversion::since(1.94)]
+        #[test]
+        fn captures_failure_when_lazy_lock_is_uninitialized() {
+            let lazy = std::sync::LazyLock::new(|| 42);
+
+            let failures = assert_that!(&lazy)
+                .with_capture()
+                .initialized_value_satisfying(|value| {
+                    value.is_greater_than(&40);
+                })
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+            assert_that!(std::sync::LazyLock::get(&lazy)).is_none();
+        }
+
+        #[test]
+        fn captures_failure_and_skips_assertions_when_uninitialized() {
+            let failures = assert_that!(OnceCell::<u32>::new())
+                .with_capture()
+                .initialized_value_satisfying(|value| {
+                    value.is_greater_than(&40);
+                })
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
+    }
+}
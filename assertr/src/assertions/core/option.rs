@@ -1,4 +1,7 @@
-use crate::{AssertThat, Mode, actual::Actual, mode::Panic, tracking::AssertionTracking};
+use crate::{
+    AssertThat, AssertrPartialEq, EqContext, Mode, actual::Actual, tracking::AssertionTracking,
+};
+use alloc::format;
 use alloc::string::String;
 use core::fmt::{Debug, Write};
 use core::option::Option;
@@ -6,36 +9,40 @@ use indoc::writedoc;
 
 /// Data-extracting assertion for `Option` values.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait OptionExtractAssertions<'t, T> {
+pub trait OptionExtractAssertions<'t, T, M: Mode> {
     /// Test if this option is of the `Some` variant.
     /// This is a terminal operation on the contained `Option`,
     /// as there is nothing meaningful to do with the option if its variant was ensured.
     /// This allows you to chain additional expectations on the contained success value.
     ///
-    /// Only available in `Panic` mode, as the extracted `T` cannot be produced when the value is
-    /// `None`. Use `OptionAssertions::is_some_satisfying` for capture mode.
-    fn is_some(self) -> AssertThat<'t, T, Panic>
+    /// In `Capture` mode, the subject is poisoned when the value is `None`, skipping all
+    /// subsequent assertions.
+    fn is_some(self) -> AssertThat<'t, T, M>
     where
         T: Debug;
 }
 
-impl<'t, T> OptionExtractAssertions<'t, T> for AssertThat<'t, Option<T>, Panic> {
+impl<'t, T, M: Mode> OptionExtractAssertions<'t, T, M> for AssertThat<'t, Option<T>, M> {
     #[track_caller]
-    fn is_some(self) -> AssertThat<'t, T, Panic>
+    fn is_some(self) -> AssertThat<'t, T, M>
     where
         T: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
         if !self.actual().is_some() {
             let actual = self.actual();
-            self.fail(|w: &mut String| {
+            self.fail_extraction(|w: &mut String| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Option::Some
                 "}
             });
+            return self.poison();
         }
 
         self.map(|actual| match actual {
@@ -62,6 +69,22 @@ pub trait OptionAssertions<'t, T, M: Mode> {
     fn is_none(self) -> AssertThat<'t, (), M>
     where
         T: Debug;
+
+    /// Test if this option is of the `Some` variant, holding a value equal to `expected`.
+    fn has_some_value<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    /// Synonym for `has_some_value`.
+    fn is_some_and_equal_to<E>(self, expected: E) -> Self
+    where
+        Self: Sized,
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.has_some_value(expected)
+    }
 }
 
 impl<'t, T, M: Mode> OptionAssertions<'t, T, M> for AssertThat<'t, Option<T>, M> {
@@ -72,20 +95,21 @@ impl<'t, T, M: Mode> OptionAssertions<'t, T, M> for AssertThat<'t, Option<T>, M>
         A: for<'a> FnOnce(AssertThat<'a, &'a T, M>),
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
-        if self.actual().is_some() {
-            self.satisfies_ref(|it| it.as_ref().unwrap(), assertions)
-        } else {
-            let actual = self.actual();
-            self.fail(|w: &mut String| {
+        self.satisfies_extracted(
+            Option::as_ref,
+            |actual, w| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Option::Some
                 "}
-            });
-            self
-        }
+            },
+            assertions,
+        )
     }
 
     #[track_caller]
@@ -94,6 +118,9 @@ impl<'t, T, M: Mode> OptionAssertions<'t, T, M> for AssertThat<'t, Option<T>, M>
         T: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
         if !self.actual().is_none() {
             let actual = self.actual();
@@ -108,6 +135,43 @@ impl<'t, T, M: Mode> OptionAssertions<'t, T, M> for AssertThat<'t, Option<T>, M>
 
         self.map(|_actual| Actual::Owned(()))
     }
+
+    #[track_caller]
+    fn has_some_value<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let actual = self.actual();
+        let expected = Some(expected);
+
+        let mut ctx = EqContext::default();
+
+        let eq = match (actual, &expected) {
+            (Some(actual), Some(expected)) => {
+                AssertrPartialEq::eq(actual, expected, Some(&mut ctx))
+            }
+            _ => false,
+        };
+        if !eq {
+            if !ctx.differences.differences.is_empty() {
+                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +203,38 @@ mod tests {
                 -------- assertr --------
             "});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_none() {
+            let failures = assert_that!(Option::<i32>::None)
+                .with_capture()
+                .with_location(false)
+                .is_some()
+                .is_equal_to(42)
+                .is_negative()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Actual: None
+
+                is not of expected variant: Option::Some
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "}]);
+        }
+
+        #[test]
+        fn captures_failures_of_subsequent_assertions_when_some() {
+            let failures = assert_that!(Option::<i32>::Some(42))
+                .with_capture()
+                .is_some()
+                .is_equal_to(43)
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
     }
 
     mod is_some_satisfying {
@@ -186,6 +282,8 @@ mod tests {
                 Actual: None
 
                 is not of expected variant: Option::Some
+
+                Nested assertions were skipped, as this precondition failed.
                 -------- assertr --------
             "}]);
         }
@@ -203,11 +301,76 @@ mod tests {
                 Actual: None
 
                 is not of expected variant: Option::Some
+
+                Nested assertions were skipped, as this precondition failed.
                 -------- assertr --------
             "});
         }
     }
 
+    mod has_some_value {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_some_with_equal_value() {
+            assert_that!(Some(42)).has_some_value(42);
+            assert_that!(Some(String::from("foo"))).is_some_and_equal_to("foo");
+        }
+
+        #[test]
+        fn panics_when_value_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(Some(42))
+                    .with_location(false)
+                    .has_some_value(43)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {"
+                -------- assertr --------
+                Expected: Some(
+                    43,
+                )
+
+                  Actual: Some(
+                    42,
+                )
+                -------- assertr --------
+            "});
+        }
+
+        #[test]
+        fn captures_variant_failure_when_none() {
+            let failures = assert_that!(Option::<i32>::None)
+                .with_capture()
+                .with_location(false)
+                .has_some_value(42)
+                .is_some_and_equal_to(42)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([
+                formatdoc! {"
+                    -------- assertr --------
+                    Expected: Some(
+                        42,
+                    )
+
+                      Actual: None
+                    -------- assertr --------
+                "},
+                formatdoc! {"
+                    -------- assertr --------
+                    Expected: Some(
+                        42,
+                    )
+
+                      Actual: None
+                    -------- assertr --------
+                "},
+            ]);
+        }
+    }
+
     mod is_none {
         use crate::prelude::*;
         use alloc::string::String;
@@ -28,23 +28,14 @@ impl<T, M: Mode> PartialEqAssertions<T> for AssertThat<'_, T, M> {
         E: Debug,
     {
         self.track_assertion();
-
-        let actual = self.actual();
-        let expected = &expected;
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let mut ctx = EqContext::default();
 
-        if !AssertrPartialEq::eq(actual, expected, Some(&mut ctx)) {
-            if !ctx.differences.differences.is_empty() {
-                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
-            }
-            self.fail(|w: &mut String| {
-                writedoc! {w, r"
-                    Expected: {expected:#?}
-                    
-                      Actual: {actual:#?}
-                "}
-            });
+        if !AssertrPartialEq::eq(self.actual(), &expected, Some(&mut ctx)) {
+            fail_with_differences(&self, &expected, &ctx);
         }
         self
     }
@@ -56,28 +47,38 @@ impl<T, M: Mode> PartialEqAssertions<T> for AssertThat<'_, T, M> {
         E: Debug,
     {
         self.track_assertion();
-
-        let actual = self.actual();
-        let expected = &expected;
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let mut ctx = EqContext::default();
 
-        if AssertrPartialEq::eq(actual, expected, Some(&mut ctx)) {
-            if !ctx.differences.differences.is_empty() {
-                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
-            }
-            self.fail(|w: &mut String| {
-                writedoc! {w, r"
-                    Expected: {expected:#?}
-
-                      Actual: {actual:#?}
-                "}
-            });
+        if AssertrPartialEq::eq(self.actual(), &expected, Some(&mut ctx)) {
+            fail_with_differences(&self, &expected, &ctx);
         }
         self
     }
 }
 
+/// Fails with the `Expected` and `Actual` values, listing the differences recorded in `ctx`.
+#[track_caller]
+pub(crate) fn fail_with_differences<T: Debug, E: Debug, M: Mode>(
+    assert: &AssertThat<'_, T, M>,
+    expected: &E,
+    ctx: &EqContext,
+) {
+    if !ctx.differences.differences.is_empty() {
+        assert.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+    }
+    assert.fail(|w: &mut String| {
+        writedoc! {w, r"
+            Expected: {expected:#?}
+
+              Actual: {actual:#?}
+        ", actual = assert.actual()}
+    });
+}
+
 #[cfg(test)]
 mod tests {
     mod is_equal_to {
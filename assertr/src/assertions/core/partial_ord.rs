@@ -41,6 +41,9 @@ impl<T: Debug, M: Mode> PartialOrdAssertions<T> for AssertThat<'_, T, M> {
         T: PartialOrd<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
         let expected = expected.borrow();
@@ -66,6 +69,9 @@ impl<T: Debug, M: Mode> PartialOrdAssertions<T> for AssertThat<'_, T, M> {
         T: PartialOrd<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
         let expected = expected.borrow();
@@ -91,6 +97,9 @@ impl<T: Debug, M: Mode> PartialOrdAssertions<T> for AssertThat<'_, T, M> {
         T: PartialOrd<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
         let expected = expected.borrow();
@@ -119,6 +128,9 @@ impl<T: Debug, M: Mode> PartialOrdAssertions<T> for AssertThat<'_, T, M> {
         T: PartialOrd<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
         let expected = expected.borrow();
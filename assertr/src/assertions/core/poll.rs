@@ -5,7 +5,7 @@ use indoc::writedoc;
 
 use crate::AssertThat;
 use crate::actual::Actual;
-use crate::mode::{Mode, Panic};
+use crate::mode::Mode;
 use crate::tracking::AssertionTracking;
 
 /// Non-extracting assertions for `Poll` values.
@@ -27,30 +27,31 @@ impl<'t, T: Debug, M: Mode> PollAssertions<'t, T, M> for AssertThat<'t, Poll<T>,
         A: for<'a> FnOnce(AssertThat<'a, &'a T, M>),
     {
         self.track_assertion();
-        let actual = self.actual();
-        if actual.is_ready() {
-            self.satisfies_ref(
-                |it| match it {
-                    Poll::Ready(t) => t,
-                    Poll::Pending => unreachable!("already checked"),
-                },
-                assertions,
-            )
-        } else {
-            self.fail(|w: &mut String| {
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        self.satisfies_extracted(
+            |it| match it {
+                Poll::Ready(t) => Some(t),
+                Poll::Pending => None,
+            },
+            |actual, w| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not yet ready.
                 "}
-            });
-            self
-        }
+            },
+            assertions,
+        )
     }
 
     #[track_caller]
     fn is_pending(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_pending() {
             self.fail(|w: &mut String| {
@@ -66,26 +67,30 @@ impl<'t, T: Debug, M: Mode> PollAssertions<'t, T, M> for AssertThat<'t, Poll<T>,
 }
 
 /// Data-extracting assertion for `Poll` values.
-/// Only available in Panic mode, as the extracted `T` cannot be produced when the poll is pending.
+/// In `Capture` mode, the subject is poisoned when the poll is pending, skipping all subsequent
+/// assertions.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait PollExtractAssertions<'t, T> {
-    /// Use `PollAssertions::is_ready_satisfying` for capture mode.
-    fn is_ready(self) -> AssertThat<'t, T, Panic>;
+pub trait PollExtractAssertions<'t, T, M: Mode> {
+    fn is_ready(self) -> AssertThat<'t, T, M>;
 }
 
-impl<'t, T: Debug> PollExtractAssertions<'t, T> for AssertThat<'t, Poll<T>, Panic> {
+impl<'t, T: Debug, M: Mode> PollExtractAssertions<'t, T, M> for AssertThat<'t, Poll<T>, M> {
     #[track_caller]
-    fn is_ready(self) -> AssertThat<'t, T, Panic> {
+    fn is_ready(self) -> AssertThat<'t, T, M> {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
         let actual = self.actual();
         if !actual.is_ready() {
-            self.fail(|w: &mut String| {
+            self.fail_extraction(|w: &mut String| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not yet ready.
                 "}
             });
+            return self.poison();
         }
         self.map(|it| match it {
             Actual::Owned(p) => Actual::Owned(match p {
@@ -136,6 +141,26 @@ mod tests {
                 -------- assertr --------
             "#});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_not_ready() {
+            let failures = assert_that!(Poll::<Foo>::Pending)
+                .with_capture()
+                .with_location(false)
+                .is_ready()
+                .is_equal_to(Foo { val: 42 })
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Actual: Pending
+
+                is not yet ready.
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "}]);
+        }
     }
 
     mod is_ready_satisfying {
@@ -184,6 +209,8 @@ mod tests {
                 Actual: Pending
                 
                 is not yet ready.
+
+                Nested assertions were skipped, as this precondition failed.
                 -------- assertr --------
             "#}]);
         }
@@ -201,6 +228,8 @@ mod tests {
                 Actual: Pending
                 
                 is not yet ready.
+
+                Nested assertions were skipped, as this precondition failed.
                 -------- assertr --------
             "#});
         }
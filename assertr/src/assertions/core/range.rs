@@ -51,6 +51,9 @@ impl<B, R: RangeBounds<B>, M: Mode> RangeBoundAssertions<B, R> for AssertThat<'_
         B: PartialOrd + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return;
+        }
         if !self.actual().contains(&expected) {
             self.fail(|w: &mut String| {
                 writedoc! {w, r"
@@ -69,6 +72,9 @@ impl<B, R: RangeBounds<B>, M: Mode> RangeBoundAssertions<B, R> for AssertThat<'_
         B: PartialOrd + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return;
+        }
         if self.actual().contains(&expected) {
             self.fail(|w: &mut String| {
                 writedoc! {w, r"
@@ -87,6 +93,9 @@ impl<B, M: Mode> RangeAssertions<B> for AssertThat<'_, B, M> {
         B: PartialOrd + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
 
@@ -109,6 +118,9 @@ impl<B, M: Mode> RangeAssertions<B> for AssertThat<'_, B, M> {
         B: PartialOrd + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = self.actual();
 
@@ -127,7 +139,7 @@ impl<B, M: Mode> RangeAssertions<B> for AssertThat<'_, B, M> {
     }
 }
 
-fn render_range<B: Debug>(w: &mut impl Write, range: impl RangeBounds<B>) {
+pub(crate) fn render_range<B: Debug>(w: &mut impl Write, range: impl RangeBounds<B>) {
     fn write_bound<W: Write, B: Debug>(to: &mut W, bound: &B) {
         to.write_fmt(format_args!("{bound:?}")).unwrap();
     }
@@ -147,6 +159,49 @@ fn render_range<B: Debug>(w: &mut impl Write, range: impl RangeBounds<B>) {
     }
 }
 
+/// Fails if `range` cannot be passed to `BTreeMap::range` or `BTreeSet::range`, which panic for
+/// ranges starting after their end and for ranges excluding both of their equal bounds. Returns
+/// the range if it is valid.
+#[track_caller]
+pub(crate) fn check_btree_range<S, B, R, M>(assert: &AssertThat<'_, S, M>, range: R) -> Option<R>
+where
+    B: Ord + Debug,
+    R: RangeBounds<B>,
+    M: Mode,
+{
+    let reason = match (range.start_bound(), range.end_bound()) {
+        (Bound::Excluded(start), Bound::Excluded(end)) if start == end => {
+            "excludes both of its equal bounds"
+        }
+        (
+            Bound::Included(start) | Bound::Excluded(start),
+            Bound::Included(end) | Bound::Excluded(end),
+        ) if start > end => "starts after its end",
+        _ => return Some(range),
+    };
+    // Range syntax cannot express an excluded start bound.
+    let mut rendered_range = String::new();
+    match range.start_bound() {
+        Bound::Excluded(_) => {
+            write!(
+                rendered_range,
+                "{:?}",
+                (range.start_bound(), range.end_bound())
+            )
+            .unwrap();
+        }
+        _ => render_range(&mut rendered_range, range),
+    }
+    assert.fail(|w: &mut String| {
+        writedoc! {w, r"
+            Range: {rendered_range}
+
+            is not a valid range, as it {reason}
+        "}
+    });
+    None
+}
+
 #[cfg(test)]
 mod tests {
 
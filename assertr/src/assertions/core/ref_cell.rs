@@ -24,6 +24,9 @@ impl<T: Debug, M: Mode> RefCellAssertions for AssertThat<'_, RefCell<T>, M> {
     #[track_caller]
     fn is_borrowed(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if actual.try_borrow_mut().is_ok() {
             self.fail(|w: &mut String| {
@@ -40,6 +43,9 @@ impl<T: Debug, M: Mode> RefCellAssertions for AssertThat<'_, RefCell<T>, M> {
     #[track_caller]
     fn is_mutably_borrowed(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if actual.try_borrow().is_ok() {
             self.fail(|w: &mut String| {
@@ -56,6 +62,9 @@ impl<T: Debug, M: Mode> RefCellAssertions for AssertThat<'_, RefCell<T>, M> {
     #[track_caller]
     fn is_not_mutably_borrowed(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if actual.try_borrow().is_err() {
             self.fail(|w: &mut String| {
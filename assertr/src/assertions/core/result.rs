@@ -1,54 +1,56 @@
 use crate::{
-    AssertThat,
-    actual::Actual,
-    mode::{Mode, Panic},
+    AssertThat, AssertrPartialEq, EqContext, actual::Actual, mode::Mode,
     tracking::AssertionTracking,
 };
+use alloc::format;
 use alloc::string::String;
 use core::fmt::{Debug, Write};
 use indoc::writedoc;
 
 /// Data-extracting assertions for `Result` values.
-/// These change the assertion subject type and are only available in Panic mode,
-/// as they cannot produce a valid value of the extracted type when the assertion fails.
-/// Use `ResultAssertions::is_ok_satisfying` / `is_err_satisfying` for capture mode.
+/// These change the assertion subject type. In `Capture` mode, the subject is poisoned when the
+/// result is of the other variant, skipping all subsequent assertions.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait ResultExtractAssertions<'t, T, E> {
-    fn is_ok(self) -> AssertThat<'t, T, Panic>
+pub trait ResultExtractAssertions<'t, T, E, M: Mode> {
+    fn is_ok(self) -> AssertThat<'t, T, M>
     where
         T: Debug,
         E: Debug;
 
-    fn is_err(self) -> AssertThat<'t, E, Panic>
+    fn is_err(self) -> AssertThat<'t, E, M>
     where
         T: Debug,
         E: Debug;
 }
 
-impl<'t, T, E> ResultExtractAssertions<'t, T, E> for AssertThat<'t, Result<T, E>, Panic> {
+impl<'t, T, E, M: Mode> ResultExtractAssertions<'t, T, E, M> for AssertThat<'t, Result<T, E>, M> {
     /// This is a terminal operation on the contained `Result`,
     /// as there is little meaningful to do with the result if its variant was ensured.
     /// This allows you to chain additional expectations on the contained success value.
     #[track_caller]
-    fn is_ok(self) -> AssertThat<'t, T, Panic>
+    fn is_ok(self) -> AssertThat<'t, T, M>
     where
         T: Debug,
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
         if self.actual().is_err() {
             let actual = self.actual();
-            self.fail(|w: &mut String| {
+            self.fail_extraction(|w: &mut String| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Result:Ok
                 "}
             });
+            return self.poison();
         }
 
-        // Calling `unwrap` is safe here, as we would have seen a panic when the error is not present!
+        // Calling `unwrap` is safe here, as we returned early when the value is not present!
         self.map(|it| match it {
             Actual::Owned(o) => Actual::Owned(o.unwrap()),
             Actual::Borrowed(b) => Actual::Borrowed(b.as_ref().unwrap()),
@@ -59,25 +61,29 @@ impl<'t, T, E> ResultExtractAssertions<'t, T, E> for AssertThat<'t, Result<T, E>
     /// as there is little meaningful to do with the result if its variant was ensured.
     /// This allows you to chain additional expectations on the contained error value.
     #[track_caller]
-    fn is_err(self) -> AssertThat<'t, E, Panic>
+    fn is_err(self) -> AssertThat<'t, E, M>
     where
         T: Debug,
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
 
         if self.actual().is_ok() {
             let actual = self.actual();
-            self.fail(|w: &mut String| {
+            self.fail_extraction(|w: &mut String| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Result:Err
                 "}
             });
+            return self.poison();
         }
 
-        // Calling `unwrap_err` is safe here, as we would have seen a panic when the error is not present!
+        // Calling `unwrap_err` is safe here, as we returned early when the error is not present!
         self.map(|it| match it {
             Actual::Owned(o) => Actual::Owned(o.unwrap_err()),
             Actual::Borrowed(b) => Actual::Borrowed(b.as_ref().unwrap_err()),
@@ -101,6 +107,28 @@ pub trait ResultAssertions<'t, M: Mode, T, E> {
         T: Debug,
         E: Debug,
         A: for<'a> FnOnce(AssertThat<'a, &'a E, M>);
+
+    /// Test if this result is of the `Ok` variant, holding a value equal to `expected`.
+    fn is_ok_with<U>(self, expected: U) -> Self
+    where
+        T: AssertrPartialEq<U> + Debug,
+        E: Debug,
+        U: Debug;
+
+    /// Test if this result is of the `Err` variant, holding an error equal to `expected`.
+    fn is_err_with<U>(self, expected: U) -> Self
+    where
+        T: Debug,
+        E: AssertrPartialEq<U> + Debug,
+        U: Debug;
+
+    /// Test if this result is of the `Err` variant, holding an error for which `predicate`
+    /// returns true, e.g. `|e| matches!(e, MyError::NotFound { .. })`.
+    fn is_err_matching<P>(self, predicate: P) -> Self
+    where
+        T: Debug,
+        E: Debug,
+        P: FnOnce(&E) -> bool;
 }
 
 impl<'t, M: Mode, T, E> ResultAssertions<'t, M, T, E> for AssertThat<'t, Result<T, E>, M> {
@@ -112,20 +140,21 @@ impl<'t, M: Mode, T, E> ResultAssertions<'t, M, T, E> for AssertThat<'t, Result<
         A: for<'a> FnOnce(AssertThat<'a, &'a T, M>),
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
-        if self.actual().is_ok() {
-            self.satisfies_ref(|it| it.as_ref().unwrap(), assertions)
-        } else {
-            let actual = self.actual();
-            self.fail(|w: &mut String| {
+        self.satisfies_extracted(
+            |it| Result::as_ref(it).ok(),
+            |actual, w| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Result:Ok
                 "}
-            });
-            self
-        }
+            },
+            assertions,
+        )
     }
 
     #[track_caller]
@@ -136,20 +165,130 @@ impl<'t, M: Mode, T, E> ResultAssertions<'t, M, T, E> for AssertThat<'t, Result<
         A: for<'a> FnOnce(AssertThat<'a, &'a E, M>),
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
-        if self.actual().is_err() {
-            self.satisfies_ref(|it| it.as_ref().unwrap_err(), assertions)
-        } else {
-            let actual = self.actual();
-            self.fail(|w: &mut String| {
+        self.satisfies_extracted(
+            |it| Result::as_ref(it).err(),
+            |actual, w| {
                 writedoc! {w, r"
                     Actual: {actual:#?}
 
                     is not of expected variant: Result:Err
                 "}
+            },
+            assertions,
+        )
+    }
+
+    #[track_caller]
+    fn is_ok_with<U>(self, expected: U) -> Self
+    where
+        T: AssertrPartialEq<U> + Debug,
+        E: Debug,
+        U: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let actual = self.actual();
+        let expected = Ok::<U, &E>(expected);
+
+        let mut ctx = EqContext::default();
+
+        let eq = match (actual, &expected) {
+            (Ok(actual), Ok(expected)) => AssertrPartialEq::eq(actual, expected, Some(&mut ctx)),
+            _ => false,
+        };
+        if !eq {
+            if !ctx.differences.differences.is_empty() {
+                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_err_with<U>(self, expected: U) -> Self
+    where
+        T: Debug,
+        E: AssertrPartialEq<U> + Debug,
+        U: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let actual = self.actual();
+        let expected = Err::<&T, U>(expected);
+
+        let mut ctx = EqContext::default();
+
+        let eq = match (actual, &expected) {
+            (Err(actual), Err(expected)) => AssertrPartialEq::eq(actual, expected, Some(&mut ctx)),
+            _ => false,
+        };
+        if !eq {
+            if !ctx.differences.differences.is_empty() {
+                self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+            }
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
             });
-            self
         }
+        self
+    }
+
+    #[track_caller]
+    fn is_err_matching<P>(self, predicate: P) -> Self
+    where
+        T: Debug,
+        E: Debug,
+        P: FnOnce(&E) -> bool,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let actual = self.actual();
+        match actual {
+            Err(err) if predicate(err) => {}
+            Err(_) => {
+                self.fail(|w: &mut String| {
+                    writedoc! {w, r"
+                        Actual: {actual:#?}
+
+                        does not hold an error matching the given predicate
+                    "}
+                });
+            }
+            Ok(_) => {
+                self.fail(|w: &mut String| {
+                    writedoc! {w, r"
+                        Actual: {actual:#?}
+
+                        is not of expected variant: Result:Err
+                    "}
+                });
+            }
+        }
+        self
     }
 }
 
@@ -207,6 +346,44 @@ mod tests {
             "#});
     }
 
+    #[test]
+    fn is_ok_captures_failure_and_skips_subsequent_assertions_when_error() {
+        let failures = assert_that!(Result::<i32, String>::Err("someError".to_owned()))
+            .with_capture()
+            .with_location(false)
+            .is_ok()
+            .is_equal_to(42)
+            .capture_failures();
+        assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual: Err(
+                    "someError",
+                )
+
+                is not of expected variant: Result:Ok
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+    }
+
+    #[test]
+    fn is_err_captures_failures_of_subsequent_assertions_when_error() {
+        let failures = assert_that!(Result::<i32, String>::Err("someError".to_owned()))
+            .with_capture()
+            .with_location(false)
+            .is_err()
+            .is_equal_to("otherError")
+            .capture_failures();
+        assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected: "otherError"
+
+                  Actual: "someError"
+                -------- assertr --------
+            "#}]);
+    }
+
     #[test]
     fn is_ok_satisfying_succeeds_when_ok() {
         let failures = assert_that!(Result::<i32, ()>::Ok(42))
@@ -226,4 +403,115 @@ mod tests {
                 -------- assertr --------
             "}]);
     }
+
+    #[test]
+    fn is_ok_with_succeeds_when_ok_with_equal_value() {
+        assert_that!(Result::<i32, ()>::Ok(42)).is_ok_with(42);
+    }
+
+    #[test]
+    fn is_ok_with_panics_when_error() {
+        assert_that_panic_by(|| {
+            assert_that!(Result::<i32, String>::Err("someError".to_owned()))
+                .with_location(false)
+                .is_ok_with(42);
+        })
+        .has_type::<String>()
+        .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected: Ok(
+                    42,
+                )
+
+                  Actual: Err(
+                    "someError",
+                )
+                -------- assertr --------
+            "#});
+    }
+
+    #[test]
+    fn is_err_with_captures_failures_in_capture_mode() {
+        let failures = assert_that!(Result::<i32, String>::Err("someError".to_owned()))
+            .with_capture()
+            .with_location(false)
+            .is_err_with("someError")
+            .is_err_with("otherError")
+            .capture_failures();
+        assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected: Err(
+                    "otherError",
+                )
+
+                  Actual: Err(
+                    "someError",
+                )
+                -------- assertr --------
+            "#}]);
+    }
+
+    #[test]
+    fn is_err_matching_succeeds_when_predicate_holds() {
+        #[derive(Debug)]
+        enum MyError {
+            NotFound { id: u32 },
+        }
+
+        assert_that!(Result::<(), MyError>::Err(MyError::NotFound { id: 42 }))
+            .is_err_matching(|e| matches!(e, MyError::NotFound { id } if *id == 42));
+    }
+
+    #[test]
+    fn is_err_matching_captures_predicate_and_variant_failures() {
+        let failures = assert_that!(Result::<i32, String>::Err("someError".to_owned()))
+            .with_capture()
+            .with_location(false)
+            .is_err_matching(|e| e.is_empty())
+            .capture_failures();
+        let variant_failures = assert_that!(Result::<i32, String>::Ok(42))
+            .with_capture()
+            .with_location(false)
+            .is_err_matching(|_| panic!("predicate should not run"))
+            .capture_failures();
+
+        assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual: Err(
+                    "someError",
+                )
+
+                does not hold an error matching the given predicate
+                -------- assertr --------
+            "#}]);
+        assert_that!(variant_failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual: Ok(
+                    42,
+                )
+
+                is not of expected variant: Result:Err
+                -------- assertr --------
+            "#}]);
+    }
+
+    #[test]
+    fn is_err_satisfying_captures_variant_failure_without_running_assertions() {
+        let failures = assert_that!(Result::<i32, String>::Ok(42))
+            .with_capture()
+            .with_location(false)
+            .is_err_satisfying(|_| panic!("assertions should not run"))
+            .capture_failures();
+        assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Actual: Ok(
+                    42,
+                )
+
+                is not of expected variant: Result:Err
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "}]);
+    }
 }
@@ -0,0 +1,50 @@
+use core::num::Saturating;
+
+use crate::AssertThat;
+use crate::actual::Actual;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+
+/// Assertions for `Saturating` values.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait SaturatingAssertions<'t, T, M: Mode> {
+    /// Extracts the inner value, allowing it to be checked using all assertions available for `T`.
+    fn value(self) -> AssertThat<'t, T, M>;
+}
+
+impl<'t, T: Copy, M: Mode> SaturatingAssertions<'t, T, M> for AssertThat<'t, Saturating<T>, M> {
+    #[track_caller]
+    fn value(self) -> AssertThat<'t, T, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        self.map(|actual| Actual::Owned(actual.borrowed().0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod value {
+        use crate::prelude::*;
+        use core::num::Saturating;
+
+        #[test]
+        fn extracts_inner_value() {
+            assert_that!(Saturating(250u8) + Saturating(10))
+                .value()
+                .is_equal_to(u8::MAX);
+        }
+
+        #[test]
+        fn fails_when_inner_value_does_not_match() {
+            assert_that!(Saturating(0i8) - Saturating(1))
+                .with_capture()
+                .value()
+                .is_equal_to(0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+}
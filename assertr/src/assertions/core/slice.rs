@@ -5,7 +5,8 @@ use alloc::vec::Vec;
 use core::fmt::{Debug, Write};
 use indoc::writedoc;
 
-use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+use crate::approx::ApproxEq;
+use crate::{AssertThat, AssertrPartialEq, EqContext, Mode, tracking::AssertionTracking};
 
 #[allow(clippy::return_self_not_must_use)]
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
@@ -31,6 +32,15 @@ pub trait SliceAssertions<'t, T> {
         EE: AsRef<[E]>,
         T: AssertrPartialEq<E> + Debug;
 
+    /// Test that every element is approximately equal to the expected element at the same index,
+    /// allowing floating point values to deviate by `tolerance`. Lengths must be identical.
+    ///
+    /// Failures list every out-of-tolerance index together with its deviation.
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug;
+
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
         T: PartialEq + Debug;
@@ -50,6 +60,9 @@ impl<'t, T, M: Mode> SliceAssertions<'t, T> for AssertThat<'t, &[T], M> {
         T: Debug + AssertrPartialEq<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().iter().collect::<Vec<_>>();
         let expected = expected;
         if !self
@@ -75,6 +88,9 @@ impl<'t, T, M: Mode> SliceAssertions<'t, T> for AssertThat<'t, &[T], M> {
         T: Debug + AssertrPartialEq<E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().iter().collect::<Vec<_>>();
 
         if actual
@@ -100,6 +116,9 @@ impl<'t, T, M: Mode> SliceAssertions<'t, T> for AssertThat<'t, &[T], M> {
         T: AssertrPartialEq<E> + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
 
@@ -131,12 +150,47 @@ impl<'t, T, M: Mode> SliceAssertions<'t, T> for AssertThat<'t, &[T], M> {
         self
     }
 
+    #[track_caller]
+    fn is_close_to_elementwise<E>(self, expected: impl AsRef<[E]>, tolerance: f64) -> Self
+    where
+        E: Debug + 't,
+        T: ApproxEq<E> + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        let expected = expected.as_ref();
+
+        let mut ctx = EqContext::default();
+
+        if !actual.approx_eq(expected, tolerance, Some(&mut ctx)) {
+            self.add_detail_message(format!("Differences: {:#?}", ctx.differences));
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: {actual:#?}
+
+                    is not elementwise close to
+
+                    Expected: {expected:#?}
+
+                    Allowed deviation: {tolerance:?}
+                "}
+            });
+        }
+        self
+    }
+
     #[track_caller]
     fn contains_exactly_in_any_order<E: AsRef<[T]>>(self, expected: E) -> Self
     where
         T: PartialEq + Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual: &[T] = self.actual();
         let expected: &[T] = expected.as_ref();
 
@@ -183,6 +237,9 @@ impl<'t, T, M: Mode> SliceAssertions<'t, T> for AssertThat<'t, &[T], M> {
         P: Fn(&T) -> bool,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
 
@@ -330,6 +387,63 @@ mod tests {
         }
     }
 
+    mod is_close_to_elementwise {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_all_elements_are_within_tolerance() {
+            assert_that!([1.0, 2.0, 3.0].as_slice())
+                .is_close_to_elementwise([1.01, 1.99, 3.0], 0.02);
+        }
+
+        #[test]
+        fn panics_listing_every_deviating_index() {
+            assert_that_panic_by(|| {
+                assert_that!([0.1f32, 0.2, 0.3].as_slice())
+                    .with_location(false)
+                    .is_close_to_elementwise([0.0, 0.2, 0.5], 0.01)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: [
+                        0.1,
+                        0.2,
+                        0.3,
+                    ]
+
+                    is not elementwise close to
+
+                    Expected: [
+                        0.0,
+                        0.2,
+                        0.5,
+                    ]
+
+                    Allowed deviation: 0.01
+
+                    Details: [
+                        Differences: [
+                            [0]: expected 0.0, but was 0.1, deviating by 0.10000000149011612,
+                            [2]: expected 0.5, but was 0.3, deviating by 0.19999998807907104,
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_lengths_differ() {
+            assert_that!([1.0, 2.0].as_slice())
+                .with_capture()
+                .is_close_to_elementwise([1.0], 0.1)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
     mod contains_exactly_in_any_order {
         use crate::prelude::*;
         use indoc::formatdoc;
@@ -38,6 +38,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn is_blank(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         // This iterator will yield no entries if the string is empty or all whitespace!
         if self.actual().split_whitespace().next().is_some() {
             self.fail(|w: &mut String| {
@@ -56,6 +59,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn is_not_blank(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().split_whitespace().next().is_none() {
             self.fail(|w: &mut String| {
                 writedoc! {w, r"
@@ -73,6 +79,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn is_blank_ascii(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         // This iterator will yield no entries if the string is empty or all whitespace!
         if self.actual().split_ascii_whitespace().next().is_some() {
             self.fail(|w: &mut String| {
@@ -91,6 +100,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn is_equal_to_ignoring_ascii_case(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
         if !actual.eq_ignore_ascii_case(expected) {
@@ -111,6 +123,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn contains(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
         if !actual.contains(expected) {
@@ -130,6 +145,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn does_not_contain(self, unexpected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let unexpected = unexpected.as_ref();
         if self.actual().contains(unexpected) {
@@ -149,6 +167,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn starts_with(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
         if !actual.starts_with(expected) {
@@ -168,6 +189,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn does_not_start_with(self, unexpected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let unexpected = unexpected.as_ref();
         if self.actual().starts_with(unexpected) {
@@ -187,6 +211,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn ends_with(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let expected = expected.as_ref();
         if !actual.ends_with(expected) {
@@ -206,6 +233,9 @@ impl<M: Mode> StrSliceAssertions for AssertThat<'_, &str, M> {
     #[track_caller]
     fn does_not_end_with(self, unexpected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = *self.actual();
         let unexpected = unexpected.as_ref();
         if self.actual().ends_with(unexpected) {
@@ -0,0 +1,301 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Write};
+use core::future::Future;
+use futures::{Stream, StreamExt};
+use indoc::writedoc;
+
+use crate::actual::Actual;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+use crate::util::unwind::unwind_safe;
+use crate::{AssertThat, AssertrPartialEq};
+
+/// Assertions for `futures::Stream` values.
+/// These work in any mode (Panic or Capture).
+///
+/// Streams are advanced by these assertions, so they can only be called on owned subjects.
+/// Streams which are not `Unpin` must be pinned first, e.g. using `Box::pin(stream)`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait StreamAssertions<'t, S: Stream, M: Mode> {
+    /// Awaits the next item, testing that it equals `expected`.
+    #[cfg_attr(feature = "fluent", fluent_alias("yield_next"))]
+    fn yields_next<E>(self, expected: E) -> impl Future<Output = Self>
+    where
+        S::Item: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    /// Awaits all remaining items, testing that they equal `expected` in order.
+    #[cfg_attr(feature = "fluent", fluent_alias("yield_exactly"))]
+    fn yields_exactly<E, EE>(self, expected: EE) -> impl Future<Output = Self>
+    where
+        S::Item: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        EE: AsRef<[E]>;
+
+    /// Awaits the next item, testing that the stream has ended instead.
+    fn is_exhausted(self) -> impl Future<Output = Self>
+    where
+        S::Item: Debug;
+
+    /// Awaits all remaining items, changing the subject to them.
+    fn collect_into_vec(self) -> impl Future<Output = AssertThat<'t, Vec<S::Item>, M>>
+    where
+        S::Item: 't;
+}
+
+impl<'t, S: Stream + Unpin, M: Mode> StreamAssertions<'t, S, M> for AssertThat<'t, S, M> {
+    async fn yields_next<E>(mut self, expected: E) -> Self
+    where
+        S::Item: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = next(self.actual_mut(), "yields_next").await;
+        match actual {
+            Some(actual) if AssertrPartialEq::eq(&actual, &expected, None) => {}
+            Some(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next item: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            }),
+            None => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next item: {expected:#?}
+
+                      Actual: Stream ended
+                "}
+            }),
+        }
+        self
+    }
+
+    async fn yields_exactly<E, EE>(mut self, expected: EE) -> Self
+    where
+        S::Item: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        EE: AsRef<[E]>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual =
+            unwind_safe(owned_stream(self.actual_mut(), "yields_exactly").collect::<Vec<_>>())
+                .await;
+        let expected = expected.as_ref();
+        if !AssertrPartialEq::eq(actual.as_slice(), expected, None) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected stream to yield exactly: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    async fn is_exhausted(mut self) -> Self
+    where
+        S::Item: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let next = next(self.actual_mut(), "is_exhausted").await;
+        if let Some(next) = next {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Stream to be exhausted
+
+                      Actual: Stream yielded {next:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    async fn collect_into_vec(self) -> AssertThat<'t, Vec<S::Item>, M>
+    where
+        S::Item: 't,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        self.map_async(|it| match it {
+            Actual::Borrowed(_) => {
+                panic!("collect_into_vec() can only be called on an owned Stream!")
+            }
+            Actual::Owned(stream) => stream.collect::<Vec<_>>(),
+        })
+        .await
+    }
+}
+
+/// Awaits the next item of the owned stream.
+fn next<'a, S: Stream + Unpin>(
+    actual: &'a mut Actual<'_, S>,
+    method: &str,
+) -> impl Future<Output = Option<S::Item>> + 'a {
+    unwind_safe(owned_stream(actual, method).next())
+}
+
+fn owned_stream<'a, S>(actual: &'a mut Actual<'_, S>, method: &str) -> &'a mut S {
+    match actual {
+        Actual::Borrowed(_) => panic!("{method}() can only be called on an owned Stream!"),
+        Actual::Owned(stream) => stream,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod yields_next {
+        use crate::prelude::*;
+        use futures::stream;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_items_are_equal() {
+            assert_that!(stream::iter([1, 2]))
+                .yields_next(1)
+                .await
+                .yields_next(2)
+                .await
+                .is_exhausted()
+                .await;
+        }
+
+        #[tokio::test]
+        async fn panics_when_item_differs() {
+            assert_that_panic_by_async(|| async {
+                assert_that!(stream::iter([1, 2]))
+                    .with_location(false)
+                    .yields_next(2)
+                    .await;
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected next item: 2
+
+                  Actual: 1
+                -------- assertr --------
+            "});
+        }
+
+        #[tokio::test]
+        async fn captures_failure_when_stream_ended() {
+            let failures = assert_that!(stream::empty::<u32>())
+                .with_capture()
+                .with_location(false)
+                .yields_next(1)
+                .await
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected next item: 1
+
+                  Actual: Stream ended
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod yields_exactly {
+        use crate::prelude::*;
+        use futures::stream;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_remaining_items_are_equal() {
+            assert_that!(stream::iter(["a", "b", "c"]))
+                .yields_next("a")
+                .await
+                .yields_exactly(["b", "c"])
+                .await;
+        }
+
+        #[tokio::test]
+        async fn succeeds_for_pinned_streams() {
+            let numbers = stream::unfold(0, async |n| (n < 3).then_some((n, n + 1)));
+
+            assert_that!(Box::pin(numbers))
+                .yields_exactly([0, 1, 2])
+                .await;
+        }
+
+        #[tokio::test]
+        async fn panics_when_items_differ() {
+            assert_that_panic_by_async(|| async {
+                assert_that!(stream::iter([1, 2, 3]))
+                    .with_location(false)
+                    .yields_exactly([1, 2])
+                    .await;
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected stream to yield exactly: [
+                    1,
+                    2,
+                ]
+
+                  Actual: [
+                    1,
+                    2,
+                    3,
+                ]
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod is_exhausted {
+        use crate::prelude::*;
+        use futures::stream;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn panics_when_stream_yields_another_item() {
+            assert_that_panic_by_async(|| async {
+                assert_that!(stream::iter([1]))
+                    .with_location(false)
+                    .is_exhausted()
+                    .await;
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: Stream to be exhausted
+
+                  Actual: Stream yielded 1
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod collect_into_vec {
+        use crate::prelude::*;
+        use futures::stream;
+
+        #[tokio::test]
+        async fn changes_subject_to_remaining_items() {
+            assert_that!(stream::iter([1, 2, 3]))
+                .yields_next(1)
+                .await
+                .collect_into_vec()
+                .await
+                .contains_exactly([2, 3]);
+        }
+    }
+}
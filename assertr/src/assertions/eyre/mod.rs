@@ -0,0 +1,6 @@
+pub mod report;
+
+pub mod prelude {
+    pub use super::report::EyreReportAssertions;
+    pub use super::report::EyreReportExtractAssertions;
+}
@@ -0,0 +1,324 @@
+use alloc::string::{String, ToString};
+use core::any::type_name;
+use core::error::Error;
+use core::fmt::{Debug, Display, Write};
+use indoc::writedoc;
+
+use crate::assertions::core::error::{AsError, display_chain};
+use crate::{
+    AssertThat,
+    mode::{Mode, Panic},
+    tracking::AssertionTracking,
+};
+
+/// Marks `eyre::Report` subjects, which do not implement `Error`.
+pub struct Eyre;
+
+impl AsError<Eyre> for eyre::Report {
+    fn as_error(&self) -> &(dyn Error + 'static) {
+        &**self
+    }
+}
+
+/// Assertions for `eyre::Report`, complementing the `ErrorAssertions` also available for it.
+/// These work in any mode (Panic or Capture).
+///
+/// Context attached using `wrap_err()` or `wrap_err_with()` is part of the chain these
+/// assertions look at, with the outermost context coming first.
+///
+/// Unlike with anyhow, backtraces are captured by the installed `EyreHandler`. No backtrace
+/// assertions are offered, as eyre's `DefaultHandler` does not expose its backtrace: it is only
+/// printed as part of the report's `Debug` output, which is not a stable format to check against.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait EyreReportAssertions<'t, M: Mode> {
+    /// Test if any error in the chain, context or cause, has a `Display` representation equal
+    /// to `expected`.
+    fn has_context_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if any error in the chain, context or cause, is of type `E`.
+    ///
+    /// This includes the sources of the error the `eyre::Report` was created from.
+    #[cfg_attr(feature = "fluent", fluent_alias("contain_type_in_chain"))]
+    fn chain_contains_type<E>(self) -> Self
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Test if the `Display` representation of the root cause, the last error in the chain,
+    /// equals `expected`.
+    fn has_root_cause_message(self, expected: impl AsRef<str>) -> Self;
+
+    /// Test if the report can be downcast to `E`, then run additional assertions on the
+    /// downcast value.
+    #[cfg_attr(feature = "fluent", fluent_alias("downcast_to_satisfying"))]
+    fn downcasts_to_satisfying<E, A>(self, assertions: A) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static,
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>);
+}
+
+impl<'t, M: Mode> EyreReportAssertions<'t, M> for AssertThat<'t, eyre::Report, M> {
+    #[track_caller]
+    fn has_context_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let expected = expected.as_ref();
+        let chain = display_chain(self.actual().as_error());
+        if !chain.iter().any(|message| message == expected) {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected chain to contain message: {expected:?}
+
+                      Actual chain: {chain:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn chain_contains_type<E>(self) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let report = self.actual();
+        if report.downcast_ref::<E>().is_none() && !report.chain().any(<dyn Error>::is::<E>) {
+            let expected_type_name = type_name::<E>();
+            let chain = display_chain(report.as_error());
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected chain to contain type: {expected_type_name}
+
+                      Actual chain: {chain:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_root_cause_message(self, expected: impl AsRef<str>) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().root_cause().to_string();
+        let expected = expected.as_ref();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected root cause message: {expected:?}
+
+                      Actual root cause message: {actual:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn downcasts_to_satisfying<E, A>(self, assertions: A) -> Self
+    where
+        E: Display + Debug + Send + Sync + 'static,
+        A: for<'a> FnOnce(AssertThat<'a, &'a E, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        self.satisfies_extracted(
+            eyre::Report::downcast_ref::<E>,
+            |report, w| write_downcast_failure::<E>(report, w),
+            assertions,
+        )
+    }
+}
+
+fn write_downcast_failure<E>(report: &eyre::Report, w: &mut String) -> core::fmt::Result {
+    let expected_type_name = type_name::<E>();
+    let chain = display_chain(report.as_error());
+    writedoc! {w, r"
+        Expected report downcastable to: {expected_type_name}
+
+          Actual chain: {chain:#?}
+    "}
+}
+
+/// Data-extracting assertions for `eyre::Report`.
+/// Only available in Panic mode, as the downcast value cannot be produced when the types differ.
+/// Use `EyreReportAssertions::downcasts_to_satisfying` for capture mode.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait EyreReportExtractAssertions<'t> {
+    /// Test if the report can be downcast to `E` and change the subject to the downcast value.
+    ///
+    /// Just like `eyre::Report::downcast_ref`, this finds context attached to the report as
+    /// well as the error it was created from.
+    #[cfg_attr(feature = "fluent", fluent_alias("downcast_to"))]
+    fn downcasts_to<E>(&'t self) -> AssertThat<'t, &'t E, Panic>
+    where
+        E: Display + Debug + Send + Sync + 'static;
+}
+
+impl<'t> EyreReportExtractAssertions<'t> for AssertThat<'t, eyre::Report, Panic> {
+    #[track_caller]
+    fn downcasts_to<E>(&'t self) -> AssertThat<'t, &'t E, Panic>
+    where
+        E: Display + Debug + Send + Sync + 'static,
+    {
+        self.track_assertion();
+        if let Some(downcast) = self.actual().downcast_ref::<E>() {
+            self.derive(|_actual| downcast)
+        } else {
+            self.fail(|w: &mut String| write_downcast_failure::<E>(self.actual(), w));
+            unreachable!("Panic mode always panics on fail")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    fn request_report() -> eyre::Report {
+        eyre::Report::new(io::Error::new(
+            io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        ))
+        .wrap_err("could not load user")
+        .wrap_err("request failed")
+    }
+
+    mod error_assertions {
+        use super::request_report;
+        use crate::prelude::*;
+
+        #[test]
+        fn are_available_for_eyre_reports() {
+            assert_that!(request_report())
+                .has_message("request failed")
+                .source_chain()
+                .contains_exactly([
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]);
+        }
+    }
+
+    mod has_context_message {
+        use super::request_report;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_any_context_has_message() {
+            assert_that!(request_report()).has_context_message("could not load user");
+        }
+
+        #[test]
+        fn panics_listing_the_chain() {
+            assert_that_panic_by(|| {
+                assert_that!(request_report())
+                    .with_location(false)
+                    .has_context_message("could not save user")
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected chain to contain message: "could not save user"
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod chain_contains_type {
+        use super::request_report;
+        use crate::prelude::*;
+        use std::io;
+
+        #[test]
+        fn succeeds_when_report_was_created_from_type() {
+            assert_that!(request_report()).chain_contains_type::<io::Error>();
+        }
+
+        #[test]
+        fn captures_failure_when_no_error_in_chain_has_type() {
+            let failures = assert_that!(request_report())
+                .with_capture()
+                .chain_contains_type::<core::fmt::Error>()
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
+    }
+
+    mod has_root_cause_message {
+        use super::request_report;
+        use crate::prelude::*;
+
+        #[test]
+        fn succeeds_when_equal() {
+            assert_that!(request_report()).has_root_cause_message("connection refused");
+        }
+    }
+
+    mod downcasts_to {
+        use super::request_report;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::io;
+
+        #[test]
+        fn extracts_the_error_the_report_was_created_from() {
+            assert_that!(request_report())
+                .downcasts_to::<io::Error>()
+                .has_display_value("connection refused");
+        }
+
+        #[test]
+        fn panics_when_type_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(request_report())
+                    .with_location(false)
+                    .downcasts_to::<core::fmt::Error>();
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                -------- assertr --------
+                Expected report downcastable to: core::fmt::Error
+
+                  Actual chain: [
+                    "request failed",
+                    "could not load user",
+                    "connection refused",
+                ]
+                -------- assertr --------
+            "#});
+        }
+    }
+
+    mod downcasts_to_satisfying {
+        use super::request_report;
+        use crate::prelude::*;
+        use std::io;
+
+        #[test]
+        fn succeeds_when_downcast_value_satisfies_assertions() {
+            assert_that!(request_report()).downcasts_to_satisfying::<io::Error, _>(|error| {
+                error.has_display_value("connection refused");
+            });
+        }
+    }
+}
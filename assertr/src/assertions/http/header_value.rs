@@ -1,5 +1,5 @@
 use crate::AssertThat;
-use crate::mode::{Mode, Panic};
+use crate::mode::Mode;
 use crate::prelude::{BoolAssertions, PartialEqAssertions, PartialOrdAssertions};
 use alloc::borrow::ToOwned;
 use alloc::string::String;
@@ -61,32 +61,37 @@ impl<'t, M: Mode> HttpHeaderValueAssertions<'t, M>
         use crate::tracking::AssertionTracking;
 
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
-        if self.actual().to_str().is_ok() {
-            self.satisfies_ref(|hv| hv.to_str().expect("already checked"), assertions)
-        } else {
-            let actual = self.actual();
-            self.fail(|w: &mut String| {
+        self.satisfies_extracted(
+            |hv| hv.to_str().ok(),
+            |actual, w| {
                 writedoc! {w, r"
                     Actual: {actual:?}
 
                     is not valid ASCII
                 "}
-            });
-            self
-        }
+            },
+            assertions,
+        )
     }
 }
 
 /// Data-extracting assertion for `HeaderValue`.
-/// Only available in Panic mode, as the extracted `String` cannot be produced when the value is not ASCII.
+/// In `Capture` mode, the subject is poisoned when the value is not ASCII, skipping all subsequent
+/// assertions.
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait HttpHeaderValueExtractAssertions<'t> {
-    fn is_ascii(self) -> AssertThat<'t, String, Panic>;
+pub trait HttpHeaderValueExtractAssertions<'t, M: Mode> {
+    fn is_ascii(self) -> AssertThat<'t, String, M>;
 }
 
-impl<'t> HttpHeaderValueExtractAssertions<'t> for AssertThat<'t, http::header::HeaderValue, Panic> {
-    fn is_ascii(self) -> AssertThat<'t, String, Panic> {
+impl<'t, M: Mode> HttpHeaderValueExtractAssertions<'t, M>
+    for AssertThat<'t, http::header::HeaderValue, M>
+{
+    #[track_caller]
+    fn is_ascii(self) -> AssertThat<'t, String, M> {
         use crate::prelude::ResultExtractAssertions;
 
         self.map(|it| it.borrowed().to_str().map(ToOwned::to_owned).into())
@@ -305,6 +310,32 @@ mod tests {
                     -------- assertr --------
                 "#});
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_not_ascii() {
+            let actual = HeaderValue::from_str("Ä").expect("valid header value");
+
+            let failures = assert_that!(actual)
+                .with_capture()
+                .with_location(false)
+                .is_ascii()
+                .is_equal_to("Ä")
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Actual: Err(
+                    ToStrError {{
+                        _priv: (),
+                    }},
+                )
+
+                is not of expected variant: Result:Ok
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
     }
 
     mod is_ascii_satisfying {
@@ -370,6 +401,8 @@ mod tests {
                 Actual: " !\xff"
 
                 is not valid ASCII
+
+                Nested assertions were skipped, as this precondition failed.
                 -------- assertr --------
             "#});
         }
@@ -21,6 +21,9 @@ impl<M: Mode> SignedDurationAssertions for AssertThat<'_, SignedDuration, M> {
     #[track_caller]
     fn is_zero(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_zero() {
             self.add_detail_message("Actual was not zero.");
@@ -41,6 +44,9 @@ impl<M: Mode> SignedDurationAssertions for AssertThat<'_, SignedDuration, M> {
     #[track_caller]
     fn is_negative(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_negative() {
             self.fail(|w: &mut String| {
@@ -58,6 +64,9 @@ impl<M: Mode> SignedDurationAssertions for AssertThat<'_, SignedDuration, M> {
     #[track_caller]
     fn is_positive(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_positive() {
             self.fail(|w: &mut String| {
@@ -75,6 +84,9 @@ impl<M: Mode> SignedDurationAssertions for AssertThat<'_, SignedDuration, M> {
     #[track_caller]
     fn is_close_to(self, expected: SignedDuration, allowed_deviation: SignedDuration) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let actual = *self.actual();
         let min = expected - allowed_deviation;
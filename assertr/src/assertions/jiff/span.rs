@@ -20,6 +20,9 @@ impl<M: Mode> SpanAssertions for AssertThat<'_, Span, M> {
     #[track_caller]
     fn is_zero(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_zero() {
             self.add_detail_message("Actual was not zero.");
@@ -40,6 +43,9 @@ impl<M: Mode> SpanAssertions for AssertThat<'_, Span, M> {
     #[track_caller]
     fn is_negative(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_negative() {
             self.fail(|w: &mut String| {
@@ -57,6 +63,9 @@ impl<M: Mode> SpanAssertions for AssertThat<'_, Span, M> {
     #[track_caller]
     fn is_positive(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self.actual().is_positive() {
             self.fail(|w: &mut String| {
@@ -20,6 +20,9 @@ impl<M: Mode> ZonedAssertions for AssertThat<'_, Zoned, M> {
     #[track_caller]
     fn is_in_time_zone(self, expected: impl Borrow<TimeZone>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let zdt = self.actual();
 
@@ -51,6 +54,9 @@ impl<M: Mode> ZonedAssertions for AssertThat<'_, Zoned, M> {
     #[track_caller]
     fn is_in_time_zone_named(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected = expected.as_ref();
         let actual = self.actual().time_zone().iana_name();
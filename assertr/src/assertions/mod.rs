@@ -1,4 +1,8 @@
-use ::alloc::{collections::VecDeque, string::String, vec::Vec};
+use ::alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    string::String,
+    vec::Vec,
+};
 #[cfg(feature = "std")]
 use ::core::fmt::Debug;
 use ::core::ops::{Range, RangeInclusive};
@@ -6,8 +10,12 @@ use ::core::ops::{Range, RangeInclusive};
 use ::std::hash::BuildHasher;
 
 pub mod alloc;
+#[cfg(feature = "anyhow")]
+pub mod anyhow;
 pub mod condition;
 pub mod core;
+#[cfg(feature = "eyre")]
+pub mod eyre;
 #[cfg(feature = "http")]
 pub mod http;
 #[cfg(feature = "jiff")]
@@ -121,6 +129,46 @@ impl<T> HasLength for &VecDeque<T> {
     }
 }
 
+impl<K, V> HasLength for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+impl<K, V> HasLength for &BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeMap::is_empty(self)
+    }
+}
+
+impl<T> HasLength for BTreeSet<T> {
+    fn length(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
+
+impl<T> HasLength for &BTreeSet<T> {
+    fn length(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
+
 #[cfg(feature = "std")]
 impl<K: Debug, V: Debug, S: BuildHasher> HasLength for ::std::collections::HashMap<K, V, S> {
     fn length(&self) -> usize {
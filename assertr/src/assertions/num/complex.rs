@@ -0,0 +1,274 @@
+use crate::AssertThat;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num::Float;
+use num::Num;
+use num::complex::Complex;
+
+/// Assertions for complex numbers.
+///
+/// Complex numbers are not ordered, so `NumAssertions::is_close_to` is not available for them.
+/// Use `is_close_to_by_magnitude` instead, which measures the distance between two complex
+/// numbers as the magnitude of their difference.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait ComplexAssertions<T> {
+    fn has_real_part(self, expected: T) -> Self;
+
+    fn has_imaginary_part(self, expected: T) -> Self;
+
+    /// Fails if `|actual - expected| > allowed_distance`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_by_magnitude(self, expected: Complex<T>, allowed_distance: T) -> Self
+    where
+        T: Float;
+
+    /// Fails if actual is further than `allowed_distance` away from the complex number with
+    /// magnitude `r` and phase `theta` (in radians).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_polar(self, r: T, theta: T, allowed_distance: T) -> Self
+    where
+        T: Float;
+}
+
+impl<T, M: Mode> ComplexAssertions<T> for AssertThat<'_, Complex<T>, M>
+where
+    T: Clone + Num + Debug,
+{
+    #[track_caller]
+    fn has_real_part(self, expected: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if actual.re != expected {
+            let re = &actual.re;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected real part: {expected:#?}
+
+                      Actual real part: {re:#?} (of {actual:?})
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_imaginary_part(self, expected: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if actual.im != expected {
+            let im = &actual.im;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected imaginary part: {expected:#?}
+
+                      Actual imaginary part: {im:#?} (of {actual:?})
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_by_magnitude(self, expected: Complex<T>, allowed_distance: T) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        let distance = (actual - expected).norm();
+        if distance.is_nan() || distance > allowed_distance {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be close to: {expected:?},
+                      with allowed distance being: {allowed_distance:?},
+                             but distance was: {distance:?}
+
+                      Actual: {actual:?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_polar(self, r: T, theta: T, allowed_distance: T) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        let expected = Complex::from_polar(r, theta);
+        let distance = (actual - expected).norm();
+        if distance.is_nan() || distance > allowed_distance {
+            let (actual_r, actual_theta) = actual.to_polar();
+            self.add_detail_message(format!(
+                "Actual in polar form: r = {actual_r:?}, theta = {actual_theta:?}"
+            ));
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be close to: r = {r:?}, theta = {theta:?} ({expected:?}),
+                      with allowed distance being: {allowed_distance:?},
+                             but distance was: {distance:?}
+
+                      Actual: {actual:?}
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_real_part {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::complex::Complex;
+
+        #[test]
+        fn succeeds_when_real_part_matches() {
+            assert_that!(Complex::new(1, 2)).has_real_part(1);
+        }
+
+        #[test]
+        fn panics_when_real_part_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(Complex::new(1, 2))
+                    .with_location(false)
+                    .has_real_part(2)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected real part: 2
+
+                      Actual real part: 1 (of Complex {{ re: 1, im: 2 }})
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_imaginary_part {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::complex::Complex;
+
+        #[test]
+        fn succeeds_when_imaginary_part_matches() {
+            assert_that!(Complex::new(1.0, -2.5)).has_imaginary_part(-2.5);
+        }
+
+        #[test]
+        fn panics_when_imaginary_part_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(Complex::new(1, 2))
+                    .with_location(false)
+                    .has_imaginary_part(1)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected imaginary part: 1
+
+                      Actual imaginary part: 2 (of Complex {{ re: 1, im: 2 }})
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_close_to_by_magnitude {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::complex::Complex;
+
+        #[test]
+        fn succeeds_when_within_distance() {
+            assert_that!(Complex::new(1.0, 1.0))
+                .is_close_to_by_magnitude(Complex::new(1.03, 0.96), 0.06);
+        }
+
+        #[test]
+        fn panics_when_too_far_away() {
+            assert_that_panic_by(|| {
+                assert_that!(Complex::new(0.0, 0.0))
+                    .with_location(false)
+                    .is_close_to_by_magnitude(Complex::new(3.0, 4.0), 4.9)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be close to: Complex {{ re: 3.0, im: 4.0 }},
+                      with allowed distance being: 4.9,
+                             but distance was: 5.0
+
+                      Actual: Complex {{ re: 0.0, im: 0.0 }}
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_nan_is_involved() {
+            assert_that!(Complex::new(f64::NAN, 0.0))
+                .with_capture()
+                .is_close_to_by_magnitude(Complex::new(0.0, 0.0), f64::INFINITY)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_close_to_polar {
+        use crate::prelude::*;
+        use core::f64::consts::FRAC_PI_2;
+        use indoc::formatdoc;
+        use num::complex::Complex;
+
+        #[test]
+        fn succeeds_when_within_distance() {
+            assert_that!(Complex::new(0.0, 2.0)).is_close_to_polar(2.0, FRAC_PI_2, 1e-9);
+        }
+
+        #[test]
+        fn panics_when_too_far_away() {
+            assert_that_panic_by(|| {
+                assert_that!(Complex::new(-1.0, 0.0))
+                    .with_location(false)
+                    .is_close_to_polar(1.0, 0.0, 0.5)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be close to: r = 1.0, theta = 0.0 (Complex {{ re: 1.0, im: 0.0 }}),
+                      with allowed distance being: 0.5,
+                             but distance was: 2.0
+
+                      Actual: Complex {{ re: -1.0, im: 0.0 }}
+
+                    Details: [
+                        Actual in polar form: r = 1.0, theta = 3.141592653589793,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
@@ -0,0 +1,532 @@
+use crate::AssertThat;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+use alloc::format;
+use alloc::string::String;
+use core::any::type_name;
+use core::fmt::{Binary, Debug, Display, LowerHex, Write};
+use indoc::writedoc;
+use num::traits::CheckedRem;
+use num::{Integer, NumCast, PrimInt};
+
+/// Assertions for primitive integer values, going beyond the generic
+/// [`crate::prelude::NumAssertions`].
+///
+/// Failures show the actual value in decimal, hexadecimal and binary.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait IntegerAssertions<T> {
+    fn is_even(self) -> Self;
+
+    fn is_odd(self) -> Self;
+
+    /// Fails if actual is not a multiple of `divisor`.
+    /// Only zero is considered to be divisible by zero.
+    fn is_divisible_by(self, divisor: T) -> Self;
+
+    /// Fails if actual is not a positive power of two.
+    fn is_power_of_two(self) -> Self;
+
+    /// Fails if the bit at index `bit` (counting from the least significant bit, starting at 0)
+    /// is not set, or if `bit` is out of range for the integer type.
+    fn has_bit_set(self, bit: u32) -> Self;
+
+    /// Fails if any bit set in `mask` is also set in actual.
+    fn has_bits_cleared(self, mask: T) -> Self;
+
+    /// Fails if actual does not have exactly `expected` bits set.
+    fn has_count_ones(self, expected: u32) -> Self;
+
+    /// Fails if actual cannot be converted to `U` without loss, i.e. if it lies outside
+    /// `U::MIN..=U::MAX`.
+    fn is_in_bounds_of<U>(self) -> Self
+    where
+        U: PrimInt + Debug;
+}
+
+/// Renders `value` in decimal, hexadecimal and binary, e.g. `42 (0x2a, 0b101010)`.
+fn radix_repr<T: Display + LowerHex + Binary>(value: &T) -> String {
+    format!("{value} ({value:#x}, {value:#b})")
+}
+
+impl<T, M: Mode> IntegerAssertions<T> for AssertThat<'_, T, M>
+where
+    T: PrimInt + Integer + CheckedRem + Debug + Display + LowerHex + Binary,
+{
+    #[track_caller]
+    fn is_even(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !actual.is_even() {
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be even. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_odd(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !actual.is_odd() {
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be odd. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_divisible_by(self, divisor: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        // `checked_rem` only fails when dividing by zero, or when dividing `MIN` by `-1`, which
+        // overflows, although every integer is divisible by `-1`.
+        let divisible = match actual.checked_rem(&divisor) {
+            Some(remainder) => remainder.is_zero(),
+            None => !divisor.is_zero() || actual.is_zero(),
+        };
+        if !divisible {
+            if !divisor.is_zero() {
+                self.add_detail_message(format!("Remainder: {}", actual.mod_floor(&divisor)));
+            }
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be divisible by: {divisor}. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_power_of_two(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !(*actual > T::zero() && actual.count_ones() == 1) {
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be a power of two. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_bit_set(self, bit: u32) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let bits = T::zero().count_zeros();
+        if bit >= bits {
+            self.add_detail_message(format!(
+                "Bit {bit} is out of range for '{}', which has {bits} bits",
+                type_name::<T>()
+            ));
+        }
+        if bit >= bits || (*actual & (T::one() << bit as usize)).is_zero() {
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected bit {bit} to be set. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_bits_cleared(self, mask: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let set_bits = *actual & mask;
+        if !set_bits.is_zero() {
+            self.add_detail_message(format!("Set bits: {set_bits:#b}"));
+            let mask = radix_repr(&mask);
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected bits of mask: {mask} to be cleared. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_count_ones(self, expected: u32) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let count_ones = actual.count_ones();
+        if count_ones != expected {
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to have {expected} bits set, but it had {count_ones}
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_in_bounds_of<U>(self) -> Self
+    where
+        U: PrimInt + Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if <U as NumCast>::from(*actual).is_none() {
+            let target = type_name::<U>();
+            let (min, max) = (U::min_value(), U::max_value());
+            let actual = radix_repr(actual);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be losslessly convertible to '{target}', ranging from {min:?} to {max:?}. But was
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn quick_type_check() {
+        use crate::prelude::*;
+
+        assert_that!(2u8).is_even();
+        assert_that!(-2i8).is_even();
+        assert_that!(3u16).is_odd();
+        assert_that!(-3i16).is_odd();
+        assert_that!(12u32).is_divisible_by(4);
+        assert_that!(-12i32).is_divisible_by(4);
+        assert_that!(64u64).is_power_of_two();
+        assert_that!(64i64).is_power_of_two();
+        assert_that!(1u128).has_bit_set(0);
+        assert_that!(i128::MIN).has_bit_set(127);
+        assert_that!(0b1010usize).has_bits_cleared(0b0101);
+        assert_that!(-1isize).has_count_ones(isize::BITS);
+    }
+
+    mod is_even {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_even() {
+            assert_that!(0).is_even();
+            assert_that!(42).is_even();
+        }
+
+        #[test]
+        fn panics_when_odd() {
+            assert_that_panic_by(|| assert_that!(43).with_location(false).is_even())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be even. But was
+
+                      Actual: 43 (0x2b, 0b101011)
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_odd {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_odd() {
+            assert_that!(-1).is_odd();
+            assert_that!(43).is_odd();
+        }
+
+        #[test]
+        fn panics_when_even() {
+            assert_that_panic_by(|| assert_that!(42u8).with_location(false).is_odd())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be odd. But was
+
+                      Actual: 42 (0x2a, 0b101010)
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_divisible_by {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_divisible() {
+            assert_that!(15).is_divisible_by(5);
+            assert_that!(0).is_divisible_by(0);
+        }
+
+        #[test]
+        fn succeeds_when_dividing_min_by_minus_one() {
+            assert_that!(i32::MIN).is_divisible_by(-1);
+            assert_that!(i8::MIN).is_divisible_by(-1);
+        }
+
+        #[test]
+        fn panics_with_remainder_when_not_divisible() {
+            assert_that_panic_by(|| assert_that!(17).with_location(false).is_divisible_by(5))
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be divisible by: 5. But was
+
+                      Actual: 17 (0x11, 0b10001)
+
+                    Details: [
+                        Remainder: 2,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_dividing_non_zero_value_by_zero() {
+            assert_that!(1)
+                .with_capture()
+                .is_divisible_by(0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_power_of_two {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_power_of_two() {
+            assert_that!(1).is_power_of_two();
+            assert_that!(1024).is_power_of_two();
+        }
+
+        #[test]
+        fn panics_when_not_power_of_two() {
+            assert_that_panic_by(|| assert_that!(12).with_location(false).is_power_of_two())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be a power of two. But was
+
+                      Actual: 12 (0xc, 0b1100)
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_for_zero_and_negative_values() {
+            assert_that!(0)
+                .with_capture()
+                .is_power_of_two()
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(i8::MIN)
+                .with_capture()
+                .is_power_of_two()
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod has_bit_set {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_bit_is_set() {
+            assert_that!(0b100u8).has_bit_set(2);
+            assert_that!(0x80u8).has_bit_set(7);
+        }
+
+        #[test]
+        fn panics_when_bit_is_not_set() {
+            assert_that_panic_by(|| assert_that!(0b100u8).with_location(false).has_bit_set(1))
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected bit 1 to be set. But was
+
+                      Actual: 4 (0x4, 0b100)
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn panics_when_bit_is_out_of_range() {
+            assert_that_panic_by(|| assert_that!(0xffu8).with_location(false).has_bit_set(8))
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected bit 8 to be set. But was
+
+                      Actual: 255 (0xff, 0b11111111)
+
+                    Details: [
+                        Bit 8 is out of range for 'u8', which has 8 bits,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_bits_cleared {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_masked_bits_are_cleared() {
+            assert_that!(0b1010_0000u8).has_bits_cleared(0b0000_1111);
+        }
+
+        #[test]
+        fn panics_when_masked_bits_are_set() {
+            assert_that_panic_by(|| {
+                assert_that!(0b1010_0110u8)
+                    .with_location(false)
+                    .has_bits_cleared(0b0000_1111)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected bits of mask: 15 (0xf, 0b1111) to be cleared. But was
+
+                      Actual: 166 (0xa6, 0b10100110)
+
+                    Details: [
+                        Set bits: 0b110,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_count_ones {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_count_matches() {
+            assert_that!(0b1011u8).has_count_ones(3);
+            assert_that!(0u8).has_count_ones(0);
+        }
+
+        #[test]
+        fn panics_when_count_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(0b1011u8)
+                    .with_location(false)
+                    .has_count_ones(2)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to have 2 bits set, but it had 3
+
+                      Actual: 11 (0xb, 0b1011)
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_in_bounds_of {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_losslessly_convertible() {
+            assert_that!(255u32).is_in_bounds_of::<u8>();
+            assert_that!(-128i64).is_in_bounds_of::<i8>();
+            assert_that!(u8::MAX).is_in_bounds_of::<i16>();
+        }
+
+        #[test]
+        fn panics_when_out_of_bounds() {
+            assert_that_panic_by(|| {
+                assert_that!(300u32)
+                    .with_location(false)
+                    .is_in_bounds_of::<u8>()
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be losslessly convertible to 'u8', ranging from 0 to 255. But was
+
+                      Actual: 300 (0x12c, 0b100101100)
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_for_negative_values_and_unsigned_targets() {
+            assert_that!(-1i32)
+                .with_capture()
+                .is_in_bounds_of::<u64>()
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+}
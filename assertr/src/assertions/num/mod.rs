@@ -1,11 +1,19 @@
 use crate::AssertThat;
 use crate::mode::Mode;
 use crate::tracking::AssertionTracking;
+use alloc::format;
+use alloc::string::String;
 use core::fmt::Debug;
 use core::fmt::Write;
+use core::num::FpCategory;
 use indoc::writedoc;
 use num::{Float, Num, Signed};
 
+pub mod complex;
+pub mod integer;
+pub mod rational;
+pub mod statistics;
+
 /// Assertions for numeric values not already handled by
 /// [`crate::prelude::PartialEqAssertions`] and [`crate::prelude::PartialOrdAssertions`].
 #[allow(clippy::return_self_not_must_use)]
@@ -51,14 +59,138 @@ pub trait NumAssertions<T: Num> {
     where
         T: Float;
 
-    // TODO: is_normal
-    // TODO: is_subnormal
+    /// Fails if actual is zero, infinite, subnormal or NaN.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_normal(self) -> Self
+    where
+        T: Float;
+
+    /// Fails if actual is not subnormal. Zero, infinity and NaN are not subnormal.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_subnormal(self) -> Self
+    where
+        T: Float;
+
+    /// Fails if the sign bit of actual is set.
+    ///
+    /// Unlike `is_positive`, this distinguishes `0.0` from `-0.0`.
+    /// NaN values carry a sign bit as well, which is checked as is.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_sign_positive(self) -> Self
+    where
+        T: Float;
+
+    /// Fails if the sign bit of actual is not set.
+    ///
+    /// Unlike `is_negative`, this distinguishes `-0.0` from `0.0`.
+    /// NaN values carry a sign bit as well, which is checked as is.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_sign_negative(self) -> Self
+    where
+        T: Float;
+
+    /// Fails if actual is not in the range
+    /// `[expected - relative_tolerance * |expected|, expected + relative_tolerance * |expected|]`.
+    ///
+    /// NaN is never close to anything. Infinities are only close to themselves.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_relative(self, expected: T, relative_tolerance: T) -> Self
+    where
+        T: Float + Ulps;
+
+    /// Fails if `|actual - expected| > absolute_tolerance + relative_tolerance * |expected|`,
+    /// the same check performed by `numpy.isclose`.
+    ///
+    /// NaN is never close to anything. Infinities are only close to themselves.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_with_tolerances(
+        self,
+        expected: T,
+        absolute_tolerance: T,
+        relative_tolerance: T,
+    ) -> Self
+    where
+        T: Float + Ulps;
+
+    /// Fails if more than `max_ulps` representable values lie between actual and expected.
+    ///
+    /// `0.0` and `-0.0` are zero ULPs apart. NaN is never within any distance.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_within_ulps(self, expected: T, max_ulps: u64) -> Self
+    where
+        T: Float + Ulps;
+}
+
+/// Floating point types whose distance can be measured in units in the last place (ULPs).
+pub trait Ulps: Copy {
+    /// Number of representable values between `self` and `other`,
+    /// or `None` if either of them is NaN.
+    fn ulps_between(self, other: Self) -> Option<u64>;
+}
+
+impl Ulps for f32 {
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        fn ordered(value: f32) -> i32 {
+            #[allow(clippy::cast_possible_wrap)]
+            let bits = value.to_bits() as i32;
+            if bits < 0 { i32::MIN - bits } else { bits }
+        }
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        Some(u64::from(ordered(self).abs_diff(ordered(other))))
+    }
+}
+
+impl Ulps for f64 {
+    fn ulps_between(self, other: Self) -> Option<u64> {
+        fn ordered(value: f64) -> i64 {
+            #[allow(clippy::cast_possible_wrap)]
+            let bits = value.to_bits() as i64;
+            if bits < 0 { i64::MIN - bits } else { bits }
+        }
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        Some(ordered(self).abs_diff(ordered(other)))
+    }
+}
+
+/// Whether actual deviates from expected by at most `allowed_deviation`.
+///
+/// Infinities are only close to themselves, as any tolerance relative to them is infinite.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn is_within_deviation<T: Float>(actual: T, expected: T, allowed_deviation: T) -> bool {
+    if actual.is_infinite() || expected.is_infinite() {
+        return actual == expected;
+    }
+    (actual - expected).abs() <= allowed_deviation
+}
+
+/// Adds the absolute difference, relative error and ULP distance of actual to expected
+/// as details to a failing float comparison.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn add_float_distance_details<T: Float + Ulps + Debug, M: Mode>(
+    assert: &AssertThat<'_, T, M>,
+    actual: T,
+    expected: T,
+) {
+    let difference = (actual - expected).abs();
+    assert.add_detail_message(format!("Difference: {difference:?}"));
+    assert.add_detail_message(format!("Relative error: {:?}", difference / expected.abs()));
+    assert.add_detail_message(match actual.ulps_between(expected) {
+        Some(ulps) => format!("ULP distance: {ulps}"),
+        None => String::from("ULP distance: undefined, as NaN is involved"),
+    });
 }
 
 impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
     #[track_caller]
     fn is_zero(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_zero() {
             self.add_detail_message(format!(
@@ -85,6 +217,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
     #[track_caller]
     fn is_one(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_one() {
             self.add_detail_message(format!(
@@ -114,6 +249,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Signed,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_negative() {
             self.fail(|w: &mut String| {
@@ -133,6 +271,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Signed,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_positive() {
             self.fail(|w: &mut String| {
@@ -153,6 +294,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Clone,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let min = expected.clone() - allowed_deviation.clone();
         let max = expected.clone() + allowed_deviation.clone();
@@ -177,6 +321,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Float,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_nan() {
             let nan = T::nan();
@@ -198,6 +345,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Float,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_finite() {
             self.fail(|w: &mut String| {
@@ -218,6 +368,9 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         T: Float,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_infinite() {
             let inf = T::infinity();
@@ -231,6 +384,191 @@ impl<T: Num + Debug, M: Mode> NumAssertions<T> for AssertThat<'_, T, M> {
         }
         self
     }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_normal(self) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !actual.is_normal() {
+            self.add_detail_message(format!("Category: {:?}", actual.classify()));
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected a normal value, but was
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_subnormal(self) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if actual.classify() != FpCategory::Subnormal {
+            self.add_detail_message(format!("Category: {:?}", actual.classify()));
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected a subnormal value, but was
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_sign_positive(self) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !actual.is_sign_positive() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to have a positive sign. But was
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_sign_negative(self) -> Self
+    where
+        T: Float,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        if !actual.is_sign_negative() {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to have a negative sign. But was
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_relative(self, expected: T, relative_tolerance: T) -> Self
+    where
+        T: Float + Ulps,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        let allowed_deviation = relative_tolerance * expected.abs();
+        if !is_within_deviation(actual, expected, allowed_deviation) {
+            let min = expected - allowed_deviation;
+            let max = expected + allowed_deviation;
+            add_float_distance_details(&self, actual, expected);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be close to: {expected:#?},
+                    with relative tolerance being: {relative_tolerance:#?},
+                      but value was outside range: [{min:?}, {max:?}]
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_close_to_with_tolerances(
+        self,
+        expected: T,
+        absolute_tolerance: T,
+        relative_tolerance: T,
+    ) -> Self
+    where
+        T: Float + Ulps,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        let allowed_deviation = absolute_tolerance + relative_tolerance * expected.abs();
+        if !is_within_deviation(actual, expected, allowed_deviation) {
+            let min = expected - allowed_deviation;
+            let max = expected + allowed_deviation;
+            add_float_distance_details(&self, actual, expected);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be close to: {expected:#?},
+                    with absolute tolerance being: {absolute_tolerance:#?},
+                     and relative tolerance being: {relative_tolerance:#?},
+                      but value was outside range: [{min:?}, {max:?}]
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn is_within_ulps(self, expected: T, max_ulps: u64) -> Self
+    where
+        T: Float + Ulps,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = *self.actual();
+        if actual
+            .ulps_between(expected)
+            .is_none_or(|ulps| ulps > max_ulps)
+        {
+            add_float_distance_details(&self, actual, expected);
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value to be within {max_ulps} ULPs of: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -284,6 +622,15 @@ mod tests {
 
         assert_that!(f32::infinity()).is_infinite();
         assert_that!(f64::infinity()).is_infinite();
+
+        assert_that!(1.0f32).is_normal();
+        assert_that!(1.0f64).is_normal();
+
+        assert_that!(0.2f32 + 0.1f32).is_close_to_relative(0.3, 0.0001);
+        assert_that!(0.2f64 + 0.1f64).is_close_to_relative(0.3, 0.0001);
+
+        assert_that!(0.2f32 + 0.1f32).is_within_ulps(0.3, 1);
+        assert_that!(0.2f64 + 0.1f64).is_within_ulps(0.3, 1);
     }
 
     mod is_zero {
@@ -551,4 +898,407 @@ mod tests {
                 "#});
         }
     }
+
+    mod is_normal {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_normal() {
+            assert_that!(1.0).is_normal();
+            assert_that!(f64::MIN_POSITIVE).is_normal();
+        }
+
+        #[test]
+        fn panics_when_zero() {
+            assert_that_panic_by(|| assert_that!(0.0).with_location(false).is_normal())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected a normal value, but was
+
+                      Actual: 0.0
+
+                    Details: [
+                        Category: Zero,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_subnormal_infinite_or_nan() {
+            assert_that!(f64::MIN_POSITIVE / 2.0)
+                .with_capture()
+                .is_normal()
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(f64::INFINITY)
+                .with_capture()
+                .is_normal()
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(f64::NAN)
+                .with_capture()
+                .is_normal()
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_subnormal {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_subnormal() {
+            assert_that!(f64::MIN_POSITIVE / 2.0).is_subnormal();
+            assert_that!(f32::MIN_POSITIVE / 2.0).is_subnormal();
+        }
+
+        #[test]
+        fn panics_when_normal() {
+            assert_that_panic_by(|| assert_that!(1.0).with_location(false).is_subnormal())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected a subnormal value, but was
+
+                      Actual: 1.0
+
+                    Details: [
+                        Category: Normal,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_zero_or_nan() {
+            assert_that!(0.0)
+                .with_capture()
+                .is_subnormal()
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(f64::NAN)
+                .with_capture()
+                .is_subnormal()
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_sign_positive {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_sign_bit_is_not_set() {
+            assert_that!(1.0).is_sign_positive();
+            assert_that!(0.0).is_sign_positive();
+            assert_that!(f64::NAN).is_sign_positive();
+        }
+
+        #[test]
+        fn panics_when_negative_zero() {
+            assert_that_panic_by(|| assert_that!(-0.0).with_location(false).is_sign_positive())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to have a positive sign. But was
+
+                      Actual: -0.0
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_sign_negative {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_sign_bit_is_set() {
+            assert_that!(-1.0).is_sign_negative();
+            assert_that!(-0.0).is_sign_negative();
+            assert_that!(-f64::NAN).is_sign_negative();
+        }
+
+        #[test]
+        fn panics_when_positive_zero() {
+            assert_that_panic_by(|| assert_that!(0.0).with_location(false).is_sign_negative())
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to have a negative sign. But was
+
+                      Actual: 0.0
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_close_to_relative {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_within_relative_tolerance() {
+            assert_that!(99.0).is_close_to_relative(100.0, 0.01);
+            assert_that!(101.0).is_close_to_relative(100.0, 0.01);
+            assert_that!(-1.0e-20).is_close_to_relative(-1.01e-20, 0.01);
+        }
+
+        #[test]
+        fn succeeds_when_infinities_are_equal() {
+            assert_that!(f64::INFINITY).is_close_to_relative(f64::INFINITY, 0.0);
+        }
+
+        #[test]
+        fn panics_when_outside_relative_tolerance() {
+            assert_that_panic_by(|| {
+                assert_that!(102.0)
+                    .with_location(false)
+                    .is_close_to_relative(100.0, 0.01)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be close to: 100.0,
+                    with relative tolerance being: 0.01,
+                      but value was outside range: [99.0, 101.0]
+
+                      Actual: 102.0
+
+                    Details: [
+                        Difference: 2.0,
+                        Relative error: 0.02,
+                        ULP distance: 140737488355328,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn fails_when_only_one_value_is_infinite() {
+            assert_that!(1.0)
+                .with_capture()
+                .is_close_to_relative(f64::INFINITY, 0.01)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+
+        #[test]
+        fn fails_when_nan_is_involved() {
+            assert_that!(f64::NAN)
+                .with_capture()
+                .is_close_to_relative(f64::NAN, 1.0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(1.0)
+                .with_capture()
+                .is_close_to_relative(f64::NAN, 1.0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_close_to_with_tolerances {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_within_combined_tolerance() {
+            assert_that!(1.0e-9).is_close_to_with_tolerances(0.0, 1.0e-8, 1.0e-5);
+            assert_that!(100.001).is_close_to_with_tolerances(100.0, 1.0e-8, 1.0e-5);
+        }
+
+        #[test]
+        fn panics_when_outside_combined_tolerance() {
+            assert_that_panic_by(|| {
+                assert_that!(10.5)
+                    .with_location(false)
+                    .is_close_to_with_tolerances(10.0, 0.25, 0.02)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be close to: 10.0,
+                    with absolute tolerance being: 0.25,
+                     and relative tolerance being: 0.02,
+                      but value was outside range: [9.55, 10.45]
+
+                      Actual: 10.5
+
+                    Details: [
+                        Difference: 0.5,
+                        Relative error: 0.05,
+                        ULP distance: 281474976710656,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn succeeds_when_infinities_are_equal() {
+            assert_that!(f64::NEG_INFINITY).is_close_to_with_tolerances(
+                f64::NEG_INFINITY,
+                0.0,
+                0.0,
+            );
+        }
+
+        #[test]
+        fn fails_when_infinities_differ() {
+            assert_that!(1.0)
+                .with_capture()
+                .is_close_to_with_tolerances(f64::INFINITY, 0.0, 0.01)
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(f64::NEG_INFINITY)
+                .with_capture()
+                .is_close_to_with_tolerances(f64::INFINITY, 0.0, 0.01)
+                .capture_failures()
+                .must()
+                .have_length(1);
+            assert_that!(f64::INFINITY)
+                .with_capture()
+                .is_close_to_with_tolerances(1.0, f64::INFINITY, 0.0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+
+        #[test]
+        fn fails_when_nan_is_involved() {
+            assert_that!(f64::NAN)
+                .with_capture()
+                .is_close_to_with_tolerances(0.0, f64::INFINITY, 0.0)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+    }
+
+    mod is_within_ulps {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_within_max_ulps() {
+            assert_that!(0.1 + 0.2).is_within_ulps(0.3, 1);
+            assert_that!(-0.0).is_within_ulps(0.0, 0);
+            assert_that!(f64::MAX).is_within_ulps(f64::INFINITY, 1);
+        }
+
+        #[test]
+        fn counts_ulps_across_zero() {
+            let smallest = f32::from_bits(1);
+            assert_that!(smallest).is_within_ulps(-smallest, 2);
+            assert_that!(smallest)
+                .with_capture()
+                .is_within_ulps(-smallest, 1)
+                .capture_failures()
+                .must()
+                .have_length(1);
+        }
+
+        #[test]
+        fn panics_when_too_many_ulps_apart() {
+            assert_that_panic_by(|| {
+                assert_that!(1.0f32)
+                    .with_location(false)
+                    .is_within_ulps(1.0 + 4.0 * f32::EPSILON, 3)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be within 3 ULPs of: 1.0000005
+
+                      Actual: 1.0
+
+                    Details: [
+                        Difference: 4.7683716e-7,
+                        Relative error: 4.7683693e-7,
+                        ULP distance: 4,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn panics_when_nan_is_involved() {
+            assert_that_panic_by(|| {
+                assert_that!(f64::NAN)
+                    .with_location(false)
+                    .is_within_ulps(1.0, u64::MAX)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be within 18446744073709551615 ULPs of: 1.0
+
+                      Actual: NaN
+
+                    Details: [
+                        Difference: NaN,
+                        Relative error: NaN,
+                        ULP distance: undefined, as NaN is involved,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod big_numbers_rationals_and_complex_numbers {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::complex::Complex;
+        use num::{BigInt, BigRational, BigUint, Rational64};
+
+        #[test]
+        fn support_num_assertions() {
+            assert_that!(BigInt::from(0)).is_zero();
+            assert_that!(BigUint::from(1u8)).is_one();
+            assert_that!(BigInt::from(-7)).is_negative();
+            assert_that!(BigInt::from(100)).is_close_to(BigInt::from(98), BigInt::from(2));
+
+            assert_that!(Rational64::new(2, 2)).is_one();
+            assert_that!(Rational64::new(-1, 3)).is_negative();
+            assert_that!(Rational64::new(1, 3))
+                .is_close_to(Rational64::new(1, 2), Rational64::new(1, 6));
+            assert_that!(BigRational::from_integer(BigInt::from(0))).is_zero();
+
+            assert_that!(Complex::new(0.0, 0.0)).is_zero();
+            assert_that!(Complex::new(1, 0)).is_one();
+        }
+
+        #[test]
+        fn panics_when_big_int_is_not_close() {
+            assert_that_panic_by(|| {
+                assert_that!(BigInt::from(10).pow(30u32))
+                    .with_location(false)
+                    .is_close_to(BigInt::from(0), BigInt::from(1))
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected value to be close to: 0,
+                     with allowed deviation being: 1,
+                      but value was outside range: [-1, 1]
+
+                      Actual: 1000000000000000000000000000000
+                    -------- assertr --------
+                "#});
+        }
+    }
 }
@@ -0,0 +1,187 @@
+use crate::AssertThat;
+use crate::mode::Mode;
+use crate::tracking::AssertionTracking;
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+use num::Integer;
+use num::rational::Ratio;
+
+/// Assertions for rational numbers, e.g. `Rational64` or `BigRational`.
+///
+/// Numerator and denominator are always checked in their reduced form, so `2/4` has the
+/// numerator `1` and the denominator `2`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait RationalAssertions<T> {
+    /// Fails if actual has a denominator other than one, after reduction.
+    fn is_integer(self) -> Self;
+
+    fn has_numerator(self, expected: T) -> Self;
+
+    fn has_denominator(self, expected: T) -> Self;
+}
+
+impl<T, M: Mode> RationalAssertions<T> for AssertThat<'_, Ratio<T>, M>
+where
+    T: Clone + Integer + Debug,
+{
+    #[track_caller]
+    fn is_integer(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let reduced = actual.reduced();
+        if !reduced.is_integer() {
+            let (numer, denom) = (reduced.numer(), reduced.denom());
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected an integer, but was: {numer:?}/{denom:?}
+
+                      Actual: {actual:#?}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_numerator(self, expected: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let reduced = actual.reduced();
+        let numer = reduced.numer();
+        if numer != &expected {
+            let denom = reduced.denom();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected numerator: {expected:#?}
+
+                      Actual numerator: {numer:#?} (of {numer:?}/{denom:?})
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_denominator(self, expected: T) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual();
+        let reduced = actual.reduced();
+        let denom = reduced.denom();
+        if denom != &expected {
+            let numer = reduced.numer();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected denominator: {expected:#?}
+
+                      Actual denominator: {denom:#?} (of {numer:?}/{denom:?})
+                "}
+            });
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod is_integer {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::Rational64;
+
+        #[test]
+        fn succeeds_when_integer() {
+            assert_that!(Rational64::new(6, 3)).is_integer();
+            assert_that!(Rational64::new_raw(6, 3)).is_integer();
+        }
+
+        #[test]
+        fn panics_when_not_integer() {
+            assert_that_panic_by(|| {
+                assert_that!(Rational64::new(3, 6))
+                    .with_location(false)
+                    .is_integer()
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected an integer, but was: 1/2
+
+                      Actual: Ratio {{
+                        numer: 1,
+                        denom: 2,
+                    }}
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_numerator {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::{BigInt, BigRational, Rational64};
+
+        #[test]
+        fn succeeds_when_reduced_numerator_matches() {
+            assert_that!(Rational64::new_raw(2, 4)).has_numerator(1);
+            assert_that!(BigRational::new(BigInt::from(10), BigInt::from(4)))
+                .has_numerator(BigInt::from(5));
+        }
+
+        #[test]
+        fn panics_when_reduced_numerator_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(Rational64::new_raw(2, 4))
+                    .with_location(false)
+                    .has_numerator(2)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected numerator: 2
+
+                      Actual numerator: 1 (of 1/2)
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_denominator {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use num::Rational64;
+
+        #[test]
+        fn succeeds_when_reduced_denominator_matches() {
+            assert_that!(Rational64::new_raw(2, 4)).has_denominator(2);
+            assert_that!(Rational64::new(-3, 9)).has_denominator(3);
+        }
+
+        #[test]
+        fn panics_when_reduced_denominator_differs() {
+            assert_that_panic_by(|| {
+                assert_that!(Rational64::new_raw(2, 4))
+                    .with_location(false)
+                    .has_denominator(4)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected denominator: 4
+
+                      Actual denominator: 2 (of 1/2)
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
@@ -0,0 +1,777 @@
+use crate::AssertThat;
+use crate::actual::Actual;
+use crate::assertions::core::range::render_range;
+use crate::mode::{Mode, Panic};
+use crate::tracking::AssertionTracking;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::fmt::Write;
+use core::ops::RangeBounds;
+use indoc::writedoc;
+use num::{Num, ToPrimitive};
+
+/// Statistical assertions over samples of numeric values, held in slices, arrays, `Vec`s or
+/// `VecDeque`s.
+///
+/// Statistics are computed in `f64`. Use [`IteratorAssertions::collected`](crate::prelude::IteratorAssertions::collected)
+/// to assert on the values yielded by an iterator.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait StatisticsAssertions<'t, T, M: Mode> {
+    /// Fails if the arithmetic mean of all samples deviates from `expected` by more than
+    /// `tolerance`, or if there are no samples.
+    fn has_mean_close_to(self, expected: f64, tolerance: f64) -> Self
+    where
+        T: ToPrimitive + Debug;
+
+    /// Fails if the median of all samples is not `expected`, or if there are no samples.
+    ///
+    /// The median of an even number of samples is the mean of the two middle samples.
+    fn has_median(self, expected: f64) -> Self
+    where
+        T: ToPrimitive + Debug;
+
+    /// Fails if the population standard deviation of all samples is not below `max`, or if there
+    /// are no samples.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn has_std_dev_below(self, max: f64) -> Self
+    where
+        T: ToPrimitive + Debug;
+
+    /// Fails if the smallest sample is not `expected`, or if there are no samples.
+    fn has_min(self, expected: T) -> Self
+    where
+        T: PartialOrd + Debug;
+
+    /// Fails if the largest sample is not `expected`, or if there are no samples.
+    fn has_max(self, expected: T) -> Self
+    where
+        T: PartialOrd + Debug;
+
+    /// Fails if the sum of all samples is not `expected`. The sum of no samples is zero.
+    fn sum_is(self, expected: T) -> Self
+    where
+        T: Num + Clone + Debug;
+
+    /// Fails if any sample lies outside of `range`, listing every such sample with its index.
+    fn all_within(self, range: impl RangeBounds<T>) -> Self
+    where
+        T: PartialOrd + Debug;
+
+    /// Change the subject to the sum of all samples. The sum of no samples is zero.
+    fn sum(self) -> AssertThat<'t, T, M>
+    where
+        T: Num + Clone;
+}
+
+/// Data-extracting statistical assertions over samples of numeric values, held in slices, arrays,
+/// `Vec`s or `VecDeque`s.
+///
+/// The extracted statistic can be asserted further, e.g. using `NumAssertions::is_close_to` or
+/// `RangeAssertions::is_in_range`.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait StatisticsExtractAssertions<'t, T> {
+    /// Test that there are samples and extract the smallest one.
+    ///
+    /// Only available in `Panic` mode, as the extracted `T` cannot be produced without samples.
+    fn min(self) -> AssertThat<'t, T, Panic>
+    where
+        T: PartialOrd + Clone + Debug;
+
+    /// Test that there are samples and extract the largest one.
+    ///
+    /// Only available in `Panic` mode, as the extracted `T` cannot be produced without samples.
+    fn max(self) -> AssertThat<'t, T, Panic>
+    where
+        T: PartialOrd + Clone + Debug;
+
+    /// Test that there are samples and extract their arithmetic mean.
+    ///
+    /// Only available in `Panic` mode, as the mean cannot be produced without samples.
+    fn mean(self) -> AssertThat<'t, f64, Panic>
+    where
+        T: ToPrimitive + Debug;
+
+    /// Test that there are samples and extract their median.
+    ///
+    /// Only available in `Panic` mode, as the median cannot be produced without samples.
+    fn median(self) -> AssertThat<'t, f64, Panic>
+    where
+        T: ToPrimitive + Debug;
+
+    /// Test that there are samples and extract their population standard deviation.
+    ///
+    /// Only available in `Panic` mode, as the standard deviation cannot be produced without
+    /// samples.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn std_dev(self) -> AssertThat<'t, f64, Panic>
+    where
+        T: ToPrimitive + Debug;
+}
+
+fn to_f64<'a, T: ToPrimitive + 'a>(samples: impl Iterator<Item = &'a T>) -> Vec<f64> {
+    samples.map(|it| it.to_f64().unwrap_or(f64::NAN)).collect()
+}
+
+fn mean(samples: &[f64]) -> Option<f64> {
+    if samples.is_empty() {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let len = samples.len() as f64;
+    Some(samples.iter().sum::<f64>() / len)
+}
+
+fn median(mut samples: Vec<f64>) -> Option<f64> {
+    samples.sort_by(f64::total_cmp);
+    let mid = samples.len() / 2;
+    match samples.len() {
+        0 => None,
+        len if len % 2 == 0 => Some(f64::midpoint(samples[mid - 1], samples[mid])),
+        _ => Some(samples[mid]),
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn std_dev(samples: &[f64]) -> Option<f64> {
+    let mean = mean(samples)?;
+    #[allow(clippy::cast_precision_loss)]
+    let len = samples.len() as f64;
+    let variance = samples
+        .iter()
+        .map(|it| (it - mean) * (it - mean))
+        .sum::<f64>()
+        / len;
+    Some(num::Float::sqrt(variance))
+}
+
+/// The sample which is ordered as `ordering` compared to all others, keeping the first one found.
+fn extreme<'a, T: PartialOrd>(
+    samples: impl Iterator<Item = &'a T>,
+    ordering: Ordering,
+) -> Option<&'a T> {
+    samples.reduce(|acc, it| {
+        if it.partial_cmp(acc) == Some(ordering) {
+            it
+        } else {
+            acc
+        }
+    })
+}
+
+fn sum<'a, T: Num + Clone + 'a>(samples: impl Iterator<Item = &'a T>) -> T {
+    samples.cloned().fold(T::zero(), |acc, it| acc + it)
+}
+
+#[track_caller]
+fn fail_without_samples<S, M: Mode>(
+    this: &AssertThat<'_, S, M>,
+    samples: &impl Debug,
+    statistic: &str,
+) {
+    this.fail(|w: &mut String| {
+        writedoc! {w, r"
+            Samples: {samples:#?}
+
+            have no {statistic}, as there are none
+        "}
+    });
+}
+
+/// Implements the statistical assertions for each given subject type, accessing its samples
+/// through its `iter()` method.
+macro_rules! impl_statistics_assertions {
+    ($([$($generics:tt)*] $subject:ty),* $(,)?) => {
+        $(
+            impl<'t, $($generics)*, M: Mode> StatisticsAssertions<'t, T, M> for AssertThat<'t, $subject, M> {
+                #[track_caller]
+                fn has_mean_close_to(self, expected: f64, tolerance: f64) -> Self
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    match mean(&to_f64(actual.iter())) {
+                        None => fail_without_samples(&self, actual, "mean"),
+                        Some(mean) if (mean - expected).is_nan() || (mean - expected).abs() > tolerance => {
+                            self.fail(|w: &mut String| {
+                                writedoc! {w, r"
+                                    Expected mean: {expected:?}, with allowed deviation being: {tolerance:?}
+
+                                      Actual mean: {mean:?}
+
+                                    Samples: {actual:#?}
+                                "}
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn has_median(self, expected: f64) -> Self
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    match median(to_f64(actual.iter())) {
+                        None => fail_without_samples(&self, actual, "median"),
+                        #[allow(clippy::float_cmp)]
+                        Some(median) if median != expected => {
+                            self.fail(|w: &mut String| {
+                                writedoc! {w, r"
+                                    Expected median: {expected:?}
+
+                                      Actual median: {median:?}
+
+                                    Samples: {actual:#?}
+                                "}
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    self
+                }
+
+                #[cfg(any(feature = "std", feature = "libm"))]
+                #[track_caller]
+                fn has_std_dev_below(self, max: f64) -> Self
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    match std_dev(&to_f64(actual.iter())) {
+                        None => fail_without_samples(&self, actual, "standard deviation"),
+                        Some(std_dev) if std_dev.is_nan() || std_dev >= max => {
+                            self.fail(|w: &mut String| {
+                                writedoc! {w, r"
+                                    Expected standard deviation below: {max:?}
+
+                                      Actual standard deviation: {std_dev:?}
+
+                                    Samples: {actual:#?}
+                                "}
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn has_min(self, expected: T) -> Self
+                where
+                    T: PartialOrd + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    match extreme(actual.iter(), Ordering::Less) {
+                        None => fail_without_samples(&self, actual, "min"),
+                        Some(min) if min != &expected => {
+                            self.fail(|w: &mut String| {
+                                writedoc! {w, r"
+                                    Expected min: {expected:?}
+
+                                      Actual min: {min:?}
+
+                                    Samples: {actual:#?}
+                                "}
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn has_max(self, expected: T) -> Self
+                where
+                    T: PartialOrd + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    match extreme(actual.iter(), Ordering::Greater) {
+                        None => fail_without_samples(&self, actual, "max"),
+                        Some(max) if max != &expected => {
+                            self.fail(|w: &mut String| {
+                                writedoc! {w, r"
+                                    Expected max: {expected:?}
+
+                                      Actual max: {max:?}
+
+                                    Samples: {actual:#?}
+                                "}
+                            });
+                        }
+                        Some(_) => {}
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn sum_is(self, expected: T) -> Self
+                where
+                    T: Num + Clone + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    let sum = sum(actual.iter());
+                    if sum != expected {
+                        self.fail(|w: &mut String| {
+                            writedoc! {w, r"
+                                Expected sum: {expected:?}
+
+                                  Actual sum: {sum:?}
+
+                                Samples: {actual:#?}
+                            "}
+                        });
+                    }
+                    self
+                }
+
+                #[track_caller]
+                fn all_within(self, range: impl RangeBounds<T>) -> Self
+                where
+                    T: PartialOrd + Debug,
+                {
+                    self.track_assertion();
+                    if self.is_subject_poisoned() {
+                        return self;
+                    }
+                    let actual = self.actual();
+                    let outside = actual
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, it)| !range.contains(*it))
+                        .collect::<Vec<_>>();
+                    if !outside.is_empty() {
+                        let mut rendered_range = String::new();
+                        render_range(&mut rendered_range, range);
+                        self.fail(|w: &mut String| {
+                            writedoc! {w, r"
+                                Samples: {actual:#?}
+
+                                are not all within range: {rendered_range}
+
+                                Samples out of range:
+                            "}?;
+                            for (index, sample) in &outside {
+                                writeln!(w, "  [{index}]: {sample:?}")?;
+                            }
+                            Ok(())
+                        });
+                    }
+                    self
+                }
+
+                fn sum(self) -> AssertThat<'t, T, M>
+                where
+                    T: Num + Clone,
+                {
+                    self.map(|actual| Actual::Owned(sum(actual.borrowed().iter())))
+                }
+            }
+
+            impl<'t, $($generics)*> StatisticsExtractAssertions<'t, T> for AssertThat<'t, $subject, Panic> {
+                #[track_caller]
+                fn min(self) -> AssertThat<'t, T, Panic>
+                where
+                    T: PartialOrd + Clone + Debug,
+                {
+                    self.track_assertion();
+                    let actual = self.actual();
+                    let Some(min) = extreme(actual.iter(), Ordering::Less).cloned() else {
+                        fail_without_samples(&self, actual, "min");
+                        unreachable!("Panic mode always panics on fail")
+                    };
+                    self.map(|_| Actual::Owned(min))
+                }
+
+                #[track_caller]
+                fn max(self) -> AssertThat<'t, T, Panic>
+                where
+                    T: PartialOrd + Clone + Debug,
+                {
+                    self.track_assertion();
+                    let actual = self.actual();
+                    let Some(max) = extreme(actual.iter(), Ordering::Greater).cloned() else {
+                        fail_without_samples(&self, actual, "max");
+                        unreachable!("Panic mode always panics on fail")
+                    };
+                    self.map(|_| Actual::Owned(max))
+                }
+
+                #[track_caller]
+                fn mean(self) -> AssertThat<'t, f64, Panic>
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    let actual = self.actual();
+                    let Some(mean) = mean(&to_f64(actual.iter())) else {
+                        fail_without_samples(&self, actual, "mean");
+                        unreachable!("Panic mode always panics on fail")
+                    };
+                    self.map(|_| Actual::Owned(mean))
+                }
+
+                #[track_caller]
+                fn median(self) -> AssertThat<'t, f64, Panic>
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    let actual = self.actual();
+                    let Some(median) = median(to_f64(actual.iter())) else {
+                        fail_without_samples(&self, actual, "median");
+                        unreachable!("Panic mode always panics on fail")
+                    };
+                    self.map(|_| Actual::Owned(median))
+                }
+
+                #[cfg(any(feature = "std", feature = "libm"))]
+                #[track_caller]
+                fn std_dev(self) -> AssertThat<'t, f64, Panic>
+                where
+                    T: ToPrimitive + Debug,
+                {
+                    self.track_assertion();
+                    let actual = self.actual();
+                    let Some(std_dev) = std_dev(&to_f64(actual.iter())) else {
+                        fail_without_samples(&self, actual, "standard deviation");
+                        unreachable!("Panic mode always panics on fail")
+                    };
+                    self.map(|_| Actual::Owned(std_dev))
+                }
+            }
+        )*
+    };
+}
+
+impl_statistics_assertions!(
+    [T] &[T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+);
+
+#[cfg(test)]
+mod tests {
+    mod has_mean_close_to {
+        use crate::prelude::*;
+        use alloc::collections::VecDeque;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_mean_is_within_tolerance() {
+            assert_that!([1, 2, 3, 4]).has_mean_close_to(2.5, 0.0);
+            assert_that!(vec![0.9, 1.1]).has_mean_close_to(1.0, 1e-9);
+            assert_that!([1.0f32, 2.0].as_slice()).has_mean_close_to(1.45, 0.1);
+            assert_that!(VecDeque::from([1, 2])).has_mean_close_to(1.5, 0.0);
+        }
+
+        #[test]
+        fn succeeds_for_collected_iterator() {
+            assert_that!((1..=99).map(|it| it * 2))
+                .collected()
+                .has_mean_close_to(100.0, 0.0);
+        }
+
+        #[test]
+        fn panics_when_mean_is_not_within_tolerance() {
+            assert_that_panic_by(|| {
+                assert_that!([1, 2, 6])
+                    .with_location(false)
+                    .has_mean_close_to(2.0, 0.5)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected mean: 2.0, with allowed deviation being: 0.5
+
+                      Actual mean: 3.0
+
+                    Samples: [
+                        1,
+                        2,
+                        6,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn panics_when_there_are_no_samples() {
+            assert_that_panic_by(|| {
+                assert_that!(Vec::<f64>::new())
+                    .with_location(false)
+                    .has_mean_close_to(0.0, 1.0)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Samples: []
+
+                    have no mean, as there are none
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_median {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_for_odd_and_even_number_of_samples() {
+            assert_that!([5, 1, 3]).has_median(3.0);
+            assert_that!([4.0, 1.0, 3.0, 2.0]).has_median(2.5);
+        }
+
+        #[test]
+        fn panics_when_median_differs() {
+            assert_that_panic_by(|| {
+                assert_that!([1, 2, 100])
+                    .with_location(false)
+                    .has_median(34.0)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected median: 34.0
+
+                      Actual median: 2.0
+
+                    Samples: [
+                        1,
+                        2,
+                        100,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_std_dev_below {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_samples_are_close_together() {
+            assert_that!([10.0, 10.1, 9.9]).has_std_dev_below(0.1);
+        }
+
+        #[test]
+        fn panics_when_samples_spread_too_far() {
+            assert_that_panic_by(|| {
+                assert_that!([2, 4, 4, 4, 5, 5, 7, 9])
+                    .with_location(false)
+                    .has_std_dev_below(2.0)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected standard deviation below: 2.0
+
+                      Actual standard deviation: 2.0
+
+                    Samples: [
+                        2,
+                        4,
+                        4,
+                        4,
+                        5,
+                        5,
+                        7,
+                        9,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod has_min_and_has_max {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_extremes_match() {
+            assert_that!([3, -1, 7, 2]).has_min(-1).has_max(7);
+        }
+
+        #[test]
+        fn reports_all_failures_in_capture_mode() {
+            let failures = assert_that!(Vec::<u8>::new())
+                .with_capture()
+                .with_location(false)
+                .has_min(0)
+                .has_max(0)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly([
+                formatdoc! {r#"
+                    -------- assertr --------
+                    Samples: []
+
+                    have no min, as there are none
+                    -------- assertr --------
+                "#},
+                formatdoc! {r#"
+                    -------- assertr --------
+                    Samples: []
+
+                    have no max, as there are none
+                    -------- assertr --------
+                "#},
+            ]);
+        }
+
+        #[test]
+        fn panics_when_max_differs() {
+            assert_that_panic_by(|| assert_that!([1.5, 0.5]).with_location(false).has_max(1.0))
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected max: 1.0
+
+                      Actual max: 1.5
+
+                    Samples: [
+                        1.5,
+                        0.5,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod sum_is {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_sum_matches() {
+            assert_that!([1u64, 2, 3]).sum_is(6);
+            assert_that!(Vec::<i32>::new()).sum_is(0);
+        }
+
+        #[test]
+        fn panics_when_sum_differs() {
+            assert_that_panic_by(|| assert_that!([1, 2]).with_location(false).sum_is(4))
+                .has_type::<String>()
+                .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected sum: 4
+
+                      Actual sum: 3
+
+                    Samples: [
+                        1,
+                        2,
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod all_within {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_all_samples_are_in_range() {
+            assert_that!([0.1, 0.5, 0.9]).all_within(0.0..1.0);
+            assert_that!(Vec::<u8>::new()).all_within(1..=2);
+        }
+
+        #[test]
+        fn panics_listing_every_sample_out_of_range() {
+            assert_that_panic_by(|| {
+                assert_that!([5, 11, 7, -1])
+                    .with_location(false)
+                    .all_within(0..=10)
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Samples: [
+                        5,
+                        11,
+                        7,
+                        -1,
+                    ]
+
+                    are not all within range: 0..=10
+
+                    Samples out of range:
+                      [1]: 11
+                      [3]: -1
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod extraction {
+        use crate::prelude::*;
+        use alloc::collections::VecDeque;
+
+        #[test]
+        fn extracted_statistics_can_be_asserted_further() {
+            let samples = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+            assert_that!(samples.as_slice()).min().is_equal_to(2.0);
+            assert_that!(samples.as_slice())
+                .max()
+                .is_in_range(8.0..10.0);
+            assert_that!(samples.as_slice())
+                .mean()
+                .is_close_to(5.0, 0.0);
+            assert_that!(samples.as_slice()).median().is_equal_to(4.5);
+            assert_that!(samples.as_slice())
+                .std_dev()
+                .is_close_to(2.0, 1e-12);
+            assert_that!(VecDeque::from(samples.clone()))
+                .median()
+                .is_equal_to(4.5);
+            assert_that!(samples).sum().is_equal_to(40.0);
+        }
+
+        #[test]
+        fn panics_when_extracting_from_no_samples() {
+            assert_that_panic_by(|| assert_that!([0u8; 0]).with_location(false).mean())
+                .has_type::<String>()
+                .is_equal_to(indoc::formatdoc! {r#"
+                    -------- assertr --------
+                    Samples: []
+
+                    have no mean, as there are none
+                    -------- assertr --------
+                "#});
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::AssertThat;
-use crate::mode::{Mode, Panic};
+use crate::actual::Actual;
+use crate::failure::NESTED_ASSERTIONS_SKIPPED;
+use crate::mode::Mode;
 use crate::tracking::AssertionTracking;
 use alloc::borrow::Cow;
 use indoc::writedoc;
@@ -60,22 +62,31 @@ impl AsRef<OsStr> for Program<'_> {
 pub trait ProgramAssertions<'t, 'a, M: Mode> {
     /// Check that the program exists (equivalent to doing a `which {program}` check on unix).
     fn exists(self) -> AssertThat<'t, Program<'a>, M>;
-}
 
-pub trait ProgramAssertionsRequiringPanicMode<'t> {
+    /// Check that the program exists (equivalent to doing a `which {program}` check on unix),
+    /// then run additional assertions on the found `PathBuf`.
+    ///
+    /// Use `exists_and` to chain assertions on the `PathBuf` instead.
+    fn exists_satisfying<A>(self, assertions: A) -> AssertThat<'t, Program<'a>, M>
+    where
+        A: for<'b> FnOnce(AssertThat<'b, PathBuf, M>);
+
     /// Check that the program exists (equivalent to doing a `which {program}` check on unix).
     ///
     /// Terminal operation, automatically mapping to the found `PathBuf` on success.
     ///
-    /// This is only available in [`Panic`] mode, os we rely on the assertion panic for the mapping
-    /// to only happen in the success case!
-    fn exists_and(self) -> AssertThat<'t, PathBuf, Panic>;
+    /// In `Capture` mode, the subject is poisoned when the program does not exist, skipping all
+    /// subsequent assertions.
+    fn exists_and(self) -> AssertThat<'t, PathBuf, M>;
 }
 
 impl<'a, 't, M: Mode> ProgramAssertions<'t, 'a, M> for AssertThat<'t, Program<'a>, M> {
     #[track_caller]
     fn exists(self) -> AssertThat<'t, Program<'a>, M> {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let program = self.actual().as_ref();
         let found = which::which(program);
 
@@ -93,29 +104,59 @@ impl<'a, 't, M: Mode> ProgramAssertions<'t, 'a, M> for AssertThat<'t, Program<'a
 
         self
     }
-}
 
-impl<'t> ProgramAssertionsRequiringPanicMode<'t> for AssertThat<'t, Program<'_>, Panic> {
     #[track_caller]
-    fn exists_and(self) -> AssertThat<'t, PathBuf, Panic> {
+    fn exists_satisfying<A>(self, assertions: A) -> AssertThat<'t, Program<'a>, M>
+    where
+        A: for<'b> FnOnce(AssertThat<'b, PathBuf, M>),
+    {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let program = self.actual().as_ref();
-        let found = which::which(program);
-
-        if let Err(err) = &found {
-            self.fail(|w: &mut String| {
-                writedoc! {w, r#"
-                    Expected program: {program:?}
 
-                    to exist, but it could not be found.
+        match which::which(program) {
+            Ok(path) => self.satisfies(|_| path, assertions),
+            Err(err) => {
+                self.fail(|w: &mut String| {
+                    writedoc! {w, r#"
+                        Expected program: {program:?}
+
+                        to exist, but it could not be found.
+
+                        Reason: "{err}"
+                    "#}?;
+                    w.write_str(NESTED_ASSERTIONS_SKIPPED)
+                });
+                self
+            }
+        }
+    }
 
-                    Reason: "{err}"
-                "#}
-            });
+    #[track_caller]
+    fn exists_and(self) -> AssertThat<'t, PathBuf, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
         }
+        let program = self.actual().as_ref();
 
-        // Note: This will fail in capturing mode!
-        self.map_owned(|_| found.expect("present"))
+        match which::which(program) {
+            Ok(path) => self.map(|_actual| Actual::Owned(path)),
+            Err(err) => {
+                self.fail_extraction(|w: &mut String| {
+                    writedoc! {w, r#"
+                        Expected program: {program:?}
+
+                        to exist, but it could not be found.
+
+                        Reason: "{err}"
+                    "#}
+                });
+                self.poison()
+            }
+        }
     }
 }
 
@@ -247,5 +288,60 @@ mod tests {
 
             drop(rw_lock_write_guard);
         }
+
+        #[test]
+        fn captures_failure_and_skips_subsequent_assertions_when_not_existent() {
+            let failures = assert_that!(Program::from("someNonexistentProgram"))
+                .with_capture()
+                .with_location(false)
+                .exists_and()
+                .is_a_file()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected program: "someNonexistentProgram"
+
+                to exist, but it could not be found.
+
+                Reason: "cannot find binary path"
+
+                Assertions on the extracted value are skipped, as it could not be extracted.
+                -------- assertr --------
+            "#}]);
+        }
+    }
+
+    mod exists_satisfying {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_existent_and_assertions_pass() {
+            assert_that!(Program::from("ls")).exists_satisfying(|path| {
+                path.is_a_file();
+            });
+        }
+
+        #[test]
+        fn captures_variant_failure_without_running_assertions() {
+            let failures = assert_that!(Program::from("someNonexistentProgram"))
+                .with_capture()
+                .with_location(false)
+                .exists_satisfying(|_| panic!("assertions should not run"))
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {r#"
+                -------- assertr --------
+                Expected program: "someNonexistentProgram"
+
+                to exist, but it could not be found.
+
+                Reason: "cannot find binary path"
+
+                Nested assertions were skipped, as this precondition failed.
+                -------- assertr --------
+            "#}]);
+        }
     }
 }
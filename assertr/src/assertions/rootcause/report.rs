@@ -108,6 +108,9 @@ impl<C: ?Sized, O, T, M: Mode> RootcauseReportRefAssertions
     #[track_caller]
     fn has_child_count(self, expected: usize) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().children().len();
 
         if actual != expected {
@@ -125,6 +128,9 @@ impl<C: ?Sized, O, T, M: Mode> RootcauseReportRefAssertions
     #[track_caller]
     fn has_attachment_count(self, expected: usize) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual().attachments().len();
 
         if actual != expected {
@@ -142,6 +148,9 @@ impl<C: ?Sized, O, T, M: Mode> RootcauseReportRefAssertions
     #[track_caller]
     fn has_current_context_type<E: 'static>(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         assert_current_context_type::<E, _, _>(
             &self,
             self.actual().current_context_type_id(),
@@ -153,6 +162,9 @@ impl<C: ?Sized, O, T, M: Mode> RootcauseReportRefAssertions
     #[track_caller]
     fn has_current_context_display_value(self, expected: impl Display) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = format!("{}", self.actual().format_current_context());
         let expected = format!("{expected}");
 
@@ -171,6 +183,9 @@ impl<C: ?Sized, O, T, M: Mode> RootcauseReportRefAssertions
     #[track_caller]
     fn has_current_context_debug_string(self, expected: impl AsRef<str>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = format!("{:?}", self.actual().format_current_context());
         let actual = strip_quotation_marks(actual.as_str());
         let expected = strip_quotation_marks(expected.as_ref());
@@ -209,6 +224,9 @@ where
         A: for<'a> FnOnce(AssertThat<'a, &'a E, M>),
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if self.actual().downcast_current_context::<E>().is_some() {
             self.satisfies_ref(
@@ -251,6 +269,9 @@ where
         A: for<'a> FnOnce(AssertThat<'a, &'r E, M>),
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if self.actual().downcast_current_context::<E>().is_some() {
             self.satisfies(
@@ -1,4 +1,10 @@
-use crate::{AssertThat, AssertrPartialEq, EqContext, Mode, tracking::AssertionTracking};
+use crate::{
+    AssertThat, AssertrPartialEq, EqContext, Mode, actual::Actual, mode::Panic,
+    tracking::AssertionTracking, util::collection_diff::CollectionDiff,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::fmt::Debug;
 use core::fmt::Write;
@@ -8,7 +14,7 @@ use std::{collections::HashMap, hash::BuildHasher, hash::Hash};
 /// Assertions for generic [`HashMap`]s.
 #[allow(clippy::return_self_not_must_use)]
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
-pub trait HashMapAssertions<K, V> {
+pub trait HashMapAssertions<'t, K, V, M: Mode> {
     fn contains_key(self, expected: impl Borrow<K>) -> Self
     where
         K: Eq + Hash + Debug,
@@ -57,10 +63,54 @@ pub trait HashMapAssertions<K, V> {
         EK: Borrow<K> + Debug,
         EV: Debug,
         I: IntoIterator<Item = (EK, EV)>;
+
+    /// Extract all keys of the map, in arbitrary order.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) keys, on which all `Vec` assertions can be
+    /// used. Extraction never fails and is therefore available in every mode.
+    fn keys(self) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Clone;
+
+    /// Extract all values of the map, in arbitrary order.
+    ///
+    /// Changes the subject to a `Vec` of the (cloned) values, on which all `Vec` assertions can be
+    /// used. Extraction never fails and is therefore available in every mode.
+    fn values(self) -> AssertThat<'t, Vec<V>, M>
+    where
+        V: Clone;
+
+    /// Run the given assertions on every entry of the map.
+    ///
+    /// Failures name the key of the value they occurred on.
+    ///
+    /// ```rust
+    /// use assertr::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let stock = HashMap::from([("apples", 3), ("pears", 7)]);
+    ///
+    /// assert_that!(stock).all_entries_satisfy(|_fruit, amount| {
+    ///     amount.is_greater_than(&0);
+    /// });
+    /// ```
+    fn all_entries_satisfy<A>(self, assertions: A) -> Self
+    where
+        K: Debug,
+        A: for<'a> FnMut(&'a K, AssertThat<'a, &'a V, M>);
+
+    /// Test that at least one entry has a key matching `key_predicate` and a value matching
+    /// `value_predicate`.
+    fn contains_entries_matching<KP, VP>(self, key_predicate: KP, value_predicate: VP) -> Self
+    where
+        K: Debug,
+        V: Debug,
+        KP: Fn(&K) -> bool,
+        VP: Fn(&V) -> bool;
 }
 
-impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
-    for AssertThat<'_, HashMap<K, V, S>, M>
+impl<'t, K, V, S: BuildHasher, M: Mode> HashMapAssertions<'t, K, V, M>
+    for AssertThat<'t, HashMap<K, V, S>, M>
 {
     #[track_caller]
     fn contains_key(self, expected: impl Borrow<K>) -> Self
@@ -69,6 +119,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         V: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected = expected.borrow();
 
@@ -91,6 +144,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         V: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let not_expected = not_expected.borrow();
 
@@ -114,6 +170,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self
             .actual()
@@ -139,6 +198,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if self
             .actual()
@@ -205,6 +267,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let unexpected_key = key.borrow();
         let unexpected_value = value.borrow();
@@ -236,6 +301,9 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         I: IntoIterator<Item = E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected = expected.into_iter().collect::<Vec<_>>();
         let keys_not_found = expected
@@ -269,62 +337,100 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
         I: IntoIterator<Item = (EK, EV)>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected = expected.into_iter().collect::<Vec<_>>();
-        let mut keys_not_found = Vec::new();
-        let mut keys_with_unexpected_values = Vec::new();
-
-        for (expected_key, expected_value) in &expected {
-            match self.actual().get((*expected_key).borrow()) {
-                None => keys_not_found.push(expected_key),
-                Some(actual_value) => {
-                    let mut ctx = EqContext::new();
-                    if !AssertrPartialEq::eq(actual_value, expected_value, Some(&mut ctx)) {
-                        keys_with_unexpected_values.push(expected_key);
-                        if !ctx.differences.differences.is_empty() {
-                            self.add_detail_message(format!(
-                                "Differences at key {expected_key:#?}: {:#?}",
-                                ctx.differences
-                            ));
-                        }
-                    }
-                }
+        let diff =
+            CollectionDiff::of_map_entries(self.actual(), |key| self.actual().get(key), &expected);
+
+        if !diff.is_empty() {
+            for message in diff.messages() {
+                self.add_detail_message(message);
             }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: HashMap {actual:#?}
+
+                    does not exactly contain expected entries
+
+                    Expected entries: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
+    fn keys(self) -> AssertThat<'t, Vec<K>, M>
+    where
+        K: Clone,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().keys().cloned().collect()))
+    }
+
+    fn values(self) -> AssertThat<'t, Vec<V>, M>
+    where
+        V: Clone,
+    {
+        self.map(|actual| Actual::Owned(actual.borrowed().values().cloned().collect()))
+    }
+
+    #[track_caller]
+    fn all_entries_satisfy<A>(self, mut assertions: A) -> Self
+    where
+        K: Debug,
+        A: for<'a> FnMut(&'a K, AssertThat<'a, &'a V, M>),
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        for (key, value) in self.actual() {
+            assertions(
+                key,
+                self.derive(|_| value)
+                    .with_subject_name(format!("value at key {key:?}")),
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    fn contains_entries_matching<KP, VP>(self, key_predicate: KP, value_predicate: VP) -> Self
+    where
+        K: Debug,
+        V: Debug,
+        KP: Fn(&K) -> bool,
+        VP: Fn(&V) -> bool,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
         }
 
-        let unexpected_entries = self
+        let entries_with_matching_key = self
             .actual()
             .iter()
-            .filter(|(actual_key, _actual_value)| {
-                !expected
-                    .iter()
-                    .any(|(expected_key, _expected_value)| (*expected_key).borrow() == *actual_key)
-            })
+            .filter(|(key, _value)| key_predicate(key))
             .collect::<Vec<_>>();
 
-        if !keys_not_found.is_empty()
-            || !unexpected_entries.is_empty()
-            || !keys_with_unexpected_values.is_empty()
+        if !entries_with_matching_key
+            .iter()
+            .any(|(_key, value)| value_predicate(value))
         {
-            if !keys_not_found.is_empty() {
-                self.add_detail_message(format!("Keys not found: {keys_not_found:#?}"));
-            }
-            if !unexpected_entries.is_empty() {
-                self.add_detail_message(format!("Unexpected entries: {unexpected_entries:#?}"));
-            }
-            if !keys_with_unexpected_values.is_empty() {
+            if !entries_with_matching_key.is_empty() {
                 self.add_detail_message(format!(
-                    "Keys with unexpected values: {keys_with_unexpected_values:#?}"
+                    "Entries with matching key but non-matching value: {entries_with_matching_key:#?}"
                 ));
             }
-
             self.fail(|w: &mut String| {
                 writedoc! {w, r"
                     Actual: HashMap {actual:#?}
 
-                    does not exactly contain expected entries
-
-                    Expected entries: {expected:#?}
+                    does not contain an entry matching the given key and value predicates
                 ", actual = self.actual()}
             });
         }
@@ -332,6 +438,42 @@ impl<K, V, S: BuildHasher, M: Mode> HashMapAssertions<K, V>
     }
 }
 
+/// Data-extracting assertions for [`HashMap`]s.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait HashMapExtractAssertions<'t, K, V> {
+    /// Test that the map contains the given key and extract the value stored for it.
+    ///
+    /// Failures of assertions made on the extracted value name the key.
+    ///
+    /// Only available in `Panic` mode, as the extracted `V` cannot be produced when the key is
+    /// absent.
+    fn value_of(self, key: impl Borrow<K>) -> AssertThat<'t, V, Panic>
+    where
+        K: Eq + Hash + Debug,
+        V: Debug;
+}
+
+impl<'t, K, V, S: BuildHasher> HashMapExtractAssertions<'t, K, V>
+    for AssertThat<'t, HashMap<K, V, S>, Panic>
+{
+    #[track_caller]
+    fn value_of(self, key: impl Borrow<K>) -> AssertThat<'t, V, Panic>
+    where
+        K: Eq + Hash + Debug,
+        V: Debug,
+    {
+        let key = key.borrow();
+        let subject_name = format!("value at key {key:?}");
+
+        self.contains_key(key)
+            .map(|actual| match actual {
+                Actual::Owned(mut o) => Actual::Owned(o.remove(key).unwrap()),
+                Actual::Borrowed(b) => Actual::Borrowed(b.get(key).unwrap()),
+            })
+            .with_subject_name(subject_name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod contains_key {
@@ -650,6 +792,7 @@ mod tests {
 
     mod contains_exactly_entries {
         use crate::prelude::*;
+        use crate::{AssertrPartialEq, EqContext};
         use indoc::formatdoc;
         use std::collections::HashMap;
 
@@ -690,7 +833,7 @@ mod tests {
                     ]
 
                     Details: [
-                        Keys not found: [
+                        Missing keys: [
                             "baz",
                         ],
                     ]
@@ -754,8 +897,219 @@ mod tests {
                     ]
 
                     Details: [
-                        Keys with unexpected values: [
-                            "foo",
+                        Differing values: [
+                            "foo": expected "baz", but was "bar",
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn lists_a_differing_value_once_even_with_nested_differences() {
+            /// Records a difference of its own whenever it is compared unequal.
+            #[derive(Debug)]
+            struct Nested(u32);
+
+            impl AssertrPartialEq for Nested {
+                fn eq(&self, other: &Self, ctx: Option<&mut EqContext>) -> bool {
+                    if self.0 != other.0
+                        && let Some(ctx) = ctx
+                    {
+                        ctx.add_difference(String::from("nested difference"));
+                    }
+                    self.0 == other.0
+                }
+            }
+
+            assert_that_panic_by(|| {
+                assert_that!(HashMap::from([("foo", Nested(1))]))
+                    .with_location(false)
+                    .contains_exactly_entries([("foo", Nested(2))]);
+            })
+            .has_type::<String>()
+            .does_not_contain("nested difference")
+            .contains(formatdoc! {r#"
+                    Details: [
+                        Differing values: [
+                            "foo": expected Nested(
+                                2,
+                            ), but was Nested(
+                                1,
+                            ),
+                        ],
+                    ]
+                "#});
+        }
+    }
+
+    mod value_of {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::collections::HashMap;
+
+        #[test]
+        fn extracts_value_of_owned_and_borrowed_map() {
+            let map = HashMap::from([("foo", 1), ("bar", 2)]);
+            assert_that!(&map).value_of("foo").is_equal_to(1);
+            assert_that!(map).value_of("bar").is_equal_to(2);
+        }
+
+        #[test]
+        fn panics_when_key_is_absent() {
+            assert_that_panic_by(|| {
+                assert_that!(HashMap::from([("foo", 1)]))
+                    .with_location(false)
+                    .value_of("bar");
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: HashMap {{
+                        "foo": 1,
+                    }}
+
+                    does not contain expected key: "bar"
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn names_the_key_in_nested_failures() {
+            assert_that_panic_by(|| {
+                assert_that!(HashMap::from([("foo", 1)]))
+                    .with_location(false)
+                    .value_of("foo")
+                    .is_equal_to(2);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Subject: value at key "foo"
+
+                    Expected: 2
+
+                      Actual: 1
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod keys {
+        use crate::prelude::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn extracts_all_keys() {
+            assert_that!(HashMap::from([("foo", 1), ("bar", 2)]))
+                .keys()
+                .contains_exactly_in_any_order(["bar", "foo"]);
+        }
+    }
+
+    mod values {
+        use crate::prelude::*;
+        use std::collections::HashMap;
+
+        #[test]
+        fn extracts_all_values() {
+            let map = HashMap::from([("foo", 1), ("bar", 2)]);
+            assert_that!(&map)
+                .values()
+                .contains_exactly_in_any_order([1, 2]);
+        }
+    }
+
+    mod all_entries_satisfy {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::collections::HashMap;
+
+        #[test]
+        fn succeeds_when_all_entries_satisfy_the_assertions() {
+            assert_that!(HashMap::from([("foo", 1), ("bar", 2)])).all_entries_satisfy(
+                |key, value| {
+                    value.is_less_than(&(key.len() as i32));
+                },
+            );
+        }
+
+        #[test]
+        fn succeeds_on_empty_map() {
+            assert_that!(HashMap::<&str, i32>::new()).all_entries_satisfy(|_key, value| {
+                value.is_greater_than(&0);
+            });
+        }
+
+        #[test]
+        fn panics_naming_the_key_of_the_offending_value() {
+            assert_that_panic_by(|| {
+                assert_that!(HashMap::from([("foo", 1)]))
+                    .with_location(false)
+                    .all_entries_satisfy(|_key, value| {
+                        value.is_greater_than(&1);
+                    });
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Subject: value at key "foo"
+
+                    Actual: 1
+
+                    is not greater than
+
+                    Expected: 1
+                    -------- assertr --------
+                "#});
+        }
+
+        #[test]
+        fn captures_one_failure_per_offending_entry() {
+            assert_that!(HashMap::from([("foo", 1), ("bar", 2), ("baz", 3)]))
+                .with_capture()
+                .all_entries_satisfy(|_key, value| {
+                    value.is_greater_than(&2);
+                })
+                .capture_failures()
+                .must()
+                .have_length(2);
+        }
+    }
+
+    mod contains_entries_matching {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::collections::HashMap;
+
+        #[test]
+        fn succeeds_when_an_entry_matches_both_predicates() {
+            assert_that!(HashMap::from([("foo", 1), ("bar", 2)]))
+                .contains_entries_matching(|key| key.starts_with('b'), |value| *value == 2);
+        }
+
+        #[test]
+        fn panics_listing_entries_whose_key_matched() {
+            assert_that_panic_by(|| {
+                assert_that!(HashMap::from([("foo", 1)]))
+                    .with_location(false)
+                    .contains_entries_matching(|key| *key == "foo", |value| *value > 1);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: HashMap {{
+                        "foo": 1,
+                    }}
+
+                    does not contain an entry matching the given key and value predicates
+
+                    Details: [
+                        Entries with matching key but non-matching value: [
+                            (
+                                "foo",
+                                1,
+                            ),
                         ],
                     ]
                     -------- assertr --------
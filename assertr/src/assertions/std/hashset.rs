@@ -8,7 +8,11 @@ use std::{
     hash::{BuildHasher, Hash},
 };
 
-use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+use crate::{
+    AssertThat, AssertrPartialEq, EqContext, Mode,
+    assertions::core::partial_eq::fail_with_differences, cmp, tracking::AssertionTracking,
+    util::collection_diff::CollectionDiff,
+};
 
 /// Assertions for generic [`HashSet`]s.
 #[allow(clippy::return_self_not_must_use)]
@@ -30,6 +34,15 @@ pub trait HashSetAssertions<T> {
         E: Debug,
         I: IntoIterator<Item = E>;
 
+    /// Test that the set contains exactly the expected elements.
+    ///
+    /// Failures report missing and unexpected elements separately.
+    fn contains_exactly<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>;
+
     fn is_subset_of<S2>(self, expected_superset: impl Borrow<HashSet<T, S2>>) -> Self
     where
         T: Eq + Hash + Debug,
@@ -54,6 +67,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if !self
             .actual()
@@ -78,6 +94,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         E: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         if self
             .actual()
@@ -103,6 +122,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         I: IntoIterator<Item = E>,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected = expected.into_iter().collect::<Vec<_>>();
         let elements_not_found = expected
@@ -131,6 +153,57 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         self
     }
 
+    #[track_caller]
+    fn contains_exactly<E, I>(self, expected: I) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+        I: IntoIterator<Item = E>,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        let mut diff = CollectionDiff::for_set();
+
+        for expected_element in &expected {
+            if !self
+                .actual()
+                .iter()
+                .any(|actual| AssertrPartialEq::eq(actual, expected_element, None))
+            {
+                diff.missing.push(expected_element);
+            }
+        }
+        for actual_element in self.actual() {
+            if !expected
+                .iter()
+                .any(|expected| AssertrPartialEq::eq(actual_element, expected, None))
+            {
+                diff.unexpected.push(actual_element);
+            }
+        }
+
+        if !diff.is_empty() {
+            for message in diff.messages() {
+                self.add_detail_message(message);
+            }
+
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Actual: HashSet {actual:#?}
+
+                    does not exactly contain expected elements
+
+                    Expected: {expected:#?}
+                ", actual = self.actual()}
+            });
+        }
+        self
+    }
+
     #[track_caller]
     fn is_subset_of<S2>(self, expected_superset: impl Borrow<HashSet<T, S2>>) -> Self
     where
@@ -138,6 +211,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         S2: BuildHasher,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected_superset = expected_superset.borrow();
         let elements_not_in_expected = self
@@ -169,6 +245,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         S2: BuildHasher,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let expected_subset = expected_subset.borrow();
         let elements_not_in_actual = expected_subset
@@ -199,6 +278,9 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
         S2: BuildHasher,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
 
         let other = other.borrow();
         let overlapping_elements = self
@@ -224,6 +306,46 @@ impl<T, S: BuildHasher, M: Mode> HashSetAssertions<T> for AssertThat<'_, HashSet
     }
 }
 
+/// Equality assertions for [`HashSet`]s, taking precedence over
+/// [`PartialEqAssertions::is_equal_to`](crate::prelude::PartialEqAssertions::is_equal_to).
+impl<T, S: BuildHasher, M: Mode> AssertThat<'_, HashSet<T, S>, M> {
+    /// Test that the set is equal to `expected`.
+    ///
+    /// Failures list the missing and unexpected elements, in addition to both sets.
+    #[track_caller]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn is_equal_to<S2>(self, expected: impl Borrow<HashSet<T, S2>>) -> Self
+    where
+        T: Eq + Hash + Debug,
+        S2: BuildHasher,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+
+        let expected = expected.borrow();
+        let mut ctx = EqContext::default();
+
+        if !cmp::hashset::compare(self.actual(), expected, Some(&mut ctx)) {
+            fail_with_differences(&self, expected, &ctx);
+        }
+        self
+    }
+
+    /// Fluent alias for [`is_equal_to`](Self::is_equal_to).
+    #[cfg(feature = "fluent")]
+    #[track_caller]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn be_equal_to<S2>(self, expected: impl Borrow<HashSet<T, S2>>) -> Self
+    where
+        T: Eq + Hash + Debug,
+        S2: BuildHasher,
+    {
+        self.is_equal_to(expected)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod contains {
@@ -345,6 +467,111 @@ mod tests {
         }
     }
 
+    mod contains_exactly {
+        use std::collections::HashSet;
+
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_elements_match_in_any_order() {
+            assert_that!(HashSet::from(["foo", "bar"])).contains_exactly(["bar", "foo"]);
+        }
+
+        #[test]
+        fn panics_with_missing_and_unexpected_elements() {
+            assert_that_panic_by(|| {
+                assert_that!(HashSet::from(["foo"]))
+                    .with_location(false)
+                    .contains_exactly(["bar"]);
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Actual: HashSet {{
+                        "foo",
+                    }}
+
+                    does not exactly contain expected elements
+
+                    Expected: [
+                        "bar",
+                    ]
+
+                    Details: [
+                        Missing elements: [
+                            "bar",
+                        ],
+                        Unexpected elements: [
+                            "foo",
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
+    mod is_equal_to {
+        use std::collections::HashSet;
+
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[test]
+        fn succeeds_when_equal() {
+            assert_that!(HashSet::from(["foo", "bar"])).is_equal_to(HashSet::from(["bar", "foo"]));
+        }
+
+        #[test]
+        fn accepts_a_borrowed_set() {
+            let expected = HashSet::from(["foo"]);
+            assert_that!(HashSet::from(["foo"])).is_equal_to(&expected);
+        }
+
+        #[cfg(feature = "fluent")]
+        #[test]
+        fn lists_missing_and_unexpected_elements_through_the_fluent_alias() {
+            let failures = HashSet::from(["foo"])
+                .verify()
+                .be_equal_to(HashSet::from(["foo", "bar"]))
+                .capture_failures();
+            assert_that!(&failures).has_length(1);
+            assert_that!(&failures[0]).contains("Missing elements");
+        }
+
+        #[test]
+        fn panics_with_missing_and_unexpected_elements() {
+            assert_that_panic_by(|| {
+                assert_that!(HashSet::from(["foo"]))
+                    .with_location(false)
+                    .is_equal_to(HashSet::from(["bar"]));
+            })
+            .has_type::<String>()
+            .is_equal_to(formatdoc! {r#"
+                    -------- assertr --------
+                    Expected: {{
+                        "bar",
+                    }}
+
+                      Actual: {{
+                        "foo",
+                    }}
+
+                    Details: [
+                        Differences: [
+                            Missing elements: [
+                                "bar",
+                            ],
+                            Unexpected elements: [
+                                "foo",
+                            ],
+                        ],
+                    ]
+                    -------- assertr --------
+                "#});
+        }
+    }
+
     mod is_subset_of {
         use std::collections::{HashSet, hash_map::RandomState};
         use std::hash::{BuildHasherDefault, DefaultHasher};
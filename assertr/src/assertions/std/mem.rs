@@ -19,6 +19,9 @@ impl<T, M: Mode> MemAssertions for AssertThat<'_, Type<T>, M> {
     #[track_caller]
     fn needs_drop(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.needs_drop() {
             self.fail(|w: &mut String| {
@@ -4,12 +4,14 @@ pub mod hashset;
 pub mod mem;
 pub mod mutex;
 pub mod path;
+pub mod poison;
 
 pub mod prelude {
     pub use super::command::CommandAssertions;
-    pub use super::hashmap::HashMapAssertions;
+    pub use super::hashmap::{HashMapAssertions, HashMapExtractAssertions};
     pub use super::hashset::HashSetAssertions;
     pub use super::mem::MemAssertions;
     pub use super::mutex::MutexAssertions;
     pub use super::path::PathAssertions;
+    pub use super::poison::PoisonAssertions;
 }
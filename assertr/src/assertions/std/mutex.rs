@@ -33,6 +33,9 @@ impl<T: Debug, M: Mode> MutexAssertions for AssertThat<'_, Mutex<T>, M> {
     #[track_caller]
     fn is_locked(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if let Ok(guard) = actual.try_lock() {
             self.fail(|w: &mut String| {
@@ -49,6 +52,9 @@ impl<T: Debug, M: Mode> MutexAssertions for AssertThat<'_, Mutex<T>, M> {
     #[track_caller]
     fn is_not_locked(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if let Err(_err) = actual.try_lock() {
             self.fail(|w: &mut String| {
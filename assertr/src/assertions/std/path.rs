@@ -109,6 +109,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn exists(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         match actual.try_exists() {
             Ok(true) => {}
@@ -139,6 +142,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn does_not_exist(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
 
         match actual.try_exists() {
@@ -160,6 +166,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn is_a_file(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_file() {
             let exists = actual.exists();
@@ -181,6 +190,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn is_a_directory(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_dir() {
             let exists = actual.exists();
@@ -202,6 +214,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn is_a_symlink(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_symlink() {
             self.fail(|w: &mut String| {
@@ -218,6 +233,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn has_a_root(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.has_root() {
             self.fail(|w: &mut String| {
@@ -234,6 +252,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn is_relative(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if !actual.is_relative() {
             self.fail(|w: &mut String| {
@@ -250,6 +271,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn has_file_name(self, expected: impl AsRef<OsStr>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let actual_file_name = actual.file_name();
         let expected_file_name = expected.as_ref();
@@ -271,6 +295,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn has_file_stem(self, expected: impl AsRef<OsStr>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let actual_file_stem = actual.file_stem();
         let expected_file_stem = expected.as_ref();
@@ -292,6 +319,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn has_extension(self, expected: impl AsRef<OsStr>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let actual_extension = actual.extension();
         let expected_extension = expected.as_ref();
@@ -313,6 +343,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn starts_with(self, expected: impl AsRef<Path>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected_prefix = expected.as_ref();
         if !actual.starts_with(expected_prefix) {
@@ -331,6 +364,9 @@ impl<M: Mode> PathAssertions for AssertThat<'_, &Path, M> {
     #[track_caller]
     fn ends_with(self, expected: impl AsRef<Path>) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         let expected_postfix = expected.as_ref();
         if !actual.ends_with(expected_postfix) {
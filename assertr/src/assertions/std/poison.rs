@@ -0,0 +1,220 @@
+use core::fmt::Write;
+use indoc::writedoc;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use crate::{AssertThat, Mode, actual::Actual, tracking::AssertionTracking};
+
+/// A std sync primitive which is poisoned when a thread panics while holding its lock.
+pub trait Poisonable<T> {
+    /// Names the primitive in failure messages.
+    const NAME: &'static str;
+
+    fn is_poisoned(&self) -> bool;
+
+    /// Consumes the primitive, returning its value even if it is poisoned.
+    fn into_inner_ignoring_poison(self) -> T;
+}
+
+impl<T> Poisonable<T> for Mutex<T> {
+    const NAME: &'static str = "Mutex";
+
+    fn is_poisoned(&self) -> bool {
+        Mutex::is_poisoned(self)
+    }
+
+    fn into_inner_ignoring_poison(self) -> T {
+        self.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Poisonable<T> for RwLock<T> {
+    const NAME: &'static str = "RwLock";
+
+    fn is_poisoned(&self) -> bool {
+        RwLock::is_poisoned(self)
+    }
+
+    fn into_inner_ignoring_poison(self) -> T {
+        self.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Poisoning assertions for std's `Mutex` and `RwLock` types, e.g. for testing recovery after a
+/// worker panicked while holding a lock.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait PoisonAssertions<'t, T, M: Mode> {
+    fn is_poisoned(self) -> Self;
+
+    #[cfg_attr(feature = "fluent", fluent_alias("not_be_poisoned"))]
+    fn is_not_poisoned(self) -> Self;
+
+    /// Changes the subject to the value of the primitive, regardless of it being poisoned.
+    ///
+    /// Can only be called on owned subjects.
+    fn into_inner_ignoring_poison(self) -> AssertThat<'t, T, M>;
+}
+
+impl<'t, T, P: Poisonable<T>, M: Mode> PoisonAssertions<'t, T, M> for AssertThat<'t, P, M> {
+    #[track_caller]
+    fn is_poisoned(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().is_poisoned() {
+            let name = P::NAME;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {name} to be poisoned
+
+                      Actual: {name} is not poisoned
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_not_poisoned(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if self.actual().is_poisoned() {
+            let name = P::NAME;
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: {name} to not be poisoned
+
+                      Actual: {name} is poisoned
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn into_inner_ignoring_poison(self) -> AssertThat<'t, T, M> {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self.poison();
+        }
+        self.map(|it| match it {
+            Actual::Borrowed(_) => panic!(
+                "into_inner_ignoring_poison() can only be called on an owned {}!",
+                P::NAME
+            ),
+            Actual::Owned(primitive) => Actual::Owned(primitive.into_inner_ignoring_poison()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::{Mutex, RwLock};
+
+    fn poisoned_mutex(value: u32) -> Mutex<u32> {
+        let mutex = Mutex::new(value);
+        assert_that_panic_by(|| {
+            let _guard = mutex.lock().unwrap();
+            panic!("worker failed");
+        })
+        .has_message("worker failed");
+        mutex
+    }
+
+    fn poisoned_rw_lock(value: u32) -> RwLock<u32> {
+        let lock = RwLock::new(value);
+        assert_that_panic_by(|| {
+            let _guard = lock.write().unwrap();
+            panic!("worker failed");
+        })
+        .has_message("worker failed");
+        lock
+    }
+
+    mod is_poisoned {
+        use super::{poisoned_mutex, poisoned_rw_lock};
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::sync::Mutex;
+
+        #[test]
+        fn succeeds_when_poisoned() {
+            assert_that!(poisoned_mutex(42)).is_poisoned();
+            assert_that!(poisoned_rw_lock(42)).is_poisoned();
+        }
+
+        #[test]
+        fn panics_when_not_poisoned() {
+            assert_that_panic_by(|| {
+                assert_that!(Mutex::new(42))
+                    .with_location(false)
+                    .is_poisoned()
+            })
+            .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected: Mutex to be poisoned
+
+                      Actual: Mutex is not poisoned
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod is_not_poisoned {
+        use super::poisoned_rw_lock;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use std::sync::RwLock;
+
+        #[test]
+        fn succeeds_when_not_poisoned() {
+            assert_that!(RwLock::new(42)).is_not_poisoned();
+        }
+
+        #[test]
+        fn captures_failure_when_poisoned() {
+            let failures = assert_that!(poisoned_rw_lock(42))
+                .with_capture()
+                .with_location(false)
+                .is_not_poisoned()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected: RwLock to not be poisoned
+
+                  Actual: RwLock is poisoned
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod into_inner_ignoring_poison {
+        use super::{poisoned_mutex, poisoned_rw_lock};
+        use crate::prelude::*;
+        use std::sync::Mutex;
+
+        #[test]
+        fn extracts_value_of_poisoned_primitives() {
+            assert_that!(poisoned_mutex(42))
+                .is_poisoned()
+                .into_inner_ignoring_poison()
+                .is_equal_to(42);
+            assert_that!(poisoned_rw_lock(42))
+                .into_inner_ignoring_poison()
+                .is_equal_to(42);
+        }
+
+        #[test]
+        fn extracts_value_of_healthy_primitives() {
+            assert_that!(Mutex::new(42))
+                .into_inner_ignoring_poison()
+                .is_equal_to(42);
+        }
+    }
+}
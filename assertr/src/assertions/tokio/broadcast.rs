@@ -0,0 +1,183 @@
+use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+use alloc::string::String;
+use core::borrow::BorrowMut;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+use tokio::sync::broadcast::Receiver;
+use tokio::sync::broadcast::error::TryRecvError;
+
+use super::receiver_mut;
+
+/// Assertions for tokio's broadcast `Receiver` type.
+/// These work in any mode (Panic or Capture).
+///
+/// The receiver must be owned or mutably borrowed, e.g. `assert_that!(&mut rx)`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioBroadcastReceiverAssertions<T> {
+    /// Receive the next message using `try_recv`, testing that it equals `expected`.
+    fn has_received<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    /// Receive the next message using `try_recv`, testing that the receiver lagged behind,
+    /// missing messages overwritten in the meantime.
+    ///
+    /// The receiver continues with the oldest message still retained by the channel.
+    fn has_lagged(self) -> Self
+    where
+        T: Debug;
+}
+
+impl<T: Clone, R: BorrowMut<Receiver<T>>, M: Mode> TokioBroadcastReceiverAssertions<T>
+    for AssertThat<'_, R, M>
+{
+    #[track_caller]
+    fn has_received<E>(mut self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let Some(receiver) = receiver_mut(&mut self, "has_received") else {
+            return self;
+        };
+        let actual = receiver.borrow_mut().try_recv();
+        match actual {
+            Ok(actual) if AssertrPartialEq::eq(&actual, &expected, None) => {}
+            Ok(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next message: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            }),
+            Err(err) => self.fail(|w: &mut String| {
+                let actual = describe_error(&err);
+                writedoc! {w, r"
+                    Expected next message: {expected:#?}
+
+                      Actual: {actual}
+                "}
+            }),
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_lagged(mut self) -> Self
+    where
+        T: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let Some(receiver) = receiver_mut(&mut self, "has_lagged") else {
+            return self;
+        };
+        let actual = receiver.borrow_mut().try_recv();
+        match actual {
+            Err(TryRecvError::Lagged(_skipped)) => {}
+            Ok(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Receiver to have lagged
+
+                      Actual: Received {actual:#?}
+                "}
+            }),
+            Err(err) => self.fail(|w: &mut String| {
+                let actual = describe_error(&err);
+                writedoc! {w, r"
+                    Expected: Receiver to have lagged
+
+                      Actual: {actual}
+                "}
+            }),
+        }
+        self
+    }
+}
+
+fn describe_error(err: &TryRecvError) -> String {
+    match err {
+        TryRecvError::Empty => String::from("No message was pending"),
+        TryRecvError::Closed => String::from("Channel is closed and empty"),
+        TryRecvError::Lagged(skipped) => {
+            alloc::format!("Receiver lagged, skipping {skipped} messages")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_received {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::broadcast;
+
+        #[test]
+        fn receives_messages_in_order() {
+            let (tx, mut rx) = broadcast::channel(4);
+            tx.send(1).unwrap();
+            tx.send(2).unwrap();
+
+            assert_that!(&mut rx).has_received(1).has_received(2);
+        }
+
+        #[test]
+        fn panics_when_receiver_lagged() {
+            let (tx, mut rx) = broadcast::channel(1);
+            tx.send(1).unwrap();
+            tx.send(2).unwrap();
+
+            assert_that_panic_by(|| assert_that!(&mut rx).with_location(false).has_received(1))
+                .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected next message: 1
+
+                  Actual: Receiver lagged, skipping 1 messages
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod has_lagged {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::broadcast;
+
+        #[test]
+        fn succeeds_when_messages_were_overwritten() {
+            let (tx, rx) = broadcast::channel(1);
+            tx.send(1).unwrap();
+            tx.send(2).unwrap();
+
+            assert_that!(rx).has_lagged().has_received(2);
+        }
+
+        #[test]
+        fn captures_failure_when_message_was_received() {
+            let (tx, rx) = broadcast::channel(4);
+            tx.send(1).unwrap();
+
+            let failures = assert_that!(rx)
+                .with_capture()
+                .with_location(false)
+                .has_lagged()
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected: Receiver to have lagged
+
+                  Actual: Received 1
+                -------- assertr --------
+            "}]);
+        }
+    }
+}
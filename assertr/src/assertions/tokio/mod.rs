@@ -1,10 +1,53 @@
+use crate::actual::Actual;
+use crate::{AssertThat, Mode};
+use alloc::string::String;
+use core::fmt::Write;
+use indoc::writedoc;
+
+pub mod broadcast;
+pub mod mpsc;
 pub mod mutex;
+pub mod oneshot;
 pub mod rw_lock;
+pub mod task;
 pub mod watch;
 
 pub mod prelude {
+    pub use super::broadcast::TokioBroadcastReceiverAssertions;
+    pub use super::mpsc::TokioMpscReceiverAssertions;
+    pub use super::mpsc::TokioMpscSenderAssertions;
+    pub use super::mpsc::TokioMpscUnboundedSenderAssertions;
     pub use super::mutex::TokioMutexAssertions;
+    pub use super::oneshot::TokioOneshotReceiverAssertions;
     pub use super::rw_lock::TokioRwLockAssertions;
+    pub use super::task::TokioJoinHandleAssertions;
+    pub use super::task::TokioJoinHandleExtractAssertions;
+    pub use super::task::TokioJoinSetExtractAssertions;
     pub use super::watch::TokioWatchReceiverAssertions;
     pub use super::watch::TokioWatchReceiverExtractAssertions;
 }
+
+/// Receiving requires mutable access, which only owned subjects, including mutable references,
+/// provide. Fails the assertion and returns `None` for shared references.
+/// Returns `None` for poisoned subjects as well.
+#[track_caller]
+fn receiver_mut<'a, R, M: Mode>(
+    this: &'a mut AssertThat<'_, R, M>,
+    method: &str,
+) -> Option<&'a mut R> {
+    match this.actual {
+        Some(Actual::Borrowed(_)) => {
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    {method}() can only be called on an owned or mutably borrowed receiver!
+
+                    Use `assert_that!(&mut receiver)`.
+                "}
+            });
+            None
+        }
+        Some(Actual::Owned(ref mut receiver)) => Some(receiver),
+        // A poisoned subject has nothing to receive from. Its failure is already recorded.
+        None => None,
+    }
+}
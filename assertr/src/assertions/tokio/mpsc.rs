@@ -0,0 +1,478 @@
+use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
+
+use super::receiver_mut;
+
+mod sealed {
+    use tokio::sync::mpsc::{Receiver, UnboundedReceiver};
+
+    pub trait Sealed {}
+
+    impl<T> Sealed for Receiver<T> {}
+    impl<T> Sealed for UnboundedReceiver<T> {}
+    impl<R: Sealed> Sealed for &mut R {}
+}
+
+/// Abstracts over tokio's bounded and unbounded mpsc receivers, as well as mutable references
+/// to them.
+///
+/// Takes the place of the `BorrowMut<Receiver<T>>` bound used for oneshot and broadcast
+/// receivers, as one such bound cannot cover both receiver types. This trait is sealed.
+pub trait MpscReceiver<T>: sealed::Sealed {
+    /// Receives the next message without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TryRecvError` if no message is pending.
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    /// The number of messages buffered in the channel.
+    fn pending_messages(&self) -> usize;
+
+    fn is_closed(&self) -> bool;
+}
+
+impl<T> MpscReceiver<T> for Receiver<T> {
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        Receiver::try_recv(self)
+    }
+
+    fn pending_messages(&self) -> usize {
+        Receiver::len(self)
+    }
+
+    fn is_closed(&self) -> bool {
+        Receiver::is_closed(self)
+    }
+}
+
+impl<T> MpscReceiver<T> for UnboundedReceiver<T> {
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        UnboundedReceiver::try_recv(self)
+    }
+
+    fn pending_messages(&self) -> usize {
+        UnboundedReceiver::len(self)
+    }
+
+    fn is_closed(&self) -> bool {
+        UnboundedReceiver::is_closed(self)
+    }
+}
+
+impl<T, R: MpscReceiver<T>> MpscReceiver<T> for &mut R {
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        (**self).try_recv()
+    }
+
+    fn pending_messages(&self) -> usize {
+        (**self).pending_messages()
+    }
+
+    fn is_closed(&self) -> bool {
+        (**self).is_closed()
+    }
+}
+
+/// Assertions for tokio's mpsc `Receiver` and `UnboundedReceiver` types.
+/// These work in any mode (Panic or Capture).
+///
+/// Assertions receiving messages require the receiver to be owned or mutably borrowed, e.g.
+/// `assert_that!(&mut rx)`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioMpscReceiverAssertions<T> {
+    /// Test that exactly `expected` messages are buffered in the channel.
+    fn has_pending_messages(self, expected: usize) -> Self;
+
+    /// Receive the next message using `try_recv`, testing that it equals `expected`.
+    #[cfg_attr(feature = "fluent", fluent_alias("have_next_message"))]
+    fn next_message_is<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+
+    /// Test that the channel is closed, which is the case when all senders were dropped or
+    /// `close` was called.
+    fn is_closed(self) -> Self;
+}
+
+impl<T, R: MpscReceiver<T>, M: Mode> TokioMpscReceiverAssertions<T> for AssertThat<'_, R, M> {
+    #[track_caller]
+    fn has_pending_messages(self, expected: usize) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().pending_messages();
+        if actual != expected {
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected pending messages: {expected}
+
+                      Actual: {actual}
+                "}
+            });
+        }
+        self
+    }
+
+    #[track_caller]
+    fn next_message_is<E>(mut self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let Some(receiver) = receiver_mut(&mut self, "next_message_is") else {
+            return self;
+        };
+        let actual = receiver.try_recv();
+        match actual {
+            Ok(actual) if AssertrPartialEq::eq(&actual, &expected, None) => {}
+            Ok(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next message: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            }),
+            Err(TryRecvError::Empty) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next message: {expected:#?}
+
+                      Actual: No message was pending
+                "}
+            }),
+            Err(TryRecvError::Disconnected) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected next message: {expected:#?}
+
+                      Actual: Channel is closed and empty
+                "}
+            }),
+        }
+        self
+    }
+
+    #[track_caller]
+    fn is_closed(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().is_closed() {
+            let pending = self.actual().pending_messages();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Channel to be closed
+
+                      Actual: Channel is open, with {pending} pending messages
+                "}
+            });
+        }
+        self
+    }
+}
+
+/// Assertions for tokio's mpsc `Sender` type.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioMpscSenderAssertions {
+    /// Test that the channel is closed, which is the case when the receiver was dropped or
+    /// `close` was called on it.
+    fn is_closed(self) -> Self;
+
+    /// Test that exactly `expected` messages can currently be sent without waiting.
+    fn has_capacity(self, expected: usize) -> Self;
+}
+
+impl<T, M: Mode> TokioMpscSenderAssertions for AssertThat<'_, Sender<T>, M> {
+    #[track_caller]
+    fn is_closed(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().is_closed() {
+            self.fail("Expected: Channel to be closed\n\n  Actual: Channel is open\n");
+        }
+        self
+    }
+
+    #[track_caller]
+    fn has_capacity(self, expected: usize) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let actual = self.actual().capacity();
+        if actual != expected {
+            let max = self.actual().max_capacity();
+            self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected capacity: {expected}
+
+                      Actual: {actual} (of {max})
+                "}
+            });
+        }
+        self
+    }
+}
+
+/// Assertions for tokio's mpsc `UnboundedSender` type.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioMpscUnboundedSenderAssertions {
+    /// Test that the channel is closed, which is the case when the receiver was dropped or
+    /// `close` was called on it.
+    fn is_closed(self) -> Self;
+}
+
+impl<T, M: Mode> TokioMpscUnboundedSenderAssertions for AssertThat<'_, UnboundedSender<T>, M> {
+    #[track_caller]
+    fn is_closed(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().is_closed() {
+            self.fail("Expected: Channel to be closed\n\n  Actual: Channel is open\n");
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod has_pending_messages {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn succeeds_when_count_matches() {
+            let (tx, rx) = mpsc::channel(8);
+            tx.send(1).await.unwrap();
+            tx.send(2).await.unwrap();
+
+            assert_that!(&rx).has_pending_messages(2);
+        }
+
+        #[test]
+        fn panics_when_count_differs() {
+            let (tx, rx) = mpsc::unbounded_channel();
+            tx.send(1).unwrap();
+
+            assert_that_panic_by(|| {
+                assert_that!(&rx)
+                    .with_location(false)
+                    .has_pending_messages(2)
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected pending messages: 2
+
+                  Actual: 1
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod next_message_is {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn receives_messages_in_order() {
+            let (tx, mut rx) = mpsc::channel(8);
+            tx.send("a").await.unwrap();
+            tx.send("b").await.unwrap();
+
+            assert_that!(&mut rx)
+                .next_message_is("a")
+                .next_message_is("b")
+                .has_pending_messages(0);
+        }
+
+        #[test]
+        fn works_on_owned_unbounded_receivers() {
+            let (tx, rx) = mpsc::unbounded_channel();
+            tx.send(42).unwrap();
+            drop(tx);
+
+            assert_that!(rx).next_message_is(42).is_closed();
+        }
+
+        #[test]
+        fn captures_failure_when_receiver_is_only_borrowed() {
+            let (tx, rx) = mpsc::unbounded_channel();
+            tx.send(42).unwrap();
+
+            let failures = assert_that!(&rx)
+                .with_capture()
+                .with_location(false)
+                .next_message_is(42)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                next_message_is() can only be called on an owned or mutably borrowed receiver!
+
+                Use `assert_that!(&mut receiver)`.
+                -------- assertr --------
+            "}]);
+            assert_that!(&rx).has_pending_messages(1);
+        }
+
+        #[test]
+        fn panics_when_message_differs() {
+            let (tx, mut rx) = mpsc::unbounded_channel();
+            tx.send(1).unwrap();
+
+            assert_that_panic_by(|| {
+                assert_that!(&mut rx)
+                    .with_location(false)
+                    .next_message_is(2)
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected next message: 2
+
+                  Actual: 1
+                -------- assertr --------
+            "});
+        }
+
+        #[test]
+        fn captures_failures_when_no_message_is_pending() {
+            let (tx, mut rx) = mpsc::unbounded_channel::<u32>();
+
+            let failures = assert_that!(&mut rx)
+                .with_capture()
+                .with_location(false)
+                .next_message_is(1)
+                .capture_failures();
+            drop(tx);
+            let closed_failures = assert_that!(&mut rx)
+                .with_capture()
+                .with_location(false)
+                .next_message_is(1)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected next message: 1
+
+                  Actual: No message was pending
+                -------- assertr --------
+            "}]);
+            assert_that!(closed_failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected next message: 1
+
+                  Actual: Channel is closed and empty
+                -------- assertr --------
+            "}]);
+        }
+    }
+
+    mod receiver_is_closed {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::mpsc;
+
+        #[test]
+        fn succeeds_after_close() {
+            let (_tx, mut rx) = mpsc::channel::<u32>(8);
+            rx.close();
+
+            assert_that!(&rx).is_closed();
+        }
+
+        #[tokio::test]
+        async fn panics_when_open() {
+            let (tx, rx) = mpsc::channel(8);
+            tx.send(1).await.unwrap();
+
+            assert_that_panic_by(|| assert_that!(&rx).with_location(false).is_closed())
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected: Channel to be closed
+
+                      Actual: Channel is open, with 1 pending messages
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod sender_is_closed {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::mpsc;
+
+        #[test]
+        fn succeeds_when_receiver_was_dropped() {
+            let (tx, rx) = mpsc::channel::<u32>(8);
+            let (unbounded_tx, unbounded_rx) = mpsc::unbounded_channel::<u32>();
+            drop(rx);
+            drop(unbounded_rx);
+
+            assert_that!(&tx).is_closed();
+            assert_that!(&unbounded_tx).is_closed();
+        }
+
+        #[test]
+        fn panics_when_open() {
+            let (tx, _rx) = mpsc::unbounded_channel::<u32>();
+
+            assert_that_panic_by(|| assert_that!(&tx).with_location(false).is_closed())
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected: Channel to be closed
+
+                      Actual: Channel is open
+                    -------- assertr --------
+                "});
+        }
+    }
+
+    mod has_capacity {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn succeeds_when_capacity_matches() {
+            let (tx, _rx) = mpsc::channel(4);
+            tx.send(1).await.unwrap();
+
+            assert_that!(&tx).has_capacity(3);
+        }
+
+        #[test]
+        fn panics_when_capacity_differs() {
+            let (tx, _rx) = mpsc::channel::<u32>(4);
+
+            assert_that_panic_by(|| assert_that!(&tx).with_location(false).has_capacity(3))
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected capacity: 3
+
+                      Actual: 4 (of 4)
+                    -------- assertr --------
+                "});
+        }
+    }
+}
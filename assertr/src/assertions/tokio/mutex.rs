@@ -4,7 +4,6 @@ use indoc::writedoc;
 use std::fmt::Write;
 use tokio::sync::Mutex;
 
-// TODO: Add possibility to easily assert on the contained value (when the lock can be acquired).
 /// Assertions for tokio's [Mutex] type.
 #[allow(clippy::return_self_not_must_use)]
 #[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
@@ -26,6 +25,9 @@ impl<T: Debug, M: Mode> TokioMutexAssertions<T> for AssertThat<'_, Mutex<T>, M>
     #[track_caller]
     fn is_locked(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if let Ok(guard) = actual.try_lock() {
             self.fail(|w: &mut String| {
@@ -42,6 +44,9 @@ impl<T: Debug, M: Mode> TokioMutexAssertions<T> for AssertThat<'_, Mutex<T>, M>
     #[track_caller]
     fn is_not_locked(self) -> Self {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         let actual = self.actual();
         if let Err(_err) = actual.try_lock() {
             self.fail(|w: &mut String| {
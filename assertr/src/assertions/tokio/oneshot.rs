@@ -0,0 +1,119 @@
+use crate::{AssertThat, AssertrPartialEq, Mode, tracking::AssertionTracking};
+use alloc::string::String;
+use core::borrow::BorrowMut;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+use tokio::sync::oneshot::Receiver;
+use tokio::sync::oneshot::error::TryRecvError;
+
+use super::receiver_mut;
+
+/// Assertions for tokio's oneshot `Receiver` type.
+/// These work in any mode (Panic or Capture).
+///
+/// The receiver must be owned or mutably borrowed, e.g. `assert_that!(&mut rx)`.
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioOneshotReceiverAssertions<T> {
+    /// Receive the value using `try_recv`, testing that it was sent and equals `expected`.
+    fn is_ready_with<E>(self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug;
+}
+
+impl<T, R: BorrowMut<Receiver<T>>, M: Mode> TokioOneshotReceiverAssertions<T>
+    for AssertThat<'_, R, M>
+{
+    #[track_caller]
+    fn is_ready_with<E>(mut self, expected: E) -> Self
+    where
+        T: AssertrPartialEq<E> + Debug,
+        E: Debug,
+    {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        let Some(receiver) = receiver_mut(&mut self, "is_ready_with") else {
+            return self;
+        };
+        let actual = receiver.borrow_mut().try_recv();
+        match actual {
+            Ok(actual) if AssertrPartialEq::eq(&actual, &expected, None) => {}
+            Ok(actual) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            }),
+            Err(TryRecvError::Empty) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value: {expected:#?}
+
+                      Actual: No value was sent yet
+                "}
+            }),
+            Err(TryRecvError::Closed) => self.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected value: {expected:#?}
+
+                      Actual: Sender was dropped without sending a value, or the value was already received
+                "}
+            }),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod is_ready_with {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+        use tokio::sync::oneshot;
+
+        #[test]
+        fn succeeds_when_sent_value_is_equal() {
+            let (tx, mut rx) = oneshot::channel();
+            tx.send(String::from("done")).unwrap();
+
+            assert_that!(&mut rx).is_ready_with("done");
+        }
+
+        #[test]
+        fn panics_when_nothing_was_sent() {
+            let (_tx, rx) = oneshot::channel::<u32>();
+
+            assert_that_panic_by(|| assert_that!(rx).with_location(false).is_ready_with(42))
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected value: 42
+
+                      Actual: No value was sent yet
+                    -------- assertr --------
+                "});
+        }
+
+        #[test]
+        fn captures_failure_when_sender_was_dropped() {
+            let (tx, rx) = oneshot::channel::<u32>();
+            drop(tx);
+
+            let failures = assert_that!(rx)
+                .with_capture()
+                .with_location(false)
+                .is_ready_with(42)
+                .capture_failures();
+
+            assert_that!(failures).contains_exactly::<String>([formatdoc! {"
+                -------- assertr --------
+                Expected value: 42
+
+                  Actual: Sender was dropped without sending a value, or the value was already received
+                -------- assertr --------
+            "}]);
+        }
+    }
+}
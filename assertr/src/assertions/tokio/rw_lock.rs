@@ -30,6 +30,9 @@ impl<T: Debug, M: Mode> TokioRwLockAssertions<T> for AssertThat<'_, RwLock<T>, M
         T: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().try_write().is_err() {
             // Cannot be locked for writing, must already be read- or write-locked than!
             if self.actual().try_read().is_err() {
@@ -66,6 +69,9 @@ impl<T: Debug, M: Mode> TokioRwLockAssertions<T> for AssertThat<'_, RwLock<T>, M
         T: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().try_write().is_ok() {
             // Can be locked for writing, must have zero locks than!
             let actual = self.actual();
@@ -103,6 +109,9 @@ impl<T: Debug, M: Mode> TokioRwLockAssertions<T> for AssertThat<'_, RwLock<T>, M
         T: Debug,
     {
         self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
         if self.actual().try_write().is_ok() {
             // Can be locked for writing, must have zero locks than!
             let actual = self.actual();
@@ -0,0 +1,385 @@
+use crate::actual::Actual;
+use crate::mode::{Mode, Panic};
+use crate::util::unwind::unwind_safe;
+use crate::{AssertThat, AssertrPartialEq, PanicValue, tracking::AssertionTracking};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Write};
+use indoc::writedoc;
+use tokio::task::{JoinHandle, JoinSet};
+
+/// Assertions for tokio's `JoinHandle` type.
+/// These work in any mode (Panic or Capture).
+#[allow(clippy::return_self_not_must_use)]
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioJoinHandleAssertions {
+    /// Test that the task finished, either by completing, panicking or being cancelled, without
+    /// waiting for it.
+    fn is_finished(self) -> Self;
+}
+
+impl<T, M: Mode> TokioJoinHandleAssertions for AssertThat<'_, JoinHandle<T>, M> {
+    #[track_caller]
+    fn is_finished(self) -> Self {
+        self.track_assertion();
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        if !self.actual().is_finished() {
+            self.fail("Expected: Task to be finished\n\n  Actual: Task is still running\n");
+        }
+        self
+    }
+}
+
+/// Data-extracting assertions for tokio's `JoinHandle` type, awaiting the task.
+/// Only available in Panic mode, as the output cannot be produced when the task failed.
+///
+/// All assertions consume the handle, so they can only be called on owned subjects.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioJoinHandleExtractAssertions<'t, T> {
+    /// Awaits the task, testing that it completes with an output equal to `expected`, and changes
+    /// the subject to the output.
+    #[cfg_attr(feature = "fluent", fluent_alias("complete_with"))]
+    fn completes_with<E>(self, expected: E) -> impl Future<Output = AssertThat<'t, T, Panic>>
+    where
+        T: AssertrPartialEq<E> + Debug + 't,
+        E: Debug;
+
+    /// Awaits the task, testing that it panics, and changes the subject to the value it panicked
+    /// with.
+    ///
+    /// The location of the panic is unknown, as the task may have panicked on any thread.
+    fn panics_async(self) -> impl Future<Output = AssertThat<'t, PanicValue, Panic>>;
+
+    /// Awaits the task, testing that it was cancelled, e.g. by calling `abort` on the handle.
+    fn is_cancelled(self) -> impl Future<Output = AssertThat<'t, (), Panic>>;
+}
+
+impl<'t, T> TokioJoinHandleExtractAssertions<'t, T> for AssertThat<'t, JoinHandle<T>, Panic> {
+    async fn completes_with<E>(self, expected: E) -> AssertThat<'t, T, Panic>
+    where
+        T: AssertrPartialEq<E> + Debug + 't,
+        E: Debug,
+    {
+        self.track_assertion();
+        let this = self
+            .map_async(|it| owned_handle(it, "completes_with"))
+            .await;
+        match this.actual() {
+            Ok(actual) if AssertrPartialEq::eq(actual, &expected, None) => {}
+            Ok(actual) => this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected output: {expected:#?}
+
+                      Actual: {actual:#?}
+                "}
+            }),
+            Err(err) => this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected output: {expected:#?}
+
+                      Actual: {err}
+                "}
+            }),
+        }
+        this.map(|it| match it.unwrap_owned() {
+            Ok(output) => Actual::Owned(output),
+            Err(_err) => unreachable!("Panic mode always panics on fail"),
+        })
+    }
+
+    async fn panics_async(self) -> AssertThat<'t, PanicValue, Panic> {
+        self.track_assertion();
+        let this = self.map_async(|it| owned_handle(it, "panics_async")).await;
+        match this.actual() {
+            Err(err) if err.is_panic() => {}
+            Ok(_output) => {
+                this.fail(
+                    "Expected: Task to panic\n\n  Actual: Task completed without panicking\n",
+                );
+            }
+            Err(_err) => this.fail("Expected: Task to panic\n\n  Actual: Task was cancelled\n"),
+        }
+        this.map(|it| match it.unwrap_owned() {
+            Err(err) => Actual::Owned(PanicValue(err.into_panic(), None)),
+            Ok(_output) => unreachable!("Panic mode always panics on fail"),
+        })
+    }
+
+    async fn is_cancelled(self) -> AssertThat<'t, (), Panic> {
+        self.track_assertion();
+        let this = self.map_async(|it| owned_handle(it, "is_cancelled")).await;
+        match this.actual() {
+            Err(err) if err.is_cancelled() => {}
+            Ok(_output) => this.fail(
+                "Expected: Task to be cancelled\n\n  Actual: Task completed without being cancelled\n",
+            ),
+            Err(err) => this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Task to be cancelled
+
+                      Actual: {err}
+                "}
+            }),
+        }
+        this.map(|_it| Actual::Owned(()))
+    }
+}
+
+/// Data-extracting assertions for tokio's `JoinSet` type, awaiting all of its tasks.
+/// Only available in Panic mode.
+///
+/// All assertions consume the set, so they can only be called on owned subjects.
+#[cfg_attr(feature = "fluent", assertr_derive::fluent_aliases)]
+pub trait TokioJoinSetExtractAssertions<'t, T> {
+    /// Awaits all tasks, testing that none of them panicked or was cancelled, and changes the
+    /// subject to their outputs, in the order the tasks completed in.
+    fn all_complete_successfully(self) -> impl Future<Output = AssertThat<'t, Vec<T>, Panic>>
+    where
+        T: 't;
+}
+
+impl<'t, T: 'static> TokioJoinSetExtractAssertions<'t, T> for AssertThat<'t, JoinSet<T>, Panic> {
+    async fn all_complete_successfully(self) -> AssertThat<'t, Vec<T>, Panic>
+    where
+        T: 't,
+    {
+        self.track_assertion();
+        let this = unwind_safe(self.map_async(|it| {
+            let mut set = match it {
+                Actual::Borrowed(_) => {
+                    panic!("all_complete_successfully() can only be called on an owned JoinSet!")
+                }
+                Actual::Owned(set) => set,
+            };
+            async move {
+                let mut outputs = Vec::with_capacity(set.len());
+                let mut errors = Vec::new();
+                while let Some(result) = set.join_next().await {
+                    match result {
+                        Ok(output) => outputs.push(output),
+                        Err(err) => errors.push(err),
+                    }
+                }
+                (outputs, errors)
+            }
+        }))
+        .await;
+        let (outputs, errors) = this.actual();
+        if !errors.is_empty() {
+            let total = outputs.len() + errors.len();
+            let failed = errors.len();
+            this.fail(|w: &mut String| {
+                writedoc! {w, r"
+                    Expected: All {total} tasks to complete successfully
+
+                      Actual: {failed} tasks failed:
+                "}?;
+                for err in errors {
+                    writeln!(w, "  {err}")?;
+                }
+                Ok(())
+            });
+        }
+        this.map(|it| Actual::Owned(it.unwrap_owned().0))
+    }
+}
+
+fn owned_handle<T>(actual: Actual<'_, JoinHandle<T>>, method: &str) -> JoinHandle<T> {
+    match actual {
+        Actual::Borrowed(_) => panic!("{method}() can only be called on an owned JoinHandle!"),
+        Actual::Owned(handle) => handle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Panics without invoking the panic hook, keeping the test output free of expected panics.
+    fn panic_silently<T>(message: &'static str) -> T {
+        std::panic::resume_unwind(Box::new(message))
+    }
+
+    mod is_finished {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_task_completed() {
+            let handle = tokio::spawn(async { 42 });
+            while !handle.is_finished() {
+                tokio::task::yield_now().await;
+            }
+
+            assert_that!(&handle).is_finished();
+        }
+
+        #[tokio::test]
+        async fn panics_when_task_is_running() {
+            let handle = tokio::spawn(std::future::pending::<()>());
+
+            assert_that_panic_by(|| assert_that!(&handle).with_location(false).is_finished())
+                .has_message(formatdoc! {"
+                    -------- assertr --------
+                    Expected: Task to be finished
+
+                      Actual: Task is still running
+                    -------- assertr --------
+                "});
+            handle.abort();
+        }
+    }
+
+    mod completes_with {
+        use super::panic_silently;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn changes_subject_to_output() {
+            let handle = tokio::spawn(async { String::from("done") });
+
+            assert_that!(handle)
+                .completes_with("done")
+                .await
+                .has_length(4);
+        }
+
+        #[tokio::test]
+        async fn panics_when_output_differs() {
+            let handle = tokio::spawn(async { 41 });
+
+            assert_that_panic_by_async(async || {
+                assert_that!(handle)
+                    .with_location(false)
+                    .completes_with(42)
+                    .await
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected output: 42
+
+                  Actual: 41
+                -------- assertr --------
+            "});
+        }
+
+        #[tokio::test]
+        async fn panics_when_task_panicked() {
+            let handle = tokio::spawn(async { panic_silently::<i32>("boom") });
+
+            assert_that_panic_by_async(async || {
+                assert_that!(handle)
+                    .with_location(false)
+                    .completes_with(42)
+                    .await
+            })
+            .await
+            .has_message_containing(r#"panicked with message "boom""#);
+        }
+    }
+
+    mod panics_async {
+        use super::panic_silently;
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn changes_subject_to_panic_value() {
+            let handle = tokio::spawn(async { panic_silently::<i32>("boom") });
+
+            assert_that!(handle)
+                .panics_async()
+                .await
+                .has_message("boom");
+        }
+
+        #[tokio::test]
+        async fn panics_when_task_completed() {
+            let handle = tokio::spawn(async { 42 });
+
+            assert_that_panic_by_async(async || {
+                assert_that!(handle)
+                    .with_location(false)
+                    .panics_async()
+                    .await
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: Task to panic
+
+                  Actual: Task completed without panicking
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod is_cancelled {
+        use crate::prelude::*;
+        use indoc::formatdoc;
+
+        #[tokio::test]
+        async fn succeeds_when_task_was_aborted() {
+            let handle = tokio::spawn(std::future::pending::<()>());
+            handle.abort();
+
+            assert_that!(handle).is_cancelled().await;
+        }
+
+        #[tokio::test]
+        async fn panics_when_task_completed() {
+            let handle = tokio::spawn(async { 42 });
+
+            assert_that_panic_by_async(async || {
+                assert_that!(handle)
+                    .with_location(false)
+                    .is_cancelled()
+                    .await
+            })
+            .await
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: Task to be cancelled
+
+                  Actual: Task completed without being cancelled
+                -------- assertr --------
+            "});
+        }
+    }
+
+    mod all_complete_successfully {
+        use super::panic_silently;
+        use crate::prelude::*;
+        use tokio::task::JoinSet;
+
+        #[tokio::test]
+        async fn changes_subject_to_outputs() {
+            let mut set = JoinSet::new();
+            for i in 1..=3 {
+                set.spawn(async move { i * 10 });
+            }
+
+            assert_that!(set)
+                .all_complete_successfully()
+                .await
+                .contains_exactly_in_any_order([10, 20, 30]);
+        }
+
+        #[tokio::test]
+        async fn panics_when_a_task_panicked() {
+            let mut set = JoinSet::new();
+            set.spawn(async { 1 });
+            set.spawn(async { panic_silently::<i32>("boom") });
+            // A `JoinSet` is not unwind safe, so it must not be captured as is.
+            let assertion = assert_that!(set).with_location(false);
+
+            assert_that_panic_by_async(async || assertion.all_complete_successfully().await)
+                .await
+                .has_message_containing("Expected: All 2 tasks to complete successfully")
+                .has_message_containing("Actual: 1 tasks failed:")
+                .has_message_containing(r#"panicked with message "boom""#);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{AssertrPartialEq, EqContext};
+use alloc::collections::BTreeMap;
+
+/// `PartialEq` like comparison on B-tree maps, but with an `EqContext`, tracking the differences
+/// of differing values, as well as the number of missing keys, unexpected keys and differing values.
+///
+/// This function is supposed to be used when deriving `AssertrEq`:
+/// ```
+/// use assertr::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, AssertrEq)]
+/// pub struct Bar {
+///     pub id: i32,
+/// }
+///
+/// #[derive(Debug, AssertrEq)]
+/// pub struct Foo {
+///     #[assertr_eq(
+///         map_type = "BTreeMap<u32, BarAssertrEq>",
+///         compare_with = "::assertr::cmp::btree_map::compare"
+///     )]
+///     pub bars: BTreeMap<u32, Bar>,
+/// }
+/// ```
+#[must_use]
+pub fn compare<K, V1, V2>(
+    map1: &BTreeMap<K, V1>,
+    map2: &BTreeMap<K, V2>,
+    ctx: Option<&mut EqContext>,
+) -> bool
+where
+    K: Ord,
+    V1: AssertrPartialEq<V2>,
+{
+    super::compare_maps(map1, map2.len(), |key| map2.get(key), ctx)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EqContext;
+    use crate::cmp::btree_map::compare;
+    use crate::prelude::*;
+    use alloc::collections::BTreeMap;
+    use indoc::formatdoc;
+
+    #[test]
+    fn counts_missing_unexpected_and_differing_entries() {
+        let actual = BTreeMap::from([("a", 1), ("b", 2), ("d", 5)]);
+        let expected = BTreeMap::from([("b", 3), ("c", 4), ("d", 5)]);
+
+        let mut ctx = EqContext::new();
+
+        assert_that!(compare(&actual, &actual.clone(), Some(&mut ctx))).is_true();
+        assert_that!(compare(&actual, &expected, Some(&mut ctx))).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                Number of missing keys: 1,
+                Number of unexpected keys: 1,
+                Number of differing values: 1,
+            ]"});
+    }
+}
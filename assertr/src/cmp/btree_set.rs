@@ -0,0 +1,67 @@
+use crate::EqContext;
+use crate::util::collection_diff::CollectionDiff;
+use alloc::collections::BTreeSet;
+use core::fmt::Debug;
+
+/// `PartialEq` like comparison on B-tree sets, but with an `EqContext`, tracking missing and
+/// unexpected elements.
+///
+/// This function is supposed to be used when deriving `AssertrEq`:
+/// ```
+/// use assertr::prelude::*;
+/// use std::collections::BTreeSet;
+///
+/// #[derive(Debug, AssertrEq)]
+/// pub struct Foo {
+///     #[assertr_eq(compare_with = "::assertr::cmp::btree_set::compare")]
+///     pub tags: BTreeSet<String>,
+/// }
+/// ```
+#[must_use]
+pub fn compare<T>(set1: &BTreeSet<T>, set2: &BTreeSet<T>, ctx: Option<&mut EqContext>) -> bool
+where
+    T: Ord + Debug,
+{
+    let mut diff = CollectionDiff::for_set();
+    diff.unexpected
+        .extend(set1.difference(set2).map(|it| it as &dyn Debug));
+    diff.missing
+        .extend(set2.difference(set1).map(|it| it as &dyn Debug));
+
+    if let Some(ctx) = ctx {
+        for message in diff.messages() {
+            ctx.add_difference(message);
+        }
+    }
+
+    diff.is_empty()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EqContext;
+    use crate::cmp::btree_set::compare;
+    use crate::prelude::*;
+    use alloc::collections::BTreeSet;
+    use indoc::formatdoc;
+
+    #[test]
+    fn tracks_missing_and_unexpected_elements() {
+        let actual = BTreeSet::from([1, 2]);
+        let expected = BTreeSet::from([2, 3]);
+
+        let mut ctx = EqContext::new();
+
+        assert_that!(compare(&actual, &actual.clone(), Some(&mut ctx))).is_true();
+        assert_that!(compare(&actual, &expected, Some(&mut ctx))).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                Missing elements: [
+                    3,
+                ],
+                Unexpected elements: [
+                    1,
+                ],
+            ]"});
+    }
+}
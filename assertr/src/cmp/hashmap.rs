@@ -2,10 +2,13 @@ use crate::{AssertrPartialEq, EqContext};
 use std::collections::HashMap;
 use std::hash::{BuildHasher, Hash};
 
+/// `PartialEq` like comparison on hash maps, but with an `EqContext`, tracking the differences
+/// of differing values, as well as the number of missing keys, unexpected keys and differing values.
 ///
 /// This function is supposed to be used when deriving `AssertrEq`:
 /// ```
 /// use assertr::prelude::*;
+/// use std::collections::HashMap;
 ///
 /// #[derive(Debug, AssertrEq)]
 /// pub struct Bar {
@@ -17,16 +20,16 @@ use std::hash::{BuildHasher, Hash};
 ///     pub id: i32,
 ///
 ///     #[assertr_eq(
-///         map_type = "Vec<BarAssertrEq>",
-///         compare_with = "::assertr::cmp::slice::compare"
+///         map_type = "HashMap<String, BarAssertrEq>",
+///         compare_with = "::assertr::cmp::hashmap::compare"
 ///     )]
-///     pub bars: Vec<Bar>,
+///     pub bars: HashMap<String, Bar>,
 /// }
 /// ```
 pub fn compare<K, V1, V2, S1, S2>(
     map1: &HashMap<K, V1, S1>,
     map2: &HashMap<K, V2, S2>,
-    mut ctx: Option<&mut EqContext>,
+    ctx: Option<&mut EqContext>,
 ) -> bool
 where
     K: Eq + Hash,
@@ -34,21 +37,15 @@ where
     S1: BuildHasher,
     S2: BuildHasher,
 {
-    if map1.len() != map2.len() {
-        return false;
-    }
-
-    map1.iter().all(|(k, v1)| {
-        map2.get(k)
-            .is_some_and(|v2| AssertrPartialEq::eq(v1, v2, ctx.as_deref_mut()))
-    })
+    super::compare_maps(map1, map2.len(), |key| map2.get(key), ctx)
 }
 
 #[cfg(test)]
 mod test {
-    use crate::EqContext;
     use crate::cmp::hashmap::compare;
     use crate::prelude::*;
+    use crate::{AssertrPartialEq, EqContext};
+    use indoc::formatdoc;
     use std::collections::HashMap;
 
     #[derive(Debug, PartialEq)]
@@ -78,4 +75,54 @@ mod test {
         assert_that!(compare(&m1, &m2, Some(&mut ctx))).is_true();
         assert_that!(compare(&m1, &m3, Some(&mut ctx))).is_false();
     }
+
+    #[test]
+    fn counts_missing_unexpected_and_differing_entries() {
+        let actual = HashMap::from([("a", 1), ("b", 2)]);
+        let expected = HashMap::from([("b", 3), ("c", 4)]);
+
+        let mut ctx = EqContext::new();
+
+        assert_that!(compare(&actual, &expected, Some(&mut ctx))).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                Number of missing keys: 1,
+                Number of unexpected keys: 1,
+                Number of differing values: 1,
+            ]"});
+    }
+
+    #[test]
+    fn records_nested_differences_of_differing_values() {
+        /// Records a difference of its own whenever it is compared unequal.
+        #[derive(Debug)]
+        struct Nested(u32);
+
+        impl AssertrPartialEq for Nested {
+            fn eq(&self, other: &Self, ctx: Option<&mut EqContext>) -> bool {
+                if self.0 != other.0
+                    && let Some(ctx) = ctx
+                {
+                    ctx.add_difference(String::from("nested difference"));
+                }
+                self.0 == other.0
+            }
+
+            fn ne(&self, other: &Self, ctx: Option<&mut EqContext>) -> bool {
+                !AssertrPartialEq::eq(self, other, ctx)
+            }
+        }
+
+        let actual = HashMap::from([("a", Nested(1))]);
+        let expected = HashMap::from([("a", Nested(2))]);
+
+        let mut ctx = EqContext::new();
+
+        assert_that!(compare(&actual, &expected, Some(&mut ctx))).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                nested difference,
+                Number of differing values: 1,
+            ]"});
+    }
 }
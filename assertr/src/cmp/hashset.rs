@@ -0,0 +1,81 @@
+use crate::EqContext;
+use crate::util::collection_diff::CollectionDiff;
+use core::fmt::Debug;
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+/// `PartialEq` like comparison on hash sets, but with an `EqContext`, tracking missing and
+/// unexpected elements.
+///
+/// This function is supposed to be used when deriving `AssertrEq`:
+/// ```
+/// use assertr::prelude::*;
+/// use std::collections::HashSet;
+///
+/// #[derive(Debug, AssertrEq)]
+/// pub struct Foo {
+///     #[assertr_eq(compare_with = "::assertr::cmp::hashset::compare")]
+///     pub tags: HashSet<String>,
+/// }
+/// ```
+#[must_use]
+pub fn compare<T, S1, S2>(
+    set1: &HashSet<T, S1>,
+    set2: &HashSet<T, S2>,
+    ctx: Option<&mut EqContext>,
+) -> bool
+where
+    T: Eq + Hash + Debug,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    let mut diff = CollectionDiff::for_set();
+
+    for element in set1 {
+        if !set2.contains(element) {
+            diff.unexpected.push(element);
+        }
+    }
+    for element in set2 {
+        if !set1.contains(element) {
+            diff.missing.push(element);
+        }
+    }
+
+    if let Some(ctx) = ctx {
+        for message in diff.messages() {
+            ctx.add_difference(message);
+        }
+    }
+
+    diff.is_empty()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EqContext;
+    use crate::cmp::hashset::compare;
+    use crate::prelude::*;
+    use indoc::formatdoc;
+    use std::collections::HashSet;
+
+    #[test]
+    fn tracks_missing_and_unexpected_elements() {
+        let actual = HashSet::from([1, 2]);
+        let expected = HashSet::from([2, 3]);
+
+        let mut ctx = EqContext::new();
+
+        assert_that!(compare(&actual, &actual.clone(), Some(&mut ctx))).is_true();
+        assert_that!(compare(&actual, &expected, Some(&mut ctx))).is_false();
+        assert_that!(format!("{:#?}", ctx.differences)).is_equal_to(formatdoc! {"
+            [
+                Missing elements: [
+                    3,
+                ],
+                Unexpected elements: [
+                    1,
+                ],
+            ]"});
+    }
+}
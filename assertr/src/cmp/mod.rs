@@ -1,3 +1,53 @@
+use crate::{AssertrPartialEq, EqContext};
+use alloc::format;
+
+pub mod btree_map;
+pub mod btree_set;
 #[cfg(feature = "std")]
 pub mod hashmap;
+#[cfg(feature = "std")]
+pub mod hashset;
 pub mod slice;
+
+/// Compares two maps entry by entry, given the entries of the first map and a lookup into the
+/// second map, which holds `len2` entries.
+///
+/// Values are compared with the given `EqContext`, recording their nested differences. As keys
+/// and values need not be `Debug`, only the number of missing keys, unexpected keys and differing
+/// values is recorded on top.
+fn compare_maps<'a, K: 'a, V1: AssertrPartialEq<V2> + 'a, V2: 'a>(
+    entries1: impl IntoIterator<Item = (&'a K, &'a V1)>,
+    len2: usize,
+    get2: impl Fn(&K) -> Option<&'a V2>,
+    mut ctx: Option<&mut EqContext>,
+) -> bool {
+    let mut found = 0;
+    let mut unexpected = 0;
+    let mut differing = 0;
+    for (key, v1) in entries1 {
+        match get2(key) {
+            None => unexpected += 1,
+            Some(v2) => {
+                found += 1;
+                if !AssertrPartialEq::eq(v1, v2, ctx.as_deref_mut()) {
+                    differing += 1;
+                }
+            }
+        }
+    }
+    let missing = len2 - found;
+
+    if let Some(ctx) = ctx {
+        for (count, what) in [
+            (missing, "missing keys"),
+            (unexpected, "unexpected keys"),
+            (differing, "differing values"),
+        ] {
+            if count > 0 {
+                ctx.add_difference(format!("Number of {what}: {count}"));
+            }
+        }
+    }
+
+    missing == 0 && unexpected == 0 && differing == 0
+}
@@ -0,0 +1,428 @@
+//! Assertions retried until they pass, for subjects changing in the background.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Write};
+use core::future::Future;
+use core::panic::Location;
+use core::time::Duration;
+use indoc::writedoc;
+use std::time::Instant;
+
+use crate::actual::Actual;
+use crate::failure::strip_banner;
+use crate::mode::{Capture, Panic};
+use crate::prelude::PartialEqAssertions;
+use crate::tracking::AssertionTracking;
+use crate::util::unwind::unwind_safe;
+use crate::{AssertThat, AssertrPartialEq, POISONED_SUBJECT, timeout};
+
+/// How long assertions are retried when `within` is not called.
+const DEFAULT_WITHIN: Duration = Duration::from_secs(1);
+
+/// How long to wait between attempts when `every` is not called.
+const DEFAULT_EVERY: Duration = Duration::from_millis(10);
+
+/// Entrypoint for assertions on a subject that changes over time, e.g. state updated by a
+/// background worker.
+///
+/// `producer` is called again for every attempt, and the assertions are retried in `Capture`
+/// mode until they pass or the timeout elapses. The assertion then fails with the failure of
+/// the last attempt.
+///
+/// ```rust
+/// use assertr::prelude::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+///
+/// let counter = AtomicUsize::new(0);
+///
+/// assert_eventually(|| counter.fetch_add(1, Ordering::Relaxed))
+///     .within(Duration::from_secs(2))
+///     .every(Duration::from_millis(1))
+///     .is_equal_to(3);
+/// ```
+#[must_use]
+pub fn assert_eventually<T, F>(producer: F) -> Eventually<F>
+where
+    F: FnMut() -> T,
+{
+    Eventually {
+        producer,
+        within: DEFAULT_WITHIN,
+        every: DEFAULT_EVERY,
+        print_location: true,
+    }
+}
+
+/// Entrypoint for assertions on a subject that changes over time, produced asynchronously.
+///
+/// Like [`assert_eventually`], but awaits the future `producer` returns for every attempt.
+/// Waits between attempts using tokio's timer when awaited within a tokio runtime (requires
+/// the `tokio` feature), a timer thread otherwise.
+///
+/// As async functions cannot track their caller, failures report the location of this call.
+#[must_use]
+#[track_caller]
+pub fn assert_eventually_async<T, F, Fut>(producer: F) -> EventuallyAsync<F>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = T>,
+{
+    EventuallyAsync {
+        producer,
+        within: DEFAULT_WITHIN,
+        every: DEFAULT_EVERY,
+        print_location: true,
+        location: Location::caller(),
+    }
+}
+
+/// Retries assertions on the values produced by a closure. Created by [`assert_eventually`].
+pub struct Eventually<F> {
+    producer: F,
+    within: Duration,
+    every: Duration,
+    print_location: bool,
+}
+
+/// Retries assertions on the values produced by an async closure. Created by
+/// [`assert_eventually_async`].
+pub struct EventuallyAsync<F> {
+    producer: F,
+    within: Duration,
+    every: Duration,
+    print_location: bool,
+    location: &'static Location<'static>,
+}
+
+impl<T, F> Eventually<F>
+where
+    F: FnMut() -> T,
+{
+    /// Sets how long the assertions are retried. Defaults to one second.
+    #[must_use]
+    pub fn within(mut self, within: Duration) -> Self {
+        self.within = within;
+        self
+    }
+
+    /// Sets how long to wait between attempts. Defaults to 10 milliseconds.
+    #[must_use]
+    pub fn every(mut self, every: Duration) -> Self {
+        self.every = every;
+        self
+    }
+
+    /// Control whether the location is shown on assertion failure.
+    #[must_use]
+    pub fn with_location(mut self, value: bool) -> Self {
+        self.print_location = value;
+        self
+    }
+
+    /// Retries `assertions` on freshly produced values until they pass, changing the subject to
+    /// the value they passed on.
+    #[track_caller]
+    pub fn satisfies<'t, A>(mut self, mut assertions: A) -> AssertThat<'t, T, Panic>
+    where
+        T: 't,
+        A: FnMut(AssertThat<'t, T, Capture>) -> AssertThat<'t, T, Capture>,
+    {
+        let start = Instant::now();
+        let mut attempts = Attempts::default();
+        loop {
+            let (actual, failures) = attempt((self.producer)(), &mut assertions);
+            if let Some(retry_in) = attempts.record(failures, start, self.within, self.every) {
+                std::thread::sleep(retry_in);
+            } else {
+                return attempts.finish(
+                    actual,
+                    self.within,
+                    self.print_location,
+                    Location::caller(),
+                );
+            }
+        }
+    }
+
+    /// Retries until a freshly produced value equals `expected`, changing the subject to it.
+    #[track_caller]
+    pub fn is_equal_to<'t, E>(self, expected: E) -> AssertThat<'t, T, Panic>
+    where
+        T: AssertrPartialEq<E> + Debug + 't,
+        E: Clone + Debug,
+    {
+        self.satisfies(|it| it.is_equal_to(expected.clone()))
+    }
+}
+
+impl<T, F, Fut> EventuallyAsync<F>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = T>,
+{
+    /// Sets how long the assertions are retried. Defaults to one second.
+    #[must_use]
+    pub fn within(mut self, within: Duration) -> Self {
+        self.within = within;
+        self
+    }
+
+    /// Sets how long to wait between attempts. Defaults to 10 milliseconds.
+    #[must_use]
+    pub fn every(mut self, every: Duration) -> Self {
+        self.every = every;
+        self
+    }
+
+    /// Control whether the location is shown on assertion failure.
+    #[must_use]
+    pub fn with_location(mut self, value: bool) -> Self {
+        self.print_location = value;
+        self
+    }
+
+    /// Retries `assertions` on freshly produced values until they pass, changing the subject to
+    /// the value they passed on.
+    pub async fn satisfies<'t, A>(mut self, mut assertions: A) -> AssertThat<'t, T, Panic>
+    where
+        T: 't,
+        A: FnMut(AssertThat<'t, T, Capture>) -> AssertThat<'t, T, Capture>,
+    {
+        let start = Instant::now();
+        let mut attempts = Attempts::default();
+        loop {
+            let (actual, failures) = attempt((self.producer)().await, &mut assertions);
+            if let Some(retry_in) = attempts.record(failures, start, self.within, self.every) {
+                unwind_safe(timeout::sleep(retry_in)).await;
+            } else {
+                return attempts.finish(actual, self.within, self.print_location, self.location);
+            }
+        }
+    }
+
+    /// Retries until a freshly produced value equals `expected`, changing the subject to it.
+    pub async fn is_equal_to<'t, E>(self, expected: E) -> AssertThat<'t, T, Panic>
+    where
+        T: AssertrPartialEq<E> + Debug + 't,
+        E: Clone + Debug,
+    {
+        self.satisfies(|it| it.is_equal_to(expected.clone())).await
+    }
+}
+
+/// Runs `assertions` on `actual` in `Capture` mode, returning the value and the failures.
+fn attempt<'t, T: 't, A>(actual: T, assertions: &mut A) -> (T, Vec<String>)
+where
+    A: FnMut(AssertThat<'t, T, Capture>) -> AssertThat<'t, T, Capture>,
+{
+    let subject = AssertThat::new_panicking(Actual::Owned(actual))
+        .with_location(false)
+        .with_capture();
+    let mut subject = assertions(subject);
+    let failures = subject.take_failures();
+    (
+        subject.actual.expect(POISONED_SUBJECT).unwrap_owned(),
+        failures,
+    )
+}
+
+#[derive(Default)]
+struct Attempts {
+    count: usize,
+    last_failures: Vec<String>,
+}
+
+impl Attempts {
+    /// Records the failures of an attempt, returning how long to wait before the next one, or
+    /// `None` if the assertions passed or no time is left.
+    fn record(
+        &mut self,
+        failures: Vec<String>,
+        start: Instant,
+        within: Duration,
+        every: Duration,
+    ) -> Option<Duration> {
+        self.count += 1;
+        self.last_failures = failures;
+        let remaining = within.saturating_sub(start.elapsed());
+        if self.last_failures.is_empty() || remaining.is_zero() {
+            None
+        } else {
+            Some(every.min(remaining))
+        }
+    }
+
+    /// Fails with the failures of the last attempt, if any, reporting `location` as the location
+    /// of the assertion.
+    #[track_caller]
+    fn finish<'t, T>(
+        self,
+        actual: T,
+        within: Duration,
+        print_location: bool,
+        location: &Location<'_>,
+    ) -> AssertThat<'t, T, Panic> {
+        let subject =
+            AssertThat::new_panicking(Actual::Owned(actual)).with_location(print_location);
+        subject.track_assertion();
+        if !self.last_failures.is_empty() {
+            let count = self.count;
+            let last_failures = self
+                .last_failures
+                .iter()
+                .map(|failure| strip_banner(failure).unwrap_or(failure))
+                .collect::<Vec<_>>()
+                .join("\n");
+            subject.fail_at(location, |w: &mut String| {
+                writedoc! {w, r"
+                    Expected: Assertions to pass within {within:?}
+
+                      Actual: Assertions still failed after {count} attempts
+
+                    Last failure:
+
+                    {last_failures}"}
+            });
+        }
+        subject
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    fn counter() -> impl FnMut() -> usize {
+        let count = Cell::new(0);
+        move || {
+            count.set(count.get() + 1);
+            count.get()
+        }
+    }
+
+    mod assert_eventually {
+        use super::counter;
+        use crate::prelude::*;
+        use core::time::Duration;
+        use indoc::formatdoc;
+        use std::time::Instant;
+
+        #[test]
+        fn succeeds_once_produced_value_satisfies_assertions() {
+            assert_eventually(counter())
+                .within(Duration::from_secs(10))
+                .every(Duration::from_millis(1))
+                .satisfies(|it| it.is_greater_than(2).is_less_than(5))
+                .is_equal_to(3);
+        }
+
+        #[test]
+        fn succeeds_once_produced_value_is_equal() {
+            assert_eventually(counter())
+                .within(Duration::from_secs(10))
+                .every(Duration::from_millis(1))
+                .is_equal_to(3);
+        }
+
+        #[test]
+        fn panics_with_last_failure_and_attempt_count() {
+            assert_that_panic_by(|| {
+                assert_eventually(|| 42)
+                    .within(Duration::ZERO)
+                    .with_location(false)
+                    .is_equal_to(43)
+            })
+            .has_message(formatdoc! {"
+                -------- assertr --------
+                Expected: Assertions to pass within 0ns
+
+                  Actual: Assertions still failed after 1 attempts
+
+                Last failure:
+
+                Expected: 43
+
+                  Actual: 42
+                -------- assertr --------
+            "});
+        }
+
+        #[test]
+        fn retries_until_timeout() {
+            assert_that_panic_by(|| {
+                assert_eventually(counter())
+                    .within(Duration::from_millis(20))
+                    .every(Duration::from_millis(1))
+                    .with_location(false)
+                    .satisfies(|it| it.is_zero())
+            })
+            .has_message_containing("Actual: Assertions still failed after")
+            .has_message_containing("Expecting additive identity of type 'usize'");
+        }
+
+        #[test]
+        fn waits_between_attempts() {
+            let start = Instant::now();
+            assert_eventually(counter())
+                .every(Duration::from_millis(10))
+                .is_equal_to(3);
+
+            assert_that!(start.elapsed()).is_greater_or_equal_to(Duration::from_millis(20));
+        }
+    }
+
+    mod assert_eventually_async {
+        use super::counter;
+        use crate::prelude::*;
+        use core::time::Duration;
+        use futures::executor::block_on;
+
+        #[tokio::test]
+        async fn succeeds_once_produced_value_is_equal() {
+            let mut next = counter();
+            assert_eventually_async(|| core::future::ready(next()))
+                .within(Duration::from_secs(10))
+                .every(Duration::from_millis(1))
+                .is_equal_to(3)
+                .await;
+        }
+
+        #[test]
+        fn succeeds_without_tokio_runtime() {
+            let mut next = counter();
+            block_on(
+                assert_eventually_async(|| core::future::ready(next()))
+                    .within(Duration::from_secs(10))
+                    .every(Duration::from_millis(1))
+                    .satisfies(|it| it.is_greater_than(2)),
+            )
+            .is_equal_to(3);
+        }
+
+        #[tokio::test]
+        async fn panics_after_timeout() {
+            assert_that_panic_by_async(|| {
+                assert_eventually_async(|| async { 42 })
+                    .within(Duration::from_millis(20))
+                    .with_location(false)
+                    .is_equal_to(43)
+            })
+            .await
+            .has_message_containing("Expected: Assertions to pass within 20ms");
+        }
+
+        #[tokio::test]
+        async fn reports_location_of_assert_eventually_async_call() {
+            let line = line!() + 2;
+            assert_that_panic_by_async(|| {
+                assert_eventually_async(|| async { 42 })
+                    .within(Duration::from_millis(20))
+                    .is_equal_to(43)
+            })
+            .await
+            .has_message_containing(format!("Assertion failed at {}:{line}:", file!()));
+        }
+    }
+}
@@ -8,6 +8,18 @@ use crate::{
     prelude::Mode,
 };
 
+/// Appended to the failure of a precondition, whose nested assertions were never run.
+pub(crate) const NESTED_ASSERTIONS_SKIPPED: &str =
+    "\nNested assertions were skipped, as this precondition failed.\n";
+
+/// Appended to the failure of a data-extracting assertion in `Capture` mode, whose subject is
+/// poisoned from then on.
+pub(crate) const EXTRACTED_ASSERTIONS_SKIPPED: &str =
+    "\nAssertions on the extracted value are skipped, as it could not be extracted.\n";
+
+/// Surrounds every failure message.
+const BANNER: &str = "-------- assertr --------\n";
+
 pub trait Failure {
     /// Writes the failure message to the target string.
     ///
@@ -59,11 +71,18 @@ impl<T, M: Mode> AssertThat<'_, T, M> {
     /// Panics with the formatted failure message when not in capture mode.
     #[track_caller]
     pub fn fail(&self, failure: impl Failure) {
+        self.fail_at(core::panic::Location::caller(), failure);
+    }
+
+    /// Like `fail`, but reports `location` as the location of the assertion instead of the
+    /// caller's location, e.g. for async assertions, which cannot track their caller.
+    #[track_caller]
+    pub(crate) fn fail_at(&self, location: &core::panic::Location<'_>, failure: impl Failure) {
         let mut detail_messages = Vec::new();
         self.collect_messages(&mut detail_messages);
 
         let msg = build_failure_message(
-            self.print_location,
+            self.print_location.then_some(location),
             self.subject_name.as_deref(),
             &detail_messages,
             failure,
@@ -79,24 +98,22 @@ impl<T, M: Mode> AssertThat<'_, T, M> {
     }
 }
 
-#[track_caller]
 fn build_failure_message(
-    print_location: bool,
+    location: Option<&core::panic::Location<'_>>,
     subject_name: Option<&str>,
     detail_messages: &[String],
     failure: impl Failure,
 ) -> Result<String, core::fmt::Error> {
     let mut err = String::new();
 
-    err.write_str("-------- assertr --------\n")?;
+    err.write_str(BANNER)?;
 
-    if print_location {
-        let caller_location = core::panic::Location::caller();
+    if let Some(location) = location {
         let _ = err.write_fmt(format_args!(
             "Assertion failed at {file}:{line}:{column}\n\n",
-            file = caller_location.file(),
-            line = caller_location.line(),
-            column = caller_location.column(),
+            file = location.file(),
+            line = location.line(),
+            column = location.column(),
         ));
     }
 
@@ -112,7 +129,19 @@ fn build_failure_message(
         err.write_fmt(format_args!("Details: {detail_messages:#?}\n"))?;
     }
 
-    err.write_str("-------- assertr --------\n")?;
+    err.write_str(BANNER)?;
 
     Ok(err)
 }
+
+/// Extracts the failure text from a failure message built by assertr, stripping the surrounding
+/// banners and the location line. Returns `None` if `message` was not built by assertr.
+pub(crate) fn strip_banner(message: &str) -> Option<&str> {
+    let inner = message.strip_prefix(BANNER)?.strip_suffix(BANNER)?;
+    Some(match inner.strip_prefix("Assertion failed at ") {
+        Some(located) => located
+            .split_once("\n\n")
+            .map_or(located, |(_location, failure)| failure),
+        None => inner,
+    })
+}
@@ -10,18 +10,19 @@ use alloc::{borrow::ToOwned, boxed::Box, format, string::String, vec::Vec};
 use core::{
     any::{Any, type_name},
     cell::RefCell,
-    fmt::{Debug, Formatter},
+    fmt::{Debug, Formatter, Write},
     future::Future,
     marker::PhantomData,
     mem::needs_drop,
     panic::{RefUnwindSafe, UnwindSafe},
 };
 use details::WithDetail;
-use failure::Fallible;
+use failure::{Failure, Fallible};
 use mode::{Capture, Mode, Panic};
 use tracking::{AssertionTracking, NumberOfAssertions};
 
 pub mod actual;
+pub mod approx;
 #[doc(hidden)]
 pub mod assert_that_macro;
 pub mod assertions;
@@ -29,12 +30,26 @@ pub mod cmp;
 pub mod condition;
 mod conversion;
 pub mod details;
+#[cfg(feature = "std")]
+pub mod eventually;
 pub mod failure;
 pub mod mode;
+#[cfg(feature = "std")]
+mod panic_hook;
+#[cfg(feature = "std")]
+mod timeout;
 pub mod tracking;
 pub mod util;
 
+/// Everything needed to write assertions, including all assertion traits.
+///
+/// Some methods are provided by several of these traits. `value()`, for example, is provided by
+/// `AtomicAssertions`, `CellAssertions`, `GuardedValueExtractAssertions`, `NonZeroAssertions`,
+/// `SaturatingAssertions` and `WrappingAssertions`. As each of them is implemented for different
+/// subject types only, a call is never ambiguous.
 pub mod prelude {
+    #[cfg(feature = "derive")]
+    pub use assertr_derive::ApproxEq;
     #[cfg(feature = "derive")]
     pub use assertr_derive::AssertrEq;
 
@@ -53,21 +68,33 @@ pub mod prelude {
     pub use crate::assert_that_type;
     pub use crate::assertions::HasLength;
     pub use crate::assertions::alloc::prelude::*;
+    #[cfg(feature = "anyhow")]
+    pub use crate::assertions::anyhow::prelude::*;
     pub use crate::assertions::condition::ConditionAssertions;
     pub use crate::assertions::condition::IterableConditionAssertions;
     pub use crate::assertions::core::prelude::*;
+    #[cfg(feature = "eyre")]
+    pub use crate::assertions::eyre::prelude::*;
     #[cfg(feature = "http")]
     pub use crate::assertions::http::prelude::*;
     #[cfg(feature = "jiff")]
     pub use crate::assertions::jiff::prelude::*;
     #[cfg(feature = "num")]
     pub use crate::assertions::num::NumAssertions;
+    #[cfg(feature = "num")]
+    pub use crate::assertions::num::complex::ComplexAssertions;
+    #[cfg(feature = "num")]
+    pub use crate::assertions::num::integer::IntegerAssertions;
+    #[cfg(feature = "num")]
+    pub use crate::assertions::num::rational::RationalAssertions;
+    #[cfg(feature = "num")]
+    pub use crate::assertions::num::statistics::{
+        StatisticsAssertions, StatisticsExtractAssertions,
+    };
     #[cfg(feature = "program")]
     pub use crate::assertions::program::Program;
     #[cfg(feature = "program")]
     pub use crate::assertions::program::ProgramAssertions;
-    #[cfg(feature = "program")]
-    pub use crate::assertions::program::ProgramAssertionsRequiringPanicMode;
     #[cfg(feature = "reqwest")]
     pub use crate::assertions::reqwest::prelude::*;
     #[cfg(feature = "rootcause")]
@@ -82,10 +109,48 @@ pub mod prelude {
     #[cfg(feature = "serde")]
     pub use crate::conversion::toml;
     pub use crate::eq;
+    #[cfg(feature = "std")]
+    pub use crate::eventually::{assert_eventually, assert_eventually_async};
     pub use crate::mode::Mode;
 }
 
-pub struct PanicValue(Box<dyn Any>);
+/// The value a function panicked with, as captured by `assert_that_panic_by`.
+pub struct PanicValue(Box<dyn Any>, Option<PanicLocation>);
+
+impl PanicValue {
+    /// The location of the panic.
+    ///
+    /// Only known when the panic was captured while the panic hook installed by assertr was
+    /// active, which is not the case if the hook was replaced afterward.
+    #[must_use]
+    pub fn location(&self) -> Option<&PanicLocation> {
+        self.1.as_ref()
+    }
+}
+
+/// An owned copy of the `core::panic::Location` a panic occurred at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl From<&core::panic::Location<'_>> for PanicLocation {
+    fn from(location: &core::panic::Location<'_>) -> Self {
+        Self {
+            file: location.file().to_owned(),
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+impl core::fmt::Display for PanicLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
 
 /// The main entrypoint into an assertion context for borrowed values.
 ///
@@ -287,7 +352,7 @@ pub fn assert_that_type<T>() -> AssertThat<'static, Type<T>, Panic> {
 ///
 /// ### Fields
 /// - `parent`: A reference to the parent assertion, if this is a derived assertion. Failures will propagate to the root assertion.
-/// - `actual`: The value being asserted against.
+/// - `actual`: The value being asserted against. Absent when the subject is poisoned.
 /// - `subject_name`: An optional subject name for the assertion, allowing for more descriptive error messages.
 /// - `detail_messages`: A collection of additional messages that provide context for the assertion.
 /// - `print_location`: A boolean indicating whether the source code location of the assertion should be printed on failure.
@@ -305,12 +370,16 @@ pub fn assert_that_type<T>() -> AssertThat<'static, Type<T>, Panic> {
 ///
 /// ### Notes
 /// - When using `Capture` mode, failures must be captured explicitly.
+/// - When a data-extracting assertion like `is_some()` fails in `Capture` mode, the subject it
+///   changes to is "poisoned": There is no value to assert on, so all further assertions on it
+///   are skipped. See [`AssertThat::is_subject_poisoned`].
 /// - This struct is designed to handle both simple and complex assertion chaining scenarios.
 pub struct AssertThat<'t, T, M: Mode> {
     // Derived assertions can be created. Calling `.fail*` on them should propagate to the root assertion!
     parent: Option<&'t dyn DynAssertThat>,
 
-    actual: Actual<'t, T>,
+    // `None` once the subject is poisoned.
+    actual: Option<Actual<'t, T>>,
 
     subject_name: Option<String>,
     detail_messages: RefCell<Vec<String>>,
@@ -322,6 +391,9 @@ pub struct AssertThat<'t, T, M: Mode> {
     mode: RefCell<M>,
 }
 
+pub(crate) const POISONED_SUBJECT: &str = "The subject is poisoned, as it could not be extracted. \
+    Check `is_subject_poisoned()` before accessing it.";
+
 pub(crate) trait DynAssertThat:
     Fallible + WithDetail + AssertionTracking + UnwindSafe + RefUnwindSafe
 {
@@ -336,7 +408,7 @@ impl<'t, T> AssertThat<'t, T, Panic> {
     pub(crate) const fn new_panicking(actual: Actual<'t, T>) -> Self {
         AssertThat {
             parent: None,
-            actual,
+            actual: Some(actual),
             subject_name: None,
             detail_messages: RefCell::new(Vec::new()),
             print_location: true,
@@ -353,7 +425,7 @@ impl<'t, T> AssertThat<'t, T, Capture> {
     pub(crate) const fn new_capturing(actual: Actual<'t, T>) -> Self {
         AssertThat {
             parent: None,
-            actual,
+            actual: Some(actual),
             subject_name: None,
             detail_messages: RefCell::new(Vec::new()),
             print_location: true,
@@ -406,8 +478,97 @@ impl<T> AssertThat<'_, T, Capture> {
 }
 
 impl<'t, T, M: Mode> AssertThat<'t, T, M> {
+    /// The value being asserted against.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the subject is poisoned. Custom assertions should return early when
+    /// [`AssertThat::is_subject_poisoned`] is true, before accessing the actual value.
+    #[track_caller]
     pub fn actual(&self) -> &T {
-        self.actual.borrowed()
+        self.actual.as_ref().expect(POISONED_SUBJECT).borrowed()
+    }
+
+    /// Mutable access to the actual value, for assertions that consume parts of it, like
+    /// receiving from a channel.
+    ///
+    /// Panics if the subject is poisoned.
+    #[track_caller]
+    pub(crate) fn actual_mut(&mut self) -> &mut Actual<'t, T> {
+        self.actual.as_mut().expect(POISONED_SUBJECT)
+    }
+
+    /// Whether this subject is poisoned, which is the case after a data-extracting assertion,
+    /// like `is_some()` or `is_ok()`, failed in `Capture` mode. There is no actual value then.
+    ///
+    /// The failure of the extraction is recorded, noting that subsequent assertions are skipped.
+    /// All built-in assertions do nothing on a poisoned subject. Derived subjects are poisoned
+    /// as well.
+    ///
+    /// ```rust
+    /// use assertr::prelude::*;
+    ///
+    /// let failures = assert_that!(None::<u32>)
+    ///     .with_capture()
+    ///     .with_location(false)
+    ///     .is_some()
+    ///     .is_greater_than(42) // Skipped.
+    ///     .capture_failures();
+    ///
+    /// assert_that!(failures).has_length(1);
+    /// ```
+    #[must_use]
+    pub fn is_subject_poisoned(&self) -> bool {
+        self.actual.is_none()
+    }
+
+    /// Changes the subject to a poisoned one of type `U`, see
+    /// [`AssertThat::is_subject_poisoned`].
+    pub(crate) fn poison<U>(self) -> AssertThat<'t, U, M> {
+        AssertThat {
+            parent: self.parent,
+            actual: None,
+            subject_name: self.subject_name,
+            detail_messages: self.detail_messages,
+            print_location: self.print_location,
+            number_of_assertions: self.number_of_assertions,
+            failures: self.failures,
+            mode: self.mode,
+        }
+    }
+
+    /// Derives a poisoned subject of type `U`, see [`AssertThat::is_subject_poisoned`].
+    pub(crate) fn derive_poisoned<'u, U: 'u>(&'t self) -> AssertThat<'u, U, M>
+    where
+        't: 'u,
+    {
+        let mut mode = self.mode.replace(M::default());
+        mode.set_derived();
+
+        AssertThat {
+            parent: Some(self),
+            actual: None,
+            subject_name: None,
+            detail_messages: RefCell::new(Vec::new()),
+            print_location: self.print_location,
+            number_of_assertions: RefCell::new(NumberOfAssertions::new()),
+            failures: RefCell::new(Vec::new()),
+            mode: RefCell::new(mode),
+        }
+    }
+
+    /// Records or raises the failure of a data-extracting assertion. In `Capture` mode, the
+    /// failure notes that the assertions on the extracted value, which is poisoned, are skipped.
+    #[track_caller]
+    pub(crate) fn fail_extraction(&self, failure: impl Failure) {
+        let capturing = self.mode.borrow().is_capture();
+        self.fail(|w: &mut String| {
+            failure.write_to(w)?;
+            if capturing {
+                w.write_str(failure::EXTRACTED_ASSERTIONS_SKIPPED)?;
+            }
+            Ok(())
+        });
     }
 
     pub(crate) fn replace_actual_with<'u, U>(
@@ -415,14 +576,15 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
         // Note: Not using an explicit generic typename allows calls like `.map<String>(...)`,
         // requiring only one type, which is the type we want to map to.
         new_actual: Actual<'u, U>,
-    ) -> (Actual<'t, T>, AssertThat<'u, U, M>)
+    ) -> (Option<Actual<'t, T>>, AssertThat<'u, U, M>)
     where
         't: 'u,
     {
-        let previous_actual: Actual<'t, T> = self.actual;
+        let previous_actual = self.actual;
         let mapped = AssertThat {
             parent: self.parent,
-            actual: new_actual,
+            // A poisoned subject stays poisoned.
+            actual: previous_actual.is_some().then_some(new_actual),
             subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: self.detail_messages,
             print_location: self.print_location,
@@ -441,7 +603,7 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
     ) -> AssertThat<'t, U, M> {
         AssertThat {
             parent: self.parent,
-            actual: mapper(self.actual),
+            actual: self.actual.map(mapper),
             subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: self.detail_messages,
             print_location: self.print_location,
@@ -462,7 +624,9 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
     {
         AssertThat {
             parent: self.parent,
-            actual: Actual::Owned(mapper(self.actual.borrowed().to_owned())),
+            actual: self
+                .actual
+                .map(|actual| Actual::Owned(mapper(actual.borrowed().to_owned()))),
             subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: self.detail_messages,
             print_location: self.print_location,
@@ -483,7 +647,10 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
     {
         AssertThat {
             parent: self.parent,
-            actual: mapper(self.actual).await.into(),
+            actual: match self.actual {
+                Some(actual) => Some(mapper(actual).await.into()),
+                None => None,
+            },
             subject_name: self.subject_name, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: self.detail_messages,
             print_location: self.print_location,
@@ -502,7 +669,10 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
 
         AssertThat {
             parent: Some(self),
-            actual: Actual::Owned(mapper(self.actual())),
+            actual: self
+                .actual
+                .as_ref()
+                .map(|actual| Actual::Owned(mapper(actual.borrowed()))),
             subject_name: None, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: RefCell::new(Vec::new()),
             print_location: self.print_location,
@@ -524,7 +694,10 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
 
         AssertThat {
             parent: Some(self),
-            actual: Actual::Owned(mapper(self.actual()).await),
+            actual: match &self.actual {
+                Some(actual) => Some(Actual::Owned(mapper(actual.borrowed()).await)),
+                None => None,
+            },
             subject_name: None, // We cannot clone self.subject_name, as the mapper produces what has to be considered a "new" subject!
             detail_messages: RefCell::new(Vec::new()),
             print_location: self.print_location,
@@ -571,6 +744,37 @@ impl<'t, T, M: Mode> AssertThat<'t, T, M> {
         self
     }
 
+    /// Runs `assertions` on the value `extractor` extracts from the subject. This is the
+    /// mode-agnostic counterpart to data-extracting assertions like `is_some()` or `is_ok()`.
+    ///
+    /// When `extractor` returns `None`, the failure written by `failure` is raised (or recorded,
+    /// when in `Capture` mode) together with a note that the nested assertions were skipped, and
+    /// `assertions` is never run.
+    #[track_caller]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn satisfies_extracted<U: ?Sized, F, A>(
+        self,
+        extractor: F,
+        failure: impl FnOnce(&T, &mut String) -> core::fmt::Result,
+        assertions: A,
+    ) -> Self
+    where
+        for<'a> F: FnOnce(&'a T) -> Option<&'a U>,
+        for<'a> A: FnOnce(AssertThat<'a, &'a U, M>),
+    {
+        if self.is_subject_poisoned() {
+            return self;
+        }
+        match extractor(self.actual()) {
+            Some(extracted) => assertions(self.derive(|_actual| extracted)),
+            None => self.fail(|w: &mut String| {
+                failure(self.actual(), w)?;
+                w.write_str(failure::NESTED_ASSERTIONS_SKIPPED)
+            }),
+        }
+        self
+    }
+
     /// Gives the `actual` value contained in this assertion a descriptive name.
     /// This name will be part of panic messages when set.
     #[allow(dead_code)]
@@ -678,16 +882,19 @@ impl<'t, T> AssertThat<'t, T, Capture> {
 /* Unwrapping */
 
 impl<T> AssertThat<'_, T, Panic> {
-    /// **Panics** Panics if the actual value was not owned.
+    /// # Panics
+    ///
+    /// Panics if the actual value was not owned.
     // TODO: We could relax this by having `AssertThat` be generic over the type of actual value.
     #[track_caller]
     pub fn unwrap_inner(self) -> T {
-        self.actual.unwrap_owned()
+        self.actual.expect(POISONED_SUBJECT).unwrap_owned()
     }
 }
 
 impl<T> AssertThat<'_, T, Capture> {
-    /// **Panics**
+    /// # Panics
+    ///
     /// - If assertion errors are present.
     /// - If the actual value is not owned.
     // TODO: We could relax this by having `AssertThat` be generic over the type of actual value.
@@ -696,7 +903,7 @@ impl<T> AssertThat<'_, T, Capture> {
         // Switch to panicking behaviour, asserting that no failures were recorded.
         let panicking = self.without_capture();
 
-        panicking.actual.unwrap_owned()
+        panicking.actual.expect(POISONED_SUBJECT).unwrap_owned()
     }
 }
 
@@ -729,6 +936,9 @@ impl Debug for Differences {
 
 pub struct EqContext {
     differences: Differences,
+    /// The location of the value currently being compared within the compared values, e.g.
+    /// `samples[1]`. Empty when comparing the values themselves.
+    path: String,
 }
 
 impl Default for EqContext {
@@ -742,6 +952,16 @@ impl EqContext {
     pub fn new() -> Self {
         Self {
             differences: Differences::default(),
+            path: String::new(),
+        }
+    }
+
+    /// Joins `location` (a field name or an index like `[1]`) onto the current path.
+    pub(crate) fn path_to(&self, location: &str) -> String {
+        if self.path.is_empty() || location.starts_with('[') {
+            format!("{}{location}", self.path)
+        } else {
+            format!("{}.{location}", self.path)
         }
     }
 
@@ -759,6 +979,20 @@ impl EqContext {
             "\"{field_name}\": expected {expected:#?}, but was {actual:#?}"
         ));
     }
+
+    /// Records a value at `location` (e.g. a field name or an index) deviating from its expected
+    /// value by more than the allowed tolerance.
+    pub fn add_deviation(
+        &mut self,
+        location: &str,
+        expected: impl Debug,
+        actual: impl Debug,
+        deviation: impl Debug,
+    ) {
+        self.differences.differences.push(format!(
+            "{location}: expected {expected:#?}, but was {actual:#?}, deviating by {deviation:#?}"
+        ));
+    }
 }
 
 pub trait AssertrPartialEq<Rhs: ?Sized = Self> {
@@ -991,6 +1225,52 @@ mod tests {
         }
     }
 
+    mod poisoned_subject {
+        use crate::prelude::*;
+
+        #[test]
+        fn is_only_poisoned_after_a_failed_extraction() {
+            let subject = assert_that!(Some(42)).with_capture().is_some();
+            assert_that!(subject.is_subject_poisoned()).is_false();
+            let _ = subject.capture_failures();
+
+            let subject = assert_that!(None::<u32>).with_capture().is_some();
+            assert_that!(subject.is_subject_poisoned()).is_true();
+            let _ = subject.capture_failures();
+        }
+
+        #[test]
+        fn stays_poisoned_when_mapped_derived_or_extracted_again() {
+            let failures = assert_that!(None::<Result<u32, ()>>)
+                .with_capture()
+                .is_some()
+                .satisfies(
+                    |_| -> u32 { panic!("poisoned subjects are never mapped") },
+                    |value| {
+                        value.is_equal_to(1);
+                    },
+                )
+                .map::<Result<u32, ()>>(|_| panic!("poisoned subjects are never mapped"))
+                .is_ok()
+                .is_equal_to(1)
+                .capture_failures();
+
+            assert_that!(failures).has_length(1);
+        }
+
+        #[test]
+        fn panics_when_accessing_the_actual_value() {
+            let subject = assert_that!(None::<u32>).with_capture().is_some();
+
+            assert_that_panic_by(|| {
+                let _ = subject.actual();
+            })
+            .has_message("The subject is poisoned, as it could not be extracted. Check `is_subject_poisoned()` before accessing it.");
+
+            let _ = subject.capture_failures();
+        }
+    }
+
     #[cfg(feature = "fluent")]
     #[test]
     fn allows_fluent_entry_into_assertion_context() {
@@ -0,0 +1,137 @@
+//! Silences the panic hook for panics that are expected, e.g. by `assert_that_panic_by`.
+//!
+//! Replacing the panic hook for the duration of a single call would race with tests running in
+//! parallel. Instead, a hook is installed once, delegating to the previously installed hook unless
+//! the panicking thread is currently silenced.
+
+use alloc::boxed::Box;
+use core::cell::{Cell, RefCell};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::sync::Once;
+
+use crate::PanicLocation;
+
+thread_local! {
+    static SILENCED: Cell<usize> = const { Cell::new(0) };
+    static LAST_LOCATION: RefCell<Option<PanicLocation>> = const { RefCell::new(None) };
+}
+
+static INSTALL: Once = Once::new();
+
+fn install() {
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if SILENCED.get() > 0 {
+                LAST_LOCATION.set(info.location().map(PanicLocation::from));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Keeps the current thread silenced while alive, even if the silenced code unwinds.
+struct Silence;
+
+impl Silence {
+    fn enter() -> Self {
+        install();
+        SILENCED.set(SILENCED.get() + 1);
+        LAST_LOCATION.set(None);
+        Self
+    }
+}
+
+impl Drop for Silence {
+    fn drop(&mut self) {
+        SILENCED.set(SILENCED.get() - 1);
+    }
+}
+
+/// Calls `f` without printing panics occurring on the current thread, returning the location of
+/// the last of them.
+pub(crate) fn silenced<R>(f: impl FnOnce() -> R) -> (R, Option<PanicLocation>) {
+    let silence = Silence::enter();
+    let output = f();
+    drop(silence);
+    (output, LAST_LOCATION.take())
+}
+
+/// Awaits `future` without printing panics occurring while it is polled, resolving to its output
+/// and the location of the last of them.
+///
+/// The thread is only silenced during each poll, as the future may be moved to another thread
+/// in between.
+pub(crate) fn silenced_async<F: Future>(future: F) -> SilencedFuture<F> {
+    SilencedFuture {
+        future: Box::pin(future),
+        location: None,
+    }
+}
+
+pub(crate) struct SilencedFuture<F> {
+    future: Pin<Box<F>>,
+    location: Option<PanicLocation>,
+}
+
+impl<F: Future> Future for SilencedFuture<F> {
+    type Output = (F::Output, Option<PanicLocation>);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let (poll, location) = silenced(|| this.future.as_mut().poll(cx));
+        if location.is_some() {
+            this.location = location;
+        }
+        poll.map(|output| (output, this.location.take()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{silenced, silenced_async};
+    use crate::prelude::*;
+
+    #[test]
+    fn records_location_of_panic() {
+        let (result, location) = silenced(|| std::panic::catch_unwind(|| panic!("boom")));
+        let line = line!() - 1;
+
+        assert_that!(result.is_err()).is_true();
+        assert_that!(location.map(|it| (it.file, it.line)))
+            .is_equal_to(Some((String::from(file!()), line)));
+    }
+
+    #[test]
+    fn records_no_location_without_panic() {
+        let (_, location) = silenced(|| 42);
+
+        assert_that!(location).is_none();
+    }
+
+    #[test]
+    fn keeps_nested_silencing_independent() {
+        let ((inner_location, outer_result), outer_location) = silenced(|| {
+            let (_, inner_location) = silenced(|| std::panic::catch_unwind(|| panic!("inner")));
+            (inner_location, std::panic::catch_unwind(|| panic!("outer")))
+        });
+        let line = line!() - 4;
+
+        assert_that!(outer_result.is_err()).is_true();
+        assert_that!(inner_location.map(|it| it.line)).is_equal_to(Some(line + 1));
+        assert_that!(outer_location.map(|it| it.line)).is_equal_to(Some(line + 2));
+    }
+
+    #[tokio::test]
+    async fn records_location_of_panic_while_polling() {
+        let (result, location) =
+            silenced_async(futures::FutureExt::catch_unwind(async { panic!("boom") })).await;
+        let line = line!() - 1;
+
+        assert_that!(result.is_err()).is_true();
+        assert_that!(location.map(|it| it.line)).is_equal_to(Some(line));
+    }
+}
@@ -0,0 +1,125 @@
+//! Races futures against, or waits for, a timer, without requiring a specific async runtime.
+
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::{Pin, pin};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use futures::future::{Either, select};
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+
+/// Awaits `future` for at most `duration`, returning its output, if it completed in time, and the
+/// elapsed time.
+///
+/// Uses tokio's timer when called from within a tokio runtime, respecting paused time. Otherwise,
+/// a thread is spawned to wake the task once `duration` has passed.
+pub(crate) async fn timeout<F: Future>(
+    duration: Duration,
+    future: F,
+) -> (Option<F::Output>, Duration) {
+    #[cfg(feature = "tokio")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        let start = tokio::time::Instant::now();
+        let output = tokio::time::timeout(duration, future).await.ok();
+        return (output, start.elapsed());
+    }
+
+    let start = Instant::now();
+    let output = match select(pin!(future), Delay::new(duration)).await {
+        Either::Left((output, _delay)) => Some(output),
+        Either::Right(((), _future)) => None,
+    };
+    (output, start.elapsed())
+}
+
+/// Waits for `duration`, using tokio's timer when called from within a tokio runtime.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        tokio::time::sleep(duration).await;
+        return;
+    }
+
+    Delay::new(duration).await;
+}
+
+/// Completes once `deadline` has passed.
+struct Delay {
+    deadline: Instant,
+    waker: Option<Arc<Mutex<Option<Waker>>>>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            waker: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        if let Some(waker) = &self.waker {
+            *waker.lock().unwrap_or_else(PoisonError::into_inner) = Some(cx.waker().clone());
+        } else {
+            let waker = Arc::new(Mutex::new(Some(cx.waker().clone())));
+            let timer_waker = Arc::clone(&waker);
+            let deadline = self.deadline;
+            std::thread::spawn(move || {
+                std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                let waker = timer_waker
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            });
+            self.waker = Some(waker);
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::timeout;
+    use crate::prelude::*;
+    use core::time::Duration;
+    use futures::executor::block_on;
+
+    #[test]
+    fn returns_output_when_future_completes_in_time() {
+        let (output, _elapsed) = block_on(timeout(Duration::from_secs(10), async { 42 }));
+
+        assert_that!(output).is_some().is_equal_to(42);
+    }
+
+    #[test]
+    fn returns_none_after_duration_without_tokio() {
+        let (output, elapsed) = block_on(timeout(
+            Duration::from_millis(20),
+            core::future::pending::<()>(),
+        ));
+
+        assert_that!(output).is_none();
+        assert_that!(elapsed).is_greater_or_equal_to(Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn returns_none_after_duration_within_tokio_runtime() {
+        let (output, elapsed) =
+            timeout(Duration::from_millis(20), core::future::pending::<()>()).await;
+
+        assert_that!(output).is_none();
+        assert_that!(elapsed).is_greater_or_equal_to(Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt::Debug;
+
+use crate::AssertrPartialEq;
+
+/// A value stored under the same key in both the actual and the expected map, which differs.
+pub(crate) struct DifferingValue<'a> {
+    pub(crate) key: &'a dyn Debug,
+    pub(crate) expected: &'a dyn Debug,
+    pub(crate) actual: &'a dyn Debug,
+}
+
+impl Debug for DifferingValue<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:#?}: expected {:#?}, but was {:#?}",
+            self.key, self.expected, self.actual
+        )
+    }
+}
+
+/// Structured difference between an actual and an expected map or set.
+///
+/// Sets never have differing values, as they only consist of keys. Maps list unexpected entries,
+/// sets unexpected elements.
+pub(crate) struct CollectionDiff<'a> {
+    noun: &'static str,
+    pub(crate) missing: Vec<&'a dyn Debug>,
+    pub(crate) unexpected: Vec<&'a dyn Debug>,
+    pub(crate) unexpected_entries: Vec<(&'a dyn Debug, &'a dyn Debug)>,
+    pub(crate) differing_values: Vec<DifferingValue<'a>>,
+}
+
+impl<'a> CollectionDiff<'a> {
+    pub(crate) fn for_map() -> Self {
+        Self {
+            noun: "keys",
+            missing: Vec::new(),
+            unexpected: Vec::new(),
+            unexpected_entries: Vec::new(),
+            differing_values: Vec::new(),
+        }
+    }
+
+    pub(crate) fn for_set() -> Self {
+        Self {
+            noun: "elements",
+            missing: Vec::new(),
+            unexpected: Vec::new(),
+            unexpected_entries: Vec::new(),
+            differing_values: Vec::new(),
+        }
+    }
+
+    /// Compares the entries of an actual map against the expected entries, using `get` to look up
+    /// the value stored under an expected key in the actual map.
+    pub(crate) fn of_map_entries<K, V, EK, EV>(
+        actual: impl IntoIterator<Item = (&'a K, &'a V)>,
+        get: impl Fn(&K) -> Option<&'a V>,
+        expected: &'a [(EK, EV)],
+    ) -> Self
+    where
+        K: PartialEq + Debug + 'a,
+        V: AssertrPartialEq<EV> + Debug + 'a,
+        EK: Borrow<K> + Debug,
+        EV: Debug,
+    {
+        let mut diff = Self::for_map();
+        for (expected_key, expected_value) in expected {
+            match get(expected_key.borrow()) {
+                None => diff.missing.push(expected_key),
+                Some(actual_value) => {
+                    if !AssertrPartialEq::eq(actual_value, expected_value, None) {
+                        diff.differing_values.push(DifferingValue {
+                            key: expected_key,
+                            expected: expected_value,
+                            actual: actual_value,
+                        });
+                    }
+                }
+            }
+        }
+        for (actual_key, actual_value) in actual {
+            if !expected
+                .iter()
+                .any(|(expected_key, _expected_value)| expected_key.borrow() == actual_key)
+            {
+                diff.unexpected_entries.push((actual_key, actual_value));
+            }
+        }
+        diff
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.missing.is_empty()
+            && self.unexpected.is_empty()
+            && self.unexpected_entries.is_empty()
+            && self.differing_values.is_empty()
+    }
+
+    /// Renders the non-empty parts of this difference, one message per part.
+    pub(crate) fn messages(&self) -> Vec<String> {
+        let mut messages = Vec::new();
+        if !self.missing.is_empty() {
+            messages.push(format!("Missing {}: {:#?}", self.noun, self.missing));
+        }
+        if !self.unexpected.is_empty() {
+            messages.push(format!("Unexpected {}: {:#?}", self.noun, self.unexpected));
+        }
+        if !self.unexpected_entries.is_empty() {
+            messages.push(format!(
+                "Unexpected entries: {:#?}",
+                self.unexpected_entries
+            ));
+        }
+        if !self.differing_values.is_empty() {
+            messages.push(format!("Differing values: {:#?}", self.differing_values));
+        }
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CollectionDiff, DifferingValue};
+    use crate::prelude::*;
+    use indoc::formatdoc;
+
+    #[test]
+    fn renders_nothing_when_empty() {
+        let diff = CollectionDiff::for_map();
+        assert_that!(diff.is_empty()).is_true();
+        assert_that!(diff.messages()).is_empty();
+    }
+
+    #[test]
+    fn renders_every_non_empty_part() {
+        let mut diff = CollectionDiff::for_map();
+        diff.missing.push(&"a");
+        diff.differing_values.push(DifferingValue {
+            key: &"b",
+            expected: &1,
+            actual: &2,
+        });
+
+        assert_that!(diff.is_empty()).is_false();
+        assert_that!(diff.messages()).contains_exactly([
+            formatdoc! {r#"
+                Missing keys: [
+                    "a",
+                ]"#},
+            formatdoc! {r#"
+                Differing values: [
+                    "b": expected 1, but was 2,
+                ]"#},
+        ]);
+    }
+}
@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::AssertrPartialEq;
+
+/// A value occurring more than once in a collection, together with all indices it occurs at.
+pub(crate) struct Duplicate<V> {
+    pub(crate) value: V,
+    pub(crate) indices: Vec<usize>,
+}
+
+impl<V: Debug> Debug for Duplicate<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#?} at indices {:?}", self.value, self.indices)
+        } else {
+            write!(f, "{:?} at indices {:?}", self.value, self.indices)
+        }
+    }
+}
+
+/// Finds all duplicated elements using their `Hash` and `Eq` implementations. Runs in O(n).
+///
+/// Duplicates are reported in the order of their first occurrence.
+#[cfg(feature = "std")]
+pub(crate) fn find_duplicates<'t, T>(
+    items: impl IntoIterator<Item = &'t T>,
+) -> Vec<Duplicate<&'t T>>
+where
+    T: Eq + core::hash::Hash + 't,
+{
+    find_duplicates_by_key(items, |it| *it)
+}
+
+/// Finds all elements whose extracted key is duplicated, using the keys `Hash` and `Eq`
+/// implementations. Runs in O(n).
+///
+/// Duplicates are reported in the order of their first occurrence.
+#[cfg(feature = "std")]
+pub(crate) fn find_duplicates_by_key<I, K>(
+    items: impl IntoIterator<Item = I>,
+    key: impl Fn(&I) -> K,
+) -> Vec<Duplicate<K>>
+where
+    K: Eq + core::hash::Hash,
+{
+    let mut occurrences: std::collections::HashMap<K, Vec<usize>> =
+        std::collections::HashMap::new();
+    for (index, item) in items.into_iter().enumerate() {
+        occurrences.entry(key(&item)).or_default().push(index);
+    }
+
+    let mut duplicates = occurrences
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .map(|(value, indices)| Duplicate { value, indices })
+        .collect::<Vec<_>>();
+    duplicates.sort_by_key(|duplicate| duplicate.indices[0]);
+    duplicates
+}
+
+/// Finds all duplicated elements using their `AssertrPartialEq` implementation. Runs in O(n²).
+///
+/// Use this for types that cannot be hashed, e.g. floats or types only comparable through
+/// `AssertrPartialEq`.
+///
+/// Duplicates are reported in the order of their first occurrence.
+pub(crate) fn find_duplicates_by_eq<'t, T>(
+    items: impl IntoIterator<Item = &'t T>,
+) -> Vec<Duplicate<&'t T>>
+where
+    T: AssertrPartialEq + 't,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    let mut already_reported = alloc::vec![false; items.len()];
+    let mut duplicates = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        if already_reported[index] {
+            continue;
+        }
+        let mut indices = alloc::vec![index];
+        for (other_index, other) in items.iter().enumerate().skip(index + 1) {
+            if !already_reported[other_index] && AssertrPartialEq::eq(*other, *item, None) {
+                already_reported[other_index] = true;
+                indices.push(other_index);
+            }
+        }
+        if indices.len() > 1 {
+            duplicates.push(Duplicate {
+                value: *item,
+                indices,
+            });
+        }
+    }
+
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    mod find_duplicates {
+        use crate::prelude::*;
+        use crate::util::duplicates::find_duplicates;
+
+        #[test]
+        fn finds_nothing_in_unique_input() {
+            find_duplicates(&[1, 2, 3]).must().be_empty();
+        }
+
+        #[test]
+        fn finds_all_duplicates_in_order_of_first_occurrence() {
+            let duplicates = find_duplicates(&[3, 1, 2, 1, 3, 3]);
+
+            duplicates.len().must().be_equal_to(2);
+            (*duplicates[0].value).must().be_equal_to(3);
+            duplicates[0].indices.must().contain_exactly([0, 4, 5]);
+            (*duplicates[1].value).must().be_equal_to(1);
+            duplicates[1].indices.must().contain_exactly([1, 3]);
+        }
+    }
+
+    mod find_duplicates_by_key {
+        use crate::prelude::*;
+        use crate::util::duplicates::find_duplicates_by_key;
+
+        #[test]
+        fn finds_duplicated_keys() {
+            let duplicates = find_duplicates_by_key(["a", "bb", "c", "dd"], |it| it.len());
+
+            duplicates.len().must().be_equal_to(2);
+            duplicates[0].value.must().be_equal_to(1);
+            duplicates[0].indices.must().contain_exactly([0, 2]);
+            duplicates[1].value.must().be_equal_to(2);
+            duplicates[1].indices.must().contain_exactly([1, 3]);
+        }
+    }
+
+    mod find_duplicates_by_eq {
+        use crate::prelude::*;
+        use crate::util::duplicates::find_duplicates_by_eq;
+
+        #[test]
+        fn finds_nothing_in_unique_input() {
+            find_duplicates_by_eq(&[1.0, 2.0, 3.0]).must().be_empty();
+        }
+
+        #[test]
+        fn finds_all_duplicates_in_order_of_first_occurrence() {
+            let duplicates = find_duplicates_by_eq(&[3.0, 1.0, 2.0, 1.0, 3.0, 3.0]);
+
+            duplicates.len().must().be_equal_to(2);
+            (*duplicates[0].value).must().be_equal_to(3.0);
+            duplicates[0].indices.must().contain_exactly([0, 4, 5]);
+            (*duplicates[1].value).must().be_equal_to(1.0);
+            duplicates[1].indices.must().contain_exactly([1, 3]);
+        }
+    }
+}
@@ -1 +1,4 @@
+pub mod collection_diff;
+pub mod duplicates;
 pub mod slice;
+pub(crate) mod unwind;
@@ -0,0 +1,12 @@
+use core::future::Future;
+use core::panic::AssertUnwindSafe;
+
+/// Allows awaiting a future that is not unwind safe, e.g. one holding a timer, a `JoinSet` or a
+/// mutable borrow of a stream, in `assert_that_panic_by_async`.
+///
+/// This is sound as long as the future is consumed by, or only borrows from, the assertion
+/// awaiting it: the assertion is dropped when unwinding, so no broken state can be observed after
+/// a panic.
+pub(crate) fn unwind_safe<F: Future>(future: F) -> AssertUnwindSafe<F> {
+    AssertUnwindSafe(future)
+}